@@ -0,0 +1,77 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use tabled::{builder::Builder, object::Columns, Disable, Extract};
+
+const COUNT_ROWS: usize = 64;
+
+fn build_builder(count_columns: usize) -> Builder<'static> {
+    let mut builder = Builder::default();
+    for row in 0..COUNT_ROWS {
+        builder.add_record((0..count_columns).map(|col| format!("{}-{}", row, col)));
+    }
+
+    builder
+}
+
+macro_rules! records_bench {
+    ($name:ident, |$table:ident| $action:expr) => {
+        pub fn $name(c: &mut Criterion) {
+            let mut group = c.benchmark_group(stringify!($name));
+            for size in [8, 64, 512, 1024] {
+                group.bench_with_input(BenchmarkId::new("vec", size), &size, |b, &size| {
+                    let table = build_builder(size).build();
+                    b.iter(|| {
+                        #[allow(unused_mut)]
+                        let mut $table = table.clone();
+                        black_box($action);
+                    });
+                });
+
+                group.bench_with_input(BenchmarkId::new("column", size), &size, |b, &size| {
+                    let table = build_builder(size).build_columnar();
+                    b.iter(|| {
+                        #[allow(unused_mut)]
+                        let mut $table = table.clone();
+                        black_box($action);
+                    });
+                });
+            }
+            group.finish();
+        }
+    };
+}
+
+records_bench!(wide_table_disable_column, |table| table
+    .with(Disable::column(Columns::new(1..)))
+    .count_columns());
+
+records_bench!(wide_table_extract_columns, |table| table
+    .with(Extract::columns(1..))
+    .count_columns());
+
+records_bench!(wide_table_total_width, |table| table.total_width());
+
+records_bench!(wide_table_to_string, |table| table.to_string());
+
+pub fn wide_table_build(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wide_table_build");
+    for size in [8, 64, 512, 1024] {
+        group.bench_with_input(BenchmarkId::new("vec", size), &size, |b, &size| {
+            b.iter(|| black_box(build_builder(size).build()));
+        });
+
+        group.bench_with_input(BenchmarkId::new("column", size), &size, |b, &size| {
+            b.iter(|| black_box(build_builder(size).build_columnar()));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    wide_table_build,
+    wide_table_disable_column,
+    wide_table_extract_columns,
+    wide_table_total_width,
+    wide_table_to_string
+);
+criterion_main!(benches);
@@ -14,7 +14,18 @@ use papergrid::{
 struct Style(OStyle);
 
 impl Color for Style {
-    fn fmt_prefix(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt_prefix<F>(&self, f: &mut F) -> std::fmt::Result
+    where
+        F: std::fmt::Write + ?Sized,
+    {
+        write!(f, "{}", StylePrefix(&self.0))
+    }
+}
+
+struct StylePrefix<'a>(&'a OStyle);
+
+impl std::fmt::Display for StylePrefix<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.0.fmt_prefix(f)
     }
 }
@@ -1,4 +1,4 @@
-use std::fmt::{self, Display, Formatter};
+use std::fmt;
 
 use super::Color;
 
@@ -48,12 +48,18 @@ impl std::convert::TryFrom<String> for AnsiColor {
 }
 
 impl Color for AnsiColor {
-    fn fmt_prefix(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.prefix.fmt(f)
+    fn fmt_prefix<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
+        f.write_str(&self.prefix)
     }
 
-    fn fmt_suffix(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        self.suffix.fmt(f)
+    fn fmt_suffix<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
+        f.write_str(&self.suffix)
     }
 }
 
@@ -1,4 +1,4 @@
-use std::fmt::{self, Display, Formatter};
+use std::fmt::{self, Display};
 
 #[cfg(feature = "color")]
 mod ansi_color;
@@ -10,22 +10,28 @@ pub use ansi_color::AnsiColor;
 /// A trait which prints an ANSI prefix and suffix.
 pub trait Color {
     /// Print ANSI prefix.
-    fn fmt_prefix(&self, f: &mut Formatter<'_>) -> fmt::Result;
+    fn fmt_prefix<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized;
 
     /// Print ANSI suffix.
-    fn fmt_suffix(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    fn fmt_suffix<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
         f.write_str("\u{1b}[0m")
     }
 
     /// Print colored text.
     ///
     /// It may not handle `\n` (new lines).
-    fn colorize<T>(&self, f: &mut Formatter<'_>, text: T) -> fmt::Result
+    fn colorize<T, F>(&self, f: &mut F, text: T) -> fmt::Result
     where
         T: Display,
+        F: fmt::Write + ?Sized,
     {
         self.fmt_prefix(f)?;
-        text.fmt(f)?;
+        write!(f, "{}", text)?;
         self.fmt_suffix(f)?;
         Ok(())
     }
@@ -35,17 +41,24 @@ impl<C> Color for &C
 where
     C: Color,
 {
-    fn fmt_prefix(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    fn fmt_prefix<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
         C::fmt_prefix(self, f)
     }
 
-    fn fmt_suffix(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    fn fmt_suffix<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
         C::fmt_suffix(self, f)
     }
 
-    fn colorize<T>(&self, f: &mut Formatter<'_>, text: T) -> fmt::Result
+    fn colorize<T, F>(&self, f: &mut F, text: T) -> fmt::Result
     where
         T: Display,
+        F: fmt::Write + ?Sized,
     {
         C::colorize(self, f, text)
     }
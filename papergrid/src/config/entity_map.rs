@@ -40,6 +40,16 @@ impl<T> EntityMap<T> {
         }
     }
 
+    /// Checks whether a value was set for a given entity itself, rather than inherited from a global one.
+    pub(crate) fn is_set(&self, entity: Entity) -> bool {
+        match entity {
+            Entity::Column(col) => self.columns.contains_key(&col),
+            Entity::Row(row) => self.rows.contains_key(&row),
+            Entity::Cell(row, col) => self.cells.contains_key(&(row, col)),
+            Entity::Global => false,
+        }
+    }
+
     pub(crate) fn invalidate(&mut self, entity: Entity) {
         match entity {
             Entity::Global => {
@@ -394,6 +394,12 @@ impl GridConfig {
         self.alignment_h.lookup(entity)
     }
 
+    /// Checks whether a horizontal alignment was set for a given [Entity] itself,
+    /// rather than inherited from a global one.
+    pub fn is_alignment_horizontal_set(&self, entity: Entity) -> bool {
+        self.alignment_h.is_set(entity)
+    }
+
     /// The function returns whether the cells will be rendered or it will be hidden because of a span.
     pub fn is_cell_visible(&self, pos: Position, shape: (usize, usize)) -> bool {
         !(self.is_cell_covered_by_column_span(pos, shape)
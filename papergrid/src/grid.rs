@@ -42,13 +42,19 @@ impl<'a, R, W, H> Grid<'a, R, W, H> {
     }
 }
 
-impl<'a, R, W, H> fmt::Display for Grid<'a, R, W, H>
+impl<'a, R, W, H> Grid<'a, R, W, H>
 where
     R: Records,
     W: Estimate<R>,
     H: Estimate<R>,
 {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// Builds a table into a given [`Write`].
+    ///
+    /// It can be used to print a table without creating a [`String`].
+    pub fn build<F>(&self, f: &mut F) -> fmt::Result
+    where
+        F: Write + ?Sized,
+    {
         if self.records.count_rows() == 0 || self.records.count_columns() == 0 {
             return Ok(());
         }
@@ -57,8 +63,19 @@ where
     }
 }
 
-fn print_grid<R, W, H>(
-    f: &mut fmt::Formatter<'_>,
+impl<'a, R, W, H> fmt::Display for Grid<'a, R, W, H>
+where
+    R: Records,
+    W: Estimate<R>,
+    H: Estimate<R>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.build(f)
+    }
+}
+
+fn print_grid<R, W, H, F>(
+    f: &mut F,
     cfg: &GridConfig,
     records: &R,
     width: &W,
@@ -68,6 +85,7 @@ where
     W: Estimate<R>,
     H: Estimate<R>,
     R: Records,
+    F: Write + ?Sized,
 {
     // spanned version is a bit more complex and 'supposedly' slower,
     // because spans are considered to be not a general case we are having 2 versions
@@ -81,8 +99,8 @@ where
 mod print_general {
     use super::*;
 
-    pub(super) fn print_grid<R, W, H>(
-        f: &mut fmt::Formatter<'_>,
+    pub(super) fn print_grid<R, W, H, F>(
+        f: &mut F,
         cfg: &GridConfig,
         records: &R,
         width: &W,
@@ -92,6 +110,7 @@ mod print_general {
         W: Estimate<R>,
         H: Estimate<R>,
         R: Records,
+        F: Write + ?Sized,
     {
         let total_width = total_width(cfg, records, width);
         let total_width_with_margin =
@@ -167,8 +186,8 @@ mod print_general {
         Ok(())
     }
 
-    fn print_split_line<R, W>(
-        f: &mut fmt::Formatter<'_>,
+    fn print_split_line<R, W, F>(
+        f: &mut F,
         cfg: &GridConfig,
         records: &R,
         width_ctrl: &W,
@@ -178,6 +197,7 @@ mod print_general {
     where
         W: Estimate<R>,
         R: Records,
+        F: Write + ?Sized,
     {
         let shape = (records.count_rows(), records.count_columns());
 
@@ -322,8 +342,8 @@ mod print_spanned {
 
     use super::*;
 
-    pub(super) fn print_grid<R, W, H>(
-        f: &mut fmt::Formatter<'_>,
+    pub(super) fn print_grid<R, W, H, F>(
+        f: &mut F,
         cfg: &GridConfig,
         records: &R,
         width: &W,
@@ -333,6 +353,7 @@ mod print_spanned {
         W: Estimate<R>,
         H: Estimate<R>,
         R: Records,
+        F: Write + ?Sized,
     {
         let shape = (records.count_rows(), records.count_columns());
 
@@ -439,8 +460,8 @@ mod print_spanned {
     }
 
     #[allow(clippy::too_many_arguments)]
-    fn print_split_line<R, W, H>(
-        f: &mut fmt::Formatter<'_>,
+    fn print_split_line<R, W, H, F>(
+        f: &mut F,
         cfg: &GridConfig,
         records: &R,
         width_ctrl: &W,
@@ -452,6 +473,7 @@ mod print_spanned {
         W: Estimate<R>,
         H: Estimate<R>,
         R: Records,
+        F: Write + ?Sized,
     {
         let shape = (records.count_rows(), records.count_columns());
 
@@ -622,8 +644,8 @@ mod print_spanned {
         Ok(())
     }
 
-    fn print_cell_line<R, W, H>(
-        f: &mut fmt::Formatter<'_>,
+    fn print_cell_line<R, W, H, F>(
+        f: &mut F,
         cfg: &GridConfig,
         records: &R,
         width: &W,
@@ -635,6 +657,7 @@ mod print_spanned {
         R: Records,
         W: Estimate<R>,
         H: Estimate<R>,
+        F: Write + ?Sized,
     {
         let width = grid_cell_width(cfg, records, width, pos);
         let height = grid_cell_height(cfg, records, height, pos);
@@ -642,13 +665,16 @@ mod print_spanned {
     }
 }
 
-fn print_horizontal_border(
-    f: &mut fmt::Formatter<'_>,
+fn print_horizontal_border<F>(
+    f: &mut F,
     cfg: &GridConfig,
     pos: Position,
     width: usize,
     c: char,
-) -> fmt::Result {
+) -> fmt::Result
+where
+    F: Write + ?Sized,
+{
     if cfg.is_overidden_horizontal(pos) {
         for i in 0..width {
             let c = cfg.lookup_overidden_horizontal(pos, i, width).unwrap_or(c);
@@ -662,8 +688,8 @@ fn print_horizontal_border(
     Ok(())
 }
 
-fn print_cell_line<R>(
-    f: &mut fmt::Formatter<'_>,
+fn print_cell_line<R, F>(
+    f: &mut F,
     cfg: &GridConfig,
     records: &R,
     width: usize,
@@ -673,6 +699,7 @@ fn print_cell_line<R>(
 ) -> fmt::Result
 where
     R: Records,
+    F: Write + ?Sized,
 {
     let mut cell_height = records.count_lines(pos);
     let formatting = *cfg.get_formatting(pos.into());
@@ -765,8 +792,8 @@ where
 }
 
 #[allow(clippy::too_many_arguments)]
-fn print_line_aligned<R, W>(
-    f: &mut fmt::Formatter<'_>,
+fn print_line_aligned<R, W, F>(
+    f: &mut F,
     records: &R,
     pos: Position,
     index: usize,
@@ -779,14 +806,15 @@ fn print_line_aligned<R, W>(
 where
     R: Records,
     W: WidthFunc,
+    F: Write + ?Sized,
 {
-    let line = records.get_line(pos, index);
-    let (line, line_width) = if formatting.horizontal_trim && !line.is_empty() {
-        let line = string_trim(line);
+    let original_line = records.get_line(pos, index);
+    let (line, line_width) = if formatting.horizontal_trim && !original_line.is_empty() {
+        let line = string_trim(&original_line);
         let width = width_ctrl.width(&line);
         (line, width)
     } else {
-        let line = Cow::Borrowed(line);
+        let line = Cow::Borrowed(original_line.as_ref());
         let width = records.get_line_width(pos, index, width_ctrl);
         (line, width)
     };
@@ -816,8 +844,8 @@ where
 }
 
 #[allow(unused)]
-fn print_text_formated<R>(
-    f: &mut fmt::Formatter<'_>,
+fn print_text_formated<R, F>(
+    f: &mut F,
     records: &R,
     pos: Position,
     text: &str,
@@ -827,6 +855,7 @@ fn print_text_formated<R>(
 ) -> fmt::Result
 where
     R: Records,
+    F: Write + ?Sized,
 {
     repeat_char(f, DEFAULT_SPACE_CHAR, left)?;
 
@@ -843,7 +872,10 @@ where
     Ok(())
 }
 
-fn print_text(f: &mut fmt::Formatter<'_>, text: &str, tab_width: usize) -> fmt::Result {
+fn print_text<F>(f: &mut F, text: &str, tab_width: usize) -> fmt::Result
+where
+    F: Write + ?Sized,
+{
     // So to not use replace_tab we are printing by char;
     // Hopefully it's more affective as it reduceses a number of allocations.
     for c in text.chars() {
@@ -858,11 +890,14 @@ fn print_text(f: &mut fmt::Formatter<'_>, text: &str, tab_width: usize) -> fmt::
 }
 
 #[cfg(feature = "color")]
-fn prepare_coloring<'a>(
-    f: &mut fmt::Formatter<'_>,
+fn prepare_coloring<'a, F>(
+    f: &mut F,
     clr: Option<&'a AnsiColor>,
     used_color: &mut Option<&'a AnsiColor>,
-) -> fmt::Result {
+) -> fmt::Result
+where
+    F: Write + ?Sized,
+{
     match clr {
         Some(clr) => match used_color.as_mut() {
             Some(used_clr) => {
@@ -945,7 +980,10 @@ where
         .count()
 }
 
-fn repeat_char(f: &mut fmt::Formatter<'_>, c: char, n: usize) -> fmt::Result {
+fn repeat_char<F>(f: &mut F, c: char, n: usize) -> fmt::Result
+where
+    F: Write + ?Sized,
+{
     for _ in 0..n {
         f.write_char(c)?;
     }
@@ -965,8 +1003,8 @@ where
     content_width + count_borders
 }
 
-fn print_vertical_char<R>(
-    f: &mut fmt::Formatter<'_>,
+fn print_vertical_char<R, F>(
+    f: &mut F,
     cfg: &GridConfig,
     records: &R,
     pos: Position,
@@ -975,6 +1013,7 @@ fn print_vertical_char<R>(
 ) -> fmt::Result
 where
     R: Records,
+    F: Write + ?Sized,
 {
     let left = get_vertical(cfg, records, pos);
     if let Some(c) = left {
@@ -1003,7 +1042,10 @@ where
     Ok(())
 }
 
-fn print_margin_top(f: &mut fmt::Formatter<'_>, cfg: &GridConfig, width: usize) -> fmt::Result {
+fn print_margin_top<F>(f: &mut F, cfg: &GridConfig, width: usize) -> fmt::Result
+where
+    F: Write + ?Sized,
+{
     print_indent_lines(
         f,
         &cfg.get_margin().top,
@@ -1013,7 +1055,10 @@ fn print_margin_top(f: &mut fmt::Formatter<'_>, cfg: &GridConfig, width: usize)
     )
 }
 
-fn print_margin_bottom(f: &mut fmt::Formatter<'_>, cfg: &GridConfig, width: usize) -> fmt::Result {
+fn print_margin_bottom<F>(f: &mut F, cfg: &GridConfig, width: usize) -> fmt::Result
+where
+    F: Write + ?Sized,
+{
     print_indent_lines(
         f,
         &cfg.get_margin().bottom,
@@ -1023,7 +1068,10 @@ fn print_margin_bottom(f: &mut fmt::Formatter<'_>, cfg: &GridConfig, width: usiz
     )
 }
 
-fn print_margin_left(f: &mut fmt::Formatter<'_>, cfg: &GridConfig) -> fmt::Result {
+fn print_margin_left<F>(f: &mut F, cfg: &GridConfig) -> fmt::Result
+where
+    F: Write + ?Sized,
+{
     print_indent(
         f,
         cfg.get_margin().left.fill,
@@ -1033,7 +1081,10 @@ fn print_margin_left(f: &mut fmt::Formatter<'_>, cfg: &GridConfig) -> fmt::Resul
     )
 }
 
-fn print_margin_right(f: &mut fmt::Formatter<'_>, cfg: &GridConfig) -> fmt::Result {
+fn print_margin_right<F>(f: &mut F, cfg: &GridConfig) -> fmt::Result
+where
+    F: Write + ?Sized,
+{
     print_indent(
         f,
         cfg.get_margin().right.fill,
@@ -1043,12 +1094,15 @@ fn print_margin_right(f: &mut fmt::Formatter<'_>, cfg: &GridConfig) -> fmt::Resu
     )
 }
 
-fn print_indent_lines(
-    f: &mut fmt::Formatter<'_>,
+fn print_indent_lines<F>(
+    f: &mut F,
     indent: &Indent,
     width: usize,
     #[cfg(feature = "color")] color: &AnsiColor,
-) -> fmt::Result {
+) -> fmt::Result
+where
+    F: Write + ?Sized,
+{
     for i in 0..indent.size {
         print_indent(
             f,
@@ -1066,12 +1120,15 @@ fn print_indent_lines(
     Ok(())
 }
 
-fn print_indent(
-    f: &mut fmt::Formatter<'_>,
+fn print_indent<F>(
+    f: &mut F,
     c: char,
     n: usize,
     #[cfg(feature = "color")] color: &AnsiColor,
-) -> fmt::Result {
+) -> fmt::Result
+where
+    F: Write + ?Sized,
+{
     #[cfg(feature = "color")]
     color.fmt_prefix(f)?;
     repeat_char(f, c, n)?;
@@ -0,0 +1,295 @@
+//! The module contains a [`ColumnRecords`] implementation of [`Records`].
+
+use std::{
+    borrow::Cow,
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use super::{
+    cell_info::CellInfo,
+    vec_records::{Cell, CellMut},
+    Records, RecordsMut, Resizable,
+};
+use crate::{width::WidthFunc, Position};
+
+/// The structure represents a [`Records`] implementation which keeps cells column by column.
+///
+/// Unlike [`VecRecords`] the cells of a column are stored contiguously,
+/// so column operations like removing, swapping and iterating over a column are cheap.
+/// While row operations are more expensive.
+///
+/// [`VecRecords`]: crate::records::vec_records::VecRecords
+#[derive(Debug, Default, Clone)]
+pub struct ColumnRecords<T> {
+    columns: Vec<Vec<T>>,
+    size: (usize, usize),
+}
+
+impl<'a> ColumnRecords<CellInfo<'a>> {
+    /// Builds a structure instance from an iterator over rows.
+    pub fn new<R, C, T, W>(records: R, size: (usize, usize), width_ctrl: W) -> Self
+    where
+        R: IntoIterator<Item = C> + 'a,
+        C: IntoIterator<Item = T> + 'a,
+        T: AsRef<str> + 'a,
+        W: WidthFunc,
+    {
+        let (count_rows, count_columns) = size;
+        let mut columns = vec![vec![CellInfo::default(); count_rows]; count_columns];
+        let mut count_rows = count_rows;
+
+        for (row, cells) in records.into_iter().enumerate() {
+            if row >= count_rows {
+                for column in &mut columns {
+                    column.push(CellInfo::default());
+                }
+
+                count_rows += 1;
+            }
+
+            for (col, text) in cells.into_iter().enumerate().take(count_columns) {
+                let text = text.as_ref();
+                if text.is_empty() {
+                    continue;
+                }
+
+                columns[col][row] = CellInfo::new(text.to_owned(), &width_ctrl);
+            }
+        }
+
+        Self {
+            columns,
+            size: (count_rows, count_columns),
+        }
+    }
+}
+
+impl<T> ColumnRecords<T> {
+    /// Builds a structure instance from a list of columns.
+    ///
+    /// WARNING: You must verify that all columns have the same length.
+    pub fn from_columns(columns: Vec<Vec<T>>) -> Self {
+        let count_columns = columns.len();
+        let count_rows = columns.first().map_or(0, Vec::len);
+        let size = (count_rows, count_columns);
+
+        Self { columns, size }
+    }
+
+    /// Returns a shape of [`Records`].
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    /// Returns a count of rows.
+    pub fn count_rows(&self) -> usize {
+        self.size.0
+    }
+
+    /// Returns a count of columns.
+    pub fn count_columns(&self) -> usize {
+        self.size.1
+    }
+
+    /// Returns cells of a given column.
+    pub fn get_column(&self, column: usize) -> Option<&[T]> {
+        self.columns.get(column).map(Vec::as_slice)
+    }
+
+    /// Truncates columns to the given length.
+    pub fn truncate(&mut self, len: usize) {
+        if self.size.1 > len {
+            self.size.1 = len;
+            self.columns.truncate(len);
+        }
+    }
+}
+
+impl<T> From<Vec<Vec<T>>> for ColumnRecords<T> {
+    /// Converts a list of rows into [`ColumnRecords`].
+    fn from(records: Vec<Vec<T>>) -> Self {
+        let count_rows = records.len();
+        let count_columns = records.first().map_or(0, Vec::len);
+
+        let mut columns: Vec<Vec<T>> = (0..count_columns)
+            .map(|_| Vec::with_capacity(count_rows))
+            .collect();
+
+        for row in records {
+            for (column, cell) in columns.iter_mut().zip(row) {
+                column.push(cell);
+            }
+        }
+
+        let size = (count_rows, count_columns);
+
+        Self { columns, size }
+    }
+}
+
+impl<T> Records for ColumnRecords<T>
+where
+    T: Cell,
+{
+    fn count_rows(&self) -> usize {
+        self.size.0
+    }
+
+    fn count_columns(&self) -> usize {
+        self.size.1
+    }
+
+    fn get_text(&self, (row, col): Position) -> Cow<'_, str> {
+        Cow::Borrowed(self.columns[col][row].as_ref())
+    }
+
+    fn get_line(&self, (row, col): Position, i: usize) -> Cow<'_, str> {
+        Cow::Borrowed(self.columns[col][row].get_line(i))
+    }
+
+    fn get_width<W>(&self, (row, col): Position, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        self.columns[col][row].width(width_ctrl)
+    }
+
+    fn get_line_width<W>(&self, (row, col): Position, i: usize, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        self.columns[col][row].line_width(i, width_ctrl)
+    }
+
+    fn count_lines(&self, (row, col): Position) -> usize {
+        self.columns[col][row].count_lines()
+    }
+
+    fn fmt_text_prefix<F>(&self, f: &mut F, (row, col): Position) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
+        self.columns[col][row].fmt_prefix(f)
+    }
+
+    fn fmt_text_suffix<F>(&self, f: &mut F, (row, col): Position) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
+        self.columns[col][row].fmt_suffix(f)
+    }
+}
+
+impl<T, Q> RecordsMut<Q> for ColumnRecords<T>
+where
+    T: CellMut<Q>,
+{
+    fn set<W>(&mut self, (row, col): Position, text: Q, width_ctrl: W)
+    where
+        W: WidthFunc,
+    {
+        self.columns[col][row].set(text, width_ctrl);
+    }
+
+    fn update<W>(&mut self, (row, col): Position, width_ctrl: W)
+    where
+        W: WidthFunc,
+    {
+        self.columns[col][row].update(width_ctrl);
+    }
+}
+
+impl<T> Resizable for ColumnRecords<T>
+where
+    T: Default + Clone,
+{
+    fn swap(&mut self, lhs: Position, rhs: Position) {
+        if lhs.0 >= self.size.0
+            || lhs.1 >= self.size.1
+            || rhs.0 >= self.size.0
+            || rhs.1 >= self.size.1
+        {
+            return;
+        }
+
+        if lhs == rhs {
+            return;
+        }
+
+        let t = std::mem::take(&mut self.columns[lhs.1][lhs.0]);
+        let t = std::mem::replace(&mut self.columns[rhs.1][rhs.0], t);
+        let _ = std::mem::replace(&mut self.columns[lhs.1][lhs.0], t);
+    }
+
+    fn swap_row(&mut self, lhs: usize, rhs: usize) {
+        if lhs >= self.size.0 || rhs >= self.size.0 {
+            return;
+        }
+
+        for column in &mut self.columns {
+            column.swap(lhs, rhs);
+        }
+    }
+
+    fn swap_column(&mut self, lhs: usize, rhs: usize) {
+        if lhs >= self.size.1 || rhs >= self.size.1 {
+            return;
+        }
+
+        self.columns.swap(lhs, rhs);
+    }
+
+    fn push_row(&mut self) {
+        self.size.0 += 1;
+        for column in &mut self.columns {
+            column.push(T::default());
+        }
+    }
+
+    fn push_column(&mut self) {
+        self.size.1 += 1;
+        self.columns.push(vec![T::default(); self.size.0]);
+    }
+
+    fn remove_row(&mut self, row: usize) {
+        if row >= self.size.0 {
+            return;
+        }
+
+        for column in &mut self.columns {
+            column.remove(row);
+        }
+        self.size.0 -= 1;
+    }
+
+    fn remove_column(&mut self, column: usize) {
+        if column >= self.size.1 {
+            return;
+        }
+
+        self.columns.remove(column);
+        self.size.1 -= 1;
+    }
+
+    fn insert_row(&mut self, row: usize) {
+        for column in &mut self.columns {
+            column.insert(row, T::default());
+        }
+        self.size.0 += 1;
+    }
+}
+
+impl<T> Index<Position> for ColumnRecords<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Position) -> &Self::Output {
+        &self.columns[col][row]
+    }
+}
+
+impl<T> IndexMut<Position> for ColumnRecords<T> {
+    fn index_mut(&mut self, (row, col): Position) -> &mut Self::Output {
+        &mut self.columns[col][row]
+    }
+}
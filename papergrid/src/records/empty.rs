@@ -1,5 +1,7 @@
 //! An empty [`Records`] implementation.
 
+use std::borrow::Cow;
+
 use crate::{records::Records, Position};
 
 /// Empty representation of [`Records`].
@@ -25,12 +27,12 @@ impl Records for EmptyRecords {
         self.cols
     }
 
-    fn get_text(&self, _: Position) -> &str {
-        ""
+    fn get_text(&self, _: Position) -> Cow<'_, str> {
+        Cow::Borrowed("")
     }
 
-    fn get_line(&self, _: Position, _: usize) -> &str {
-        ""
+    fn get_line(&self, _: Position, _: usize) -> Cow<'_, str> {
+        Cow::Borrowed("")
     }
 
     fn get_width<W>(&self, _: Position, _: W) -> usize {
@@ -45,11 +47,17 @@ impl Records for EmptyRecords {
         1
     }
 
-    fn fmt_text_prefix(&self, _: &mut std::fmt::Formatter<'_>, _: Position) -> std::fmt::Result {
+    fn fmt_text_prefix<F>(&self, _: &mut F, _: Position) -> std::fmt::Result
+    where
+        F: std::fmt::Write + ?Sized,
+    {
         Ok(())
     }
 
-    fn fmt_text_suffix(&self, _: &mut std::fmt::Formatter<'_>, _: Position) -> std::fmt::Result {
+    fn fmt_text_suffix<F>(&self, _: &mut F, _: Position) -> std::fmt::Result
+    where
+        F: std::fmt::Write + ?Sized,
+    {
         Ok(())
     }
 }
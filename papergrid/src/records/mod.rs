@@ -2,9 +2,12 @@
 //!
 //! [`Grid`]: crate::Grid
 
+use std::borrow::Cow;
+
 use crate::{width::WidthFunc, Position};
 
 pub mod cell_info;
+pub mod column_records;
 pub mod empty;
 pub mod vec_records;
 
@@ -22,10 +25,12 @@ pub trait Records {
     fn count_columns(&self) -> usize;
 
     /// Returns a text of a cell by an index.
-    fn get_text(&self, pos: Position) -> &str;
+    ///
+    /// The text may be built on demand in which case an owned value is returned.
+    fn get_text(&self, pos: Position) -> Cow<'_, str>;
 
     /// Returns a line of a text of a cell by an index.
-    fn get_line(&self, pos: Position, i: usize) -> &str;
+    fn get_line(&self, pos: Position, i: usize) -> Cow<'_, str>;
 
     /// Returns an amount of lines of a text of a cell by an index.
     fn count_lines(&self, pos: Position) -> usize;
@@ -43,12 +48,16 @@ pub trait Records {
     /// Prints a prefix of a text of a cell by an index.
     ///
     /// Maybe be usefull in order to emit ANSI sequences.
-    fn fmt_text_prefix(&self, f: &mut std::fmt::Formatter<'_>, pos: Position) -> std::fmt::Result;
+    fn fmt_text_prefix<F>(&self, f: &mut F, pos: Position) -> std::fmt::Result
+    where
+        F: std::fmt::Write + ?Sized;
 
     /// Prints a suffix of a text of a cell by an index.
     ///
     /// Maybe be usefull in order to emit ANSI sequences.
-    fn fmt_text_suffix(&self, f: &mut std::fmt::Formatter<'_>, pos: Position) -> std::fmt::Result;
+    fn fmt_text_suffix<F>(&self, f: &mut F, pos: Position) -> std::fmt::Result
+    where
+        F: std::fmt::Write + ?Sized;
 }
 
 impl<R> Records for &R
@@ -63,11 +72,11 @@ where
         R::count_columns(self)
     }
 
-    fn get_text(&self, pos: Position) -> &str {
+    fn get_text(&self, pos: Position) -> Cow<'_, str> {
         R::get_text(self, pos)
     }
 
-    fn get_line(&self, pos: Position, i: usize) -> &str {
+    fn get_line(&self, pos: Position, i: usize) -> Cow<'_, str> {
         R::get_line(self, pos, i)
     }
 
@@ -89,11 +98,17 @@ where
         R::get_line_width(self, pos, i, width_ctrl)
     }
 
-    fn fmt_text_prefix(&self, f: &mut std::fmt::Formatter<'_>, pos: Position) -> std::fmt::Result {
+    fn fmt_text_prefix<F>(&self, f: &mut F, pos: Position) -> std::fmt::Result
+    where
+        F: std::fmt::Write + ?Sized,
+    {
         R::fmt_text_prefix(self, f, pos)
     }
 
-    fn fmt_text_suffix(&self, f: &mut std::fmt::Formatter<'_>, pos: Position) -> std::fmt::Result {
+    fn fmt_text_suffix<F>(&self, f: &mut F, pos: Position) -> std::fmt::Result
+    where
+        F: std::fmt::Write + ?Sized,
+    {
         R::fmt_text_suffix(self, f, pos)
     }
 }
@@ -1,7 +1,7 @@
 //! A [`Cell`] wrapper to be used with custom [Color]s.
 
 use std::{
-    fmt::Formatter,
+    fmt,
     ops::{Deref, DerefMut},
 };
 
@@ -74,11 +74,17 @@ where
         self.cell.line_width(i, width_ctrl)
     }
 
-    fn fmt_prefix(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt_prefix<F>(&self, f: &mut F) -> std::fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
         self.color.fmt_prefix(f)
     }
 
-    fn fmt_suffix(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt_suffix<F>(&self, f: &mut F) -> std::fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
         self.color.fmt_suffix(f)
     }
 }
@@ -1,7 +1,8 @@
 //! The module contains a [`VecRecords`] implementation of [`Records`].
 
 use std::{
-    fmt::{self, Formatter},
+    borrow::Cow,
+    fmt,
     ops::{Index, IndexMut},
 };
 
@@ -120,12 +121,12 @@ where
         self.size.1
     }
 
-    fn get_text(&self, (row, col): Position) -> &str {
-        self.records[row][col].as_ref()
+    fn get_text(&self, (row, col): Position) -> Cow<'_, str> {
+        Cow::Borrowed(self.records[row][col].as_ref())
     }
 
-    fn get_line(&self, (row, col): Position, i: usize) -> &str {
-        self.records[row][col].get_line(i)
+    fn get_line(&self, (row, col): Position, i: usize) -> Cow<'_, str> {
+        Cow::Borrowed(self.records[row][col].get_line(i))
     }
 
     fn get_width<W>(&self, (row, col): Position, width_ctrl: W) -> usize
@@ -146,19 +147,17 @@ where
         self.records[row][col].count_lines()
     }
 
-    fn fmt_text_prefix(
-        &self,
-        f: &mut std::fmt::Formatter<'_>,
-        (row, col): Position,
-    ) -> std::fmt::Result {
+    fn fmt_text_prefix<F>(&self, f: &mut F, (row, col): Position) -> std::fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
         self.records[row][col].fmt_prefix(f)
     }
 
-    fn fmt_text_suffix(
-        &self,
-        f: &mut std::fmt::Formatter<'_>,
-        (row, col): Position,
-    ) -> std::fmt::Result {
+    fn fmt_text_suffix<F>(&self, f: &mut F, (row, col): Position) -> std::fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
         self.records[row][col].fmt_suffix(f)
     }
 }
@@ -329,14 +328,20 @@ pub trait Cell: AsRef<str> {
     /// Prints a prefix.
     ///
     /// It might be usefull when used for ANSI prefix.
-    fn fmt_prefix(&self, _: &mut Formatter<'_>) -> fmt::Result {
+    fn fmt_prefix<F>(&self, _: &mut F) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
         Ok(())
     }
 
     /// Prints a suffix.
     ///
     /// It might be usefull when used for ANSI suffix.
-    fn fmt_suffix(&self, _: &mut Formatter<'_>) -> fmt::Result {
+    fn fmt_suffix<F>(&self, _: &mut F) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
         Ok(())
     }
 }
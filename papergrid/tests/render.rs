@@ -12,7 +12,7 @@
 
 use papergrid::{AlignmentHorizontal, AlignmentVertical, Entity};
 
-use crate::util::{grid, test_table};
+use crate::util::{grid, static_table, test_table};
 
 mod util;
 
@@ -177,6 +177,34 @@ test_table!(
     "+---+--------+"
 );
 
+#[test]
+fn render_into_fmt_write() {
+    let mut buf = String::new();
+    grid(2, 2).build_to(&mut buf).unwrap();
+
+    assert_eq!(buf, grid(2, 2).build());
+}
+
+#[test]
+fn render_spanned_into_fmt_write() {
+    let mut buf = String::from(">");
+    grid(2, 2)
+        .config(|cfg| cfg.set_column_span((0, 0), 2))
+        .build_to(&mut buf)
+        .unwrap();
+
+    assert_eq!(
+        buf,
+        static_table!(
+            ">+---+---+"
+            "|0-0    |"
+            "+---+---+"
+            "|1-0|1-1|"
+            "+---+---+"
+        )
+    );
+}
+
 // #[test]
 // #[ignore = "I am not sure what is the right behaiviour here"]
 // fn hieroglyph_handling() {
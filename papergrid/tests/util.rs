@@ -1,6 +1,6 @@
 #![allow(dead_code, unused_macros, unused_imports)]
 
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 use papergrid::{
     height::HeightEstimator,
@@ -66,6 +66,20 @@ impl GridBuilder {
 
         grid.to_string()
     }
+
+    pub fn build_to<F>(self, f: &mut F) -> fmt::Result
+    where
+        F: fmt::Write,
+    {
+        let mut data = records(self.size.0, self.size.1);
+        for ((row, col), text) in self.data {
+            data[row][col] = text;
+        }
+
+        let grid = build_grid(self.size.0, self.size.1, self.cfg, data);
+
+        grid.build(f)
+    }
 }
 
 fn build_grid(
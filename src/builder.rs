@@ -114,6 +114,7 @@ use std::{borrow::Cow, iter::FromIterator};
 use papergrid::{
     records::{
         cell_info::CellInfo,
+        column_records::ColumnRecords,
         vec_records::{CellMut, VecRecords},
         Records,
     },
@@ -121,7 +122,9 @@ use papergrid::{
     AlignmentHorizontal, Entity, Formatting, GridConfig, Indent, Padding,
 };
 
-use crate::{Style, Table};
+#[cfg(feature = "csv")]
+use crate::csv::CsvOptions;
+use crate::{parse::Rendered, style::RawStyle, Style, Table};
 
 /// Builder creates a [`Table`] from dynamic data set.
 ///
@@ -312,6 +315,41 @@ impl<'a> Builder<'a> {
         build_table(records)
     }
 
+    /// Build creates a [`Table`] instance which keeps the data column by column.
+    ///
+    /// It may be preferable for wide tables,
+    /// as columns operations like [`Disable`] or [`Extract`] are cheaper on it.
+    ///
+    /// ```rust
+    /// use tabled::builder::Builder;
+    ///
+    /// let mut builder = Builder::default();
+    /// builder.set_columns(["i", "column1", "column2"]);
+    /// builder.add_record(["0", "value1", "value2"]);
+    ///
+    /// let table = builder.build_columnar();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+---+---------+---------+\n\
+    ///      | i | column1 | column2 |\n\
+    ///      +---+---------+---------+\n\
+    ///      | 0 | value1  | value2  |\n\
+    ///      +---+---------+---------+"
+    /// );
+    /// ```
+    ///
+    /// [`Disable`]: crate::Disable
+    /// [`Extract`]: crate::Extract
+    pub fn build_columnar(mut self) -> Table<ColumnRecords<CellInfo<'a>>> {
+        if self.different_column_sizes_used {
+            self.fix_rows();
+        }
+
+        let records = build_columnar_grid(self.records, self.columns, self.size);
+        build_table(records)
+    }
+
     /// Add an index to the [`Table`].
     ///
     /// Default index is a range 0-N where N is amount of records.
@@ -369,6 +407,86 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Creates a Builder from a table rendered with a given style.
+    ///
+    /// A first row becomes a header.
+    /// See [`Rendered`] for details on how a text is parsed;
+    /// use it directly in order to recover column spans.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tabled::{builder::Builder, Style};
+    ///
+    /// let text = concat!(
+    ///     " id | name      \n",
+    ///     "----+-----------\n",
+    ///     " 0  | tabled    \n",
+    ///     " 1  | papergrid ",
+    /// );
+    ///
+    /// let mut table = Builder::from_rendered(text, Style::psql()).build();
+    /// table.with(Style::markdown());
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "| id | name      |\n",
+    ///         "|----|-----------|\n",
+    ///         "| 0  | tabled    |\n",
+    ///         "| 1  | papergrid |",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`Rendered`]: crate::parse::Rendered
+    pub fn from_rendered<S>(text: &str, style: S) -> Self
+    where
+        S: Into<RawStyle>,
+    {
+        Rendered::parse(text, style).into()
+    }
+
+    /// Creates a [`Builder`] from a CSV data.
+    ///
+    /// Whether a first record is a header is set by [`CsvOptions::header`].
+    /// Records may have a different number of fields.
+    ///
+    /// ```
+    /// use tabled::{
+    ///     builder::Builder,
+    ///     csv::{CsvOptions, Header},
+    /// };
+    ///
+    /// let data = "name;count\napple;10\norange;3\n";
+    ///
+    /// let options = CsvOptions::new().delimiter(b';').header(Header::Present);
+    /// let table = Builder::from_csv(data.as_bytes(), options).unwrap().build();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     concat!(
+    ///         "+--------+-------+\n",
+    ///         "| name   | count |\n",
+    ///         "+--------+-------+\n",
+    ///         "| apple  | 10    |\n",
+    ///         "+--------+-------+\n",
+    ///         "| orange | 3     |\n",
+    ///         "+--------+-------+",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`CsvOptions::header`]: crate::csv::CsvOptions::header
+    #[cfg(feature = "csv")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+    pub fn from_csv<R>(reader: R, options: CsvOptions) -> Result<Self, crate::csv::Error>
+    where
+        R: std::io::Read,
+    {
+        crate::csv::read(reader, &options)
+    }
+
     /// Creates a Builder from a built [`Records`]
     ///
     /// [`Records`]: papergrid::records::Records
@@ -916,6 +1034,27 @@ fn build_grid<'a>(
     VecRecords::with_hint(records, count_columns)
 }
 
+/// Building [`Grid`] from ordinary data keeping it column by column.
+fn build_columnar_grid<'a>(
+    records: Vec<Vec<CellInfo<'a>>>,
+    columns: Option<Vec<CellInfo<'a>>>,
+    count_columns: usize,
+) -> ColumnRecords<CellInfo<'a>> {
+    let count_rows = records.len() + usize::from(columns.is_some());
+
+    let mut data: Vec<Vec<CellInfo<'a>>> = (0..count_columns)
+        .map(|_| Vec::with_capacity(count_rows))
+        .collect();
+
+    for row in columns.into_iter().chain(records) {
+        for (column, cell) in data.iter_mut().zip(row) {
+            column.push(cell);
+        }
+    }
+
+    ColumnRecords::from_columns(data)
+}
+
 fn configure_grid(cfg: &mut GridConfig) {
     cfg.set_tab_width(4);
     cfg.set_padding(
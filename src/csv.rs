@@ -0,0 +1,369 @@
+//! This module contains a [`CsvOptions`] structure which configures CSV and TSV import and export.
+//!
+//! It's used by [`Builder::from_csv`] and [`Table::to_csv`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{builder::Builder, csv::CsvOptions, Style};
+//!
+//! let data = "id,name\n0,tabled\n1,\"papergrid, grid\"\n";
+//!
+//! let mut table = Builder::from_csv(data.as_bytes(), CsvOptions::new())
+//!     .unwrap()
+//!     .build();
+//! table.with(Style::psql());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " id | name            \n",
+//!         "----+-----------------\n",
+//!         " 0  | tabled          \n",
+//!         " 1  | papergrid, grid ",
+//!     )
+//! );
+//!
+//! let mut buf = Vec::new();
+//! table.to_csv(&mut buf, CsvOptions::tsv()).unwrap();
+//!
+//! assert_eq!(buf, b"id\tname\n0\ttabled\n1\tpapergrid, grid\n");
+//! ```
+//!
+//! [`Builder::from_csv`]: crate::builder::Builder::from_csv
+//! [`Table::to_csv`]: crate::Table::to_csv
+
+use std::io;
+
+use papergrid::{records::Records, Position};
+
+use crate::{builder::Builder, util::strip_ansi, Table};
+
+pub use ::csv::Error;
+
+/// CsvOptions configures how a [`Table`] is read from and written to CSV.
+///
+/// By default it's a comma separated format,
+/// where fields are quoted only when necessary,
+/// a header is detected, multi-line cells are kept as they are,
+/// ANSI escape sequences are stripped and text of spanned cells is not repeated.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    delimiter: u8,
+    quote: u8,
+    quote_style: Quote,
+    header: Header,
+    multiline: MultiLine,
+    strip_ansi: bool,
+    spans: SpanText,
+}
+
+impl CsvOptions {
+    /// Creates a default [`CsvOptions`] for a comma separated format.
+    pub fn new() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            quote_style: Quote::Necessary,
+            header: Header::Detect,
+            multiline: MultiLine::Keep,
+            strip_ansi: true,
+            spans: SpanText::Blank,
+        }
+    }
+
+    /// Creates a [`CsvOptions`] for a tab separated format.
+    pub fn tsv() -> Self {
+        Self::new().delimiter(b'\t')
+    }
+
+    /// Sets a field delimiter.
+    ///
+    /// By default it's `,`.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets a quote character.
+    ///
+    /// By default it's `"`.
+    pub fn quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets when fields are quoted on export.
+    ///
+    /// On import quotes are always recognized.
+    pub fn quote_style(mut self, style: Quote) -> Self {
+        self.quote_style = style;
+        self
+    }
+
+    /// Sets whether a first record is a header on import.
+    ///
+    /// A header is set by [`Builder::set_columns`].
+    ///
+    /// [`Builder::set_columns`]: crate::builder::Builder::set_columns
+    pub fn header(mut self, header: Header) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Sets how multi-line cells are handled on import and export.
+    pub fn multiline(mut self, multiline: MultiLine) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// Sets whether ANSI escape sequences, like colors, are removed on import and export.
+    ///
+    /// By default they're removed.
+    pub fn strip_ansi(mut self, strip: bool) -> Self {
+        self.strip_ansi = strip;
+        self
+    }
+
+    /// Sets how a text of spanned cells is written on export.
+    pub fn spans(mut self, spans: SpanText) -> Self {
+        self.spans = spans;
+        self
+    }
+
+    fn convert(&self, text: &str) -> String {
+        let text = if self.strip_ansi {
+            strip_ansi(text)
+        } else {
+            text.to_owned()
+        };
+
+        match &self.multiline {
+            MultiLine::Keep => text,
+            MultiLine::Join(separator) => {
+                if text.contains('\n') {
+                    text.lines().collect::<Vec<_>>().join(separator)
+                } else {
+                    text
+                }
+            }
+        }
+    }
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Quote sets when fields are quoted on export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quote {
+    /// Only fields which contain a delimiter, a quote or a new line are quoted.
+    Necessary,
+    /// All fields are quoted.
+    Always,
+    /// Fields which are not numbers are quoted.
+    NonNumeric,
+    /// No field is quoted, which may produce an invalid CSV.
+    Never,
+}
+
+/// Header sets whether a first record is a header on import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Header {
+    /// A first record is a header.
+    Present,
+    /// There's no header, all records are data.
+    Absent,
+    /// A first record is a header if it doesn't look like the rest of the records.
+    ///
+    /// It's a header when it has no empty or duplicate fields,
+    /// and most of its fields are not numbers while the values below are,
+    /// or they differ in length from the values below which all have the same length.
+    Detect,
+}
+
+/// MultiLine sets how cells with several lines are handled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultiLine {
+    /// Lines are kept, so a field is quoted.
+    Keep,
+    /// Lines are joined by a given separator.
+    Join(String),
+}
+
+/// SpanText sets how a text of spanned cells is written on export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanText {
+    /// A text is written only to a first cell of a span, the rest of the cells are empty.
+    Blank,
+    /// A text is repeated in each cell of a span.
+    Repeat,
+}
+
+pub(crate) fn read<'a, R>(reader: R, options: &CsvOptions) -> Result<Builder<'a>, Error>
+where
+    R: io::Read,
+{
+    let mut reader = ::csv::ReaderBuilder::new()
+        .delimiter(options.delimiter)
+        .quote(options.quote)
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(reader);
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        let row = record
+            .iter()
+            .map(|field| options.convert(field))
+            .collect::<Vec<_>>();
+        rows.push(row);
+    }
+
+    let has_header = match options.header {
+        Header::Present => true,
+        Header::Absent => false,
+        Header::Detect => detect_header(&rows),
+    };
+
+    let mut rows = rows.into_iter();
+    let mut builder = Builder::default();
+    if has_header {
+        if let Some(header) = rows.next() {
+            builder.set_columns(header);
+        }
+    }
+
+    for row in rows {
+        builder.add_record(row);
+    }
+
+    Ok(builder)
+}
+
+pub(crate) fn write<R, W>(table: &Table<R>, writer: W, options: &CsvOptions) -> Result<(), Error>
+where
+    R: Records,
+    W: io::Write,
+{
+    let quote_style = match options.quote_style {
+        Quote::Necessary => ::csv::QuoteStyle::Necessary,
+        Quote::Always => ::csv::QuoteStyle::Always,
+        Quote::NonNumeric => ::csv::QuoteStyle::NonNumeric,
+        Quote::Never => ::csv::QuoteStyle::Never,
+    };
+
+    let mut writer = ::csv::WriterBuilder::new()
+        .delimiter(options.delimiter)
+        .quote(options.quote)
+        .quote_style(quote_style)
+        .from_writer(writer);
+
+    let records = table.get_records();
+    let origins = span_origins(table);
+    for (row, origins) in origins.iter().enumerate() {
+        let fields = origins
+            .iter()
+            .enumerate()
+            .map(|(col, origin)| match origin {
+                None => options.convert(&records.get_text((row, col))),
+                Some(pos) => match options.spans {
+                    SpanText::Repeat => options.convert(&records.get_text(*pos)),
+                    SpanText::Blank => String::new(),
+                },
+            });
+
+        writer.write_record(fields)?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Returns a position of a spanned cell for each cell covered by it.
+fn span_origins<R>(table: &Table<R>) -> Vec<Vec<Option<Position>>>
+where
+    R: Records,
+{
+    let shape = table.shape();
+    let (count_rows, count_columns) = shape;
+    let cfg = table.get_config();
+
+    let mut origins = vec![vec![None; count_columns]; count_rows];
+
+    let spans = cfg
+        .iter_column_spans(shape)
+        .map(|(pos, _)| pos)
+        .chain(cfg.iter_row_spans(shape).map(|(pos, _)| pos))
+        .collect::<Vec<_>>();
+    for (row, col) in spans {
+        let column_span = cfg.get_column_span((row, col), shape).unwrap_or(1);
+        let row_span = cfg.get_row_span((row, col), shape).unwrap_or(1);
+
+        let rows = origins.iter_mut().enumerate().skip(row).take(row_span);
+        for (r, cells) in rows {
+            let cells = cells.iter_mut().enumerate().skip(col).take(column_span);
+            for (c, origin) in cells {
+                if (r, c) != (row, col) {
+                    *origin = Some((row, col));
+                }
+            }
+        }
+    }
+
+    origins
+}
+
+fn detect_header(rows: &[Vec<String>]) -> bool {
+    let (header, rows) = match rows.split_first() {
+        Some((header, rows)) if !rows.is_empty() => (header, rows),
+        _ => return false,
+    };
+
+    let is_unique = header
+        .iter()
+        .enumerate()
+        .all(|(i, field)| !header[..i].contains(field));
+    if header.iter().any(|field| field.trim().is_empty()) || !is_unique {
+        return false;
+    }
+
+    let mut votes = 0;
+    for (col, field) in header.iter().enumerate() {
+        let values = rows
+            .iter()
+            .filter_map(|row| row.get(col))
+            .filter(|value| !value.is_empty())
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            continue;
+        }
+
+        if values.iter().all(|value| is_number(value)) {
+            votes += if is_number(field) { -1 } else { 1 };
+            continue;
+        }
+
+        let length = values[0].chars().count();
+        if values.iter().all(|value| value.chars().count() == length) {
+            votes += if field.chars().count() == length {
+                -1
+            } else {
+                1
+            };
+        }
+    }
+
+    votes > 0
+}
+
+fn is_number(text: &str) -> bool {
+    text.trim().parse::<f64>().is_ok()
+}
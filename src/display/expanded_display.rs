@@ -90,11 +90,15 @@ impl ExpandedDisplay {
     {
         let data = iter
             .into_iter()
-            .map(|i| {
-                i.fields()
+            .flat_map(|i| {
+                i.rows()
                     .into_iter()
-                    .map(|s| s.escape_debug().to_string())
-                    .collect()
+                    .map(|row| {
+                        row.into_iter()
+                            .map(|s| s.escape_debug().to_string())
+                            .collect()
+                    })
+                    .collect::<Vec<_>>()
             })
             .collect();
         let header = T::headers()
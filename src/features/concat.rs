@@ -134,7 +134,7 @@ where
 
                 for row in 0..rhs.shape().0 {
                     for col in 0..rhs.shape().1 {
-                        let text = rhs.get_records().get_text((row, col)).to_owned();
+                        let text = rhs.get_records().get_text((row, col)).into_owned();
                         let col = col + count_cols;
                         lhs.get_records_mut().set((row, col), text, &ctrl);
                     }
@@ -156,7 +156,7 @@ where
 
                 for row in 0..rhs.shape().0 {
                     for col in 0..rhs.shape().1 {
-                        let text = rhs.get_records().get_text((row, col)).to_owned();
+                        let text = rhs.get_records().get_text((row, col)).into_owned();
                         let row = row + count_rows;
                         lhs.get_records_mut().set((row, col), text, &ctrl);
                     }
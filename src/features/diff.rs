@@ -0,0 +1,367 @@
+//! This module contains a [`TableDiff`] structure which shows a difference of 2 [`Table`]s.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(not(feature = "color"))]
+//! # {
+//! use std::iter::FromIterator;
+//! use tabled::{builder::Builder, locator::ByColumnName, Style, TableDiff};
+//!
+//! let old = Builder::from_iter([
+//!     ["id", "name", "size"],
+//!     ["0", "tabled", "1,024"],
+//!     ["1", "papergrid", "512"],
+//! ])
+//! .build();
+//!
+//! let new = Builder::from_iter([
+//!     ["id", "name", "size"],
+//!     ["0", "tabled", "2,048"],
+//!     ["2", "json_to_table", "64"],
+//! ])
+//! .build();
+//!
+//! let mut table = TableDiff::new(&old, &new)
+//!     .key(ByColumnName::new("id"))
+//!     .build();
+//! table.with(Style::psql());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "   | id | name          | size          \n",
+//!         "---+----+---------------+---------------\n",
+//!         " ~ | 0  | tabled        | 1,024 → 2,048 \n",
+//!         " - | 1  | papergrid     | 512           \n",
+//!         " + | 2  | json_to_table | 64            ",
+//!     )
+//! );
+//! # }
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::{borrow::Cow, collections::HashMap};
+
+use papergrid::records::Records;
+
+use crate::{
+    builder::Builder,
+    locator::{locate_column, Locator},
+    object::Column,
+    Table,
+};
+
+/// TableDiff builds a [`Table`] which shows a difference between an old and a new table.
+///
+/// Columns are matched by their names in a header, which is a first row.
+/// Rows are matched by position, or by a key column if it's set via [`TableDiff::key`].
+/// Removed rows and columns are put after the ones they followed in the old table.
+///
+/// A changed cell is shown as `old → new`.
+///
+/// Without the `color` feature added, removed and changed rows are marked by `+`, `-` and `~`
+/// in a first column, and names of added and removed columns are prefixed by `+` and `-`.
+/// With the `color` feature they are colored green, red and yellow instead.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug)]
+pub struct TableDiff<'a, A, B, L = Column> {
+    old: &'a Table<A>,
+    new: &'a Table<B>,
+    key: Option<L>,
+}
+
+impl<'a, A, B> TableDiff<'a, A, B> {
+    /// Creates a [`TableDiff`] which matches rows by position.
+    pub fn new(old: &'a Table<A>, new: &'a Table<B>) -> Self {
+        Self {
+            old,
+            new,
+            key: None,
+        }
+    }
+}
+
+impl<'a, A, B, L> TableDiff<'a, A, B, L> {
+    /// Sets a key column by which rows are matched.
+    ///
+    /// The column is located in each of the tables, so it's better to use [`ByColumnName`].
+    /// If several rows have the same key they're matched in order.
+    ///
+    /// [`ByColumnName`]: crate::locator::ByColumnName
+    pub fn key<K>(self, locator: K) -> TableDiff<'a, A, B, K>
+    where
+        K: Locator<Coordinate = usize>,
+    {
+        TableDiff {
+            old: self.old,
+            new: self.new,
+            key: Some(locator),
+        }
+    }
+
+    /// Builds a [`Table`] with the difference.
+    ///
+    /// [`Table`]: crate::Table
+    pub fn build(&mut self) -> Table
+    where
+        A: Records,
+        B: Records,
+        L: Locator<Coordinate = usize>,
+    {
+        let old = self.old.get_records();
+        let new = self.new.get_records();
+
+        let columns = match_columns(old, new);
+        let rows = match &mut self.key {
+            Some(key) => {
+                let old_key = locate_column(key, old);
+                let new_key = locate_column(key, new);
+                match (old_key, new_key) {
+                    (Some(old_key), Some(new_key)) => match_rows_by_key(old, new, old_key, new_key),
+                    _ => match_rows(old, new),
+                }
+            }
+            None => match_rows(old, new),
+        };
+
+        if columns.is_empty() {
+            return Builder::default().build();
+        }
+
+        let mut builder = Builder::default();
+
+        let header = columns.iter().map(|&(old_col, new_col)| {
+            let name = match new_col {
+                Some(col) => new.get_text((0, col)),
+                None => old_col.map_or(Cow::Borrowed(""), |col| old.get_text((0, col))),
+            };
+
+            let change = match (old_col, new_col) {
+                (Some(_), None) => Change::Removed,
+                (None, Some(_)) => Change::Added,
+                _ => Change::None,
+            };
+
+            mark_column(name.into_owned(), change)
+        });
+        builder.add_record(mark_row(header.collect(), Change::None));
+
+        for (old_row, new_row) in rows {
+            let mut change = match (old_row, new_row) {
+                (Some(_), None) => Change::Removed,
+                (None, Some(_)) => Change::Added,
+                _ => Change::None,
+            };
+
+            let mut cells = Vec::with_capacity(columns.len());
+            for &(old_col, new_col) in &columns {
+                let old_text = old_row.zip(old_col).map(|pos| old.get_text(pos));
+                let new_text = new_row.zip(new_col).map(|pos| new.get_text(pos));
+
+                let cell = match (old_text, new_text) {
+                    (Some(old_text), Some(new_text)) if old_text != new_text => {
+                        change = Change::Changed;
+                        mark_cell(format!("{} → {}", old_text, new_text), Change::Changed)
+                    }
+                    (_, Some(text)) | (Some(text), None) => text.into_owned(),
+                    (None, None) => String::new(),
+                };
+
+                cells.push(cell);
+            }
+
+            builder.add_record(mark_row(cells, change));
+        }
+
+        builder.build()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change {
+    None,
+    Added,
+    Removed,
+    Changed,
+}
+
+#[cfg(not(feature = "color"))]
+fn mark_column(name: String, change: Change) -> String {
+    match change {
+        Change::Added => format!("+ {}", name),
+        Change::Removed => format!("- {}", name),
+        Change::None | Change::Changed => name,
+    }
+}
+
+#[cfg(not(feature = "color"))]
+fn mark_row(cells: Vec<String>, change: Change) -> Vec<String> {
+    let marker = match change {
+        Change::None => "",
+        Change::Added => "+",
+        Change::Removed => "-",
+        Change::Changed => "~",
+    };
+
+    std::iter::once(marker.to_owned()).chain(cells).collect()
+}
+
+#[cfg(not(feature = "color"))]
+fn mark_cell(text: String, _: Change) -> String {
+    text
+}
+
+#[cfg(feature = "color")]
+fn mark_column(name: String, change: Change) -> String {
+    paint(name, change)
+}
+
+#[cfg(feature = "color")]
+fn mark_row(cells: Vec<String>, change: Change) -> Vec<String> {
+    match change {
+        Change::Added | Change::Removed => cells.into_iter().map(|c| paint(c, change)).collect(),
+        Change::None | Change::Changed => cells,
+    }
+}
+
+#[cfg(feature = "color")]
+fn mark_cell(text: String, change: Change) -> String {
+    paint(text, change)
+}
+
+#[cfg(feature = "color")]
+fn paint(text: String, change: Change) -> String {
+    use papergrid::{AnsiColor, Color};
+
+    // papergrid has no named colors, so a foreground color is set by its SGR code
+    let prefix = match change {
+        Change::None => return text,
+        Change::Added => "\u{1b}[32m",
+        Change::Removed => "\u{1b}[31m",
+        Change::Changed => "\u{1b}[33m",
+    };
+
+    let color = AnsiColor::new(prefix.to_owned(), "\u{1b}[39m".to_owned());
+
+    let mut buf = String::new();
+    color.colorize(&mut buf, text).unwrap();
+
+    buf
+}
+
+/// Matches columns by names in a first row.
+///
+/// Returns pairs of an old and a new column.
+fn match_columns<A, B>(old: A, new: B) -> Vec<(Option<usize>, Option<usize>)>
+where
+    A: Records,
+    B: Records,
+{
+    let (old_count_columns, new_count_columns) = (old.count_columns(), new.count_columns());
+    if old.count_rows() == 0 || new.count_rows() == 0 {
+        let old_columns = (0..old_count_columns).map(|col| (Some(col), None));
+        let new_columns = (0..new_count_columns).map(|col| (None, Some(col)));
+        return old_columns.chain(new_columns).collect();
+    }
+
+    let mut used = vec![false; old_count_columns];
+    let matches = (0..new_count_columns)
+        .map(|col| {
+            let name = new.get_text((0, col));
+            let found = (0..old_count_columns).find(|&c| !used[c] && old.get_text((0, c)) == name);
+            if let Some(c) = found {
+                used[c] = true;
+            }
+
+            found
+        })
+        .collect::<Vec<_>>();
+
+    merge(&matches, old_count_columns)
+}
+
+/// Matches data rows by position.
+///
+/// Returns pairs of an old and a new row.
+fn match_rows<A, B>(old: A, new: B) -> Vec<(Option<usize>, Option<usize>)>
+where
+    A: Records,
+    B: Records,
+{
+    let count_rows = old.count_rows().max(new.count_rows());
+    (1..count_rows)
+        .map(|row| {
+            let old_row = Some(row).filter(|&row| row < old.count_rows());
+            let new_row = Some(row).filter(|&row| row < new.count_rows());
+            (old_row, new_row)
+        })
+        .collect()
+}
+
+/// Matches data rows by a key column.
+///
+/// Returns pairs of an old and a new row.
+fn match_rows_by_key<A, B>(
+    old: A,
+    new: B,
+    old_key: usize,
+    new_key: usize,
+) -> Vec<(Option<usize>, Option<usize>)>
+where
+    A: Records,
+    B: Records,
+{
+    let mut keys: HashMap<Cow<'_, str>, Vec<usize>> = HashMap::new();
+    for row in (1..old.count_rows()).rev() {
+        keys.entry(old.get_text((row, old_key)))
+            .or_default()
+            .push(row);
+    }
+
+    let matches = (1..new.count_rows())
+        .map(|row| {
+            keys.get_mut(&new.get_text((row, new_key)))
+                .and_then(|rows| rows.pop())
+                .map(|row| row - 1)
+        })
+        .collect::<Vec<_>>();
+
+    merge(&matches, old.count_rows().saturating_sub(1))
+        .into_iter()
+        .map(|(old_row, new_row)| (old_row.map(|row| row + 1), new_row.map(|row| row + 1)))
+        .collect()
+}
+
+/// Merges new entries with old ones.
+///
+/// Old entries which have no match are put right after the entry which preceded them in the old list.
+fn merge(matches: &[Option<usize>], count_old: usize) -> Vec<(Option<usize>, Option<usize>)> {
+    let mut positions = vec![None; count_old];
+    for (new, &old) in matches.iter().enumerate() {
+        if let Some(old) = old {
+            positions[old] = Some(new);
+        }
+    }
+
+    // removed entries which go before any new entry and after each of them
+    let mut removed = vec![Vec::new(); matches.len() + 1];
+    let mut slot = 0;
+    for (old, position) in positions.into_iter().enumerate() {
+        match position {
+            Some(new) => slot = new + 1,
+            None => removed[slot].push(old),
+        }
+    }
+
+    let mut list = Vec::with_capacity(matches.len() + count_old);
+    list.extend(removed[0].iter().map(|&old| (Some(old), None)));
+    for (new, &old) in matches.iter().enumerate() {
+        list.push((old, Some(new)));
+        list.extend(removed[new + 1].iter().map(|&old| (Some(old), None)));
+    }
+
+    list
+}
@@ -0,0 +1,249 @@
+//! This module contains a [`Filter`] structure which removes rows of a [`Table`] by their content.
+//!
+//! # Example
+//!
+//! ```
+//! use std::iter::FromIterator;
+//! use tabled::{builder::Builder, locator::ByColumnName, Filter, Style};
+//!
+//! let data = [
+//!     ["service", "status"],
+//!     ["api", "ok"],
+//!     ["db", "down"],
+//!     ["cache", "ok"],
+//! ];
+//!
+//! let mut table = Builder::from_iter(data).build();
+//! table
+//!     .with(Filter::column(ByColumnName::new("status"), |status| status != "ok"))
+//!     .with(Style::psql());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " service | status \n",
+//!         "---------+--------\n",
+//!         " db      | down   ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::borrow::Cow;
+
+use papergrid::records::{Records, Resizable};
+
+use crate::{locator::Locator, Table, TableOption};
+
+/// Filter removes rows of a [`Table`] for which a predicate returns `false`.
+///
+/// The header, which is a first row by default, is never removed.
+///
+/// Spans are adjusted to the rows which are left.
+/// If a first row of a vertical span is removed the content of the span is moved to the next row.
+///
+/// ```
+/// use tabled::{Filter, Table, Style};
+///
+/// let data = [1, 2, 3, 4];
+///
+/// let mut table = Table::new(data);
+/// table
+///     .with(Filter::rows(|row| row[0] != "2"))
+///     .with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " i32 \n",
+///         "-----\n",
+///         " 1   \n",
+///         " 3   \n",
+///         " 4   ",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug)]
+pub struct Filter<L, F> {
+    locator: L,
+    predicate: F,
+    header: usize,
+}
+
+impl<F> Filter<(), F> {
+    /// Creates a [`Filter`] with a predicate which takes all cells of a row.
+    ///
+    /// A row is kept if the predicate returns `true`.
+    pub fn rows(predicate: F) -> Self
+    where
+        F: FnMut(&[&str]) -> bool,
+    {
+        Self {
+            locator: (),
+            predicate,
+            header: 1,
+        }
+    }
+}
+
+impl<L, F> Filter<L, F> {
+    /// Creates a [`Filter`] with a predicate which takes a cell of a given column.
+    ///
+    /// A row is kept if the predicate returns `true`.
+    /// If the locator finds several columns the row is kept only when the predicate returns `true` for each of them.
+    ///
+    /// Available locators are:
+    ///
+    /// - [`Columns`]
+    /// - [`Column`]
+    /// - [`FirstColumn`]
+    /// - [`LastColumn`]
+    /// - [`ByColumnName`]
+    ///
+    /// [`Columns`]: crate::object::Columns
+    /// [`Column`]: crate::object::Column
+    /// [`FirstColumn`]: crate::object::FirstColumn
+    /// [`LastColumn`]: crate::object::LastColumn
+    /// [`ByColumnName`]: crate::locator::ByColumnName
+    pub fn column(locator: L, predicate: F) -> Self
+    where
+        L: Locator<Coordinate = usize>,
+        F: FnMut(&str) -> bool,
+    {
+        Self {
+            locator,
+            predicate,
+            header: 1,
+        }
+    }
+
+    /// Sets a number of first rows which are never removed.
+    ///
+    /// By default it's 1.
+    pub fn header(mut self, count_rows: usize) -> Self {
+        self.header = count_rows;
+        self
+    }
+}
+
+impl<F, R> TableOption<R> for Filter<(), F>
+where
+    F: FnMut(&[&str]) -> bool,
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_columns) = table.shape();
+        let records = table.get_records();
+
+        let mut keep = vec![true; count_rows];
+        for (row, is_kept) in keep.iter_mut().enumerate().skip(self.header) {
+            let texts = (0..count_columns)
+                .map(|col| records.get_text((row, col)))
+                .collect::<Vec<_>>();
+            let texts = texts.iter().map(Cow::as_ref).collect::<Vec<_>>();
+
+            *is_kept = (self.predicate)(&texts);
+        }
+
+        retain_rows(table, &keep);
+    }
+}
+
+impl<L, F, R> TableOption<R> for Filter<L, F>
+where
+    L: Locator<Coordinate = usize>,
+    F: FnMut(&str) -> bool,
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_columns) = table.shape();
+        let records = table.get_records();
+
+        let columns = self
+            .locator
+            .locate(records)
+            .into_iter()
+            .filter(|&col| col < count_columns)
+            .collect::<Vec<_>>();
+
+        let mut keep = vec![true; count_rows];
+        for (row, is_kept) in keep.iter_mut().enumerate().skip(self.header) {
+            *is_kept = columns
+                .iter()
+                .all(|&col| (self.predicate)(&records.get_text((row, col))));
+        }
+
+        retain_rows(table, &keep);
+    }
+}
+
+/// Removes rows which are not marked to be kept, adjusting spans.
+fn retain_rows<R>(table: &mut Table<R>, keep: &[bool])
+where
+    R: Records + Resizable,
+{
+    if keep.iter().all(|&is_kept| is_kept) {
+        return;
+    }
+
+    let shape = table.shape();
+
+    let mut index = vec![0; shape.0];
+    let mut count_kept = 0;
+    for (row, &is_kept) in keep.iter().enumerate() {
+        index[row] = count_kept;
+        if is_kept {
+            count_kept += 1;
+        }
+    }
+
+    let row_spans = table.get_config().iter_row_spans(shape).collect::<Vec<_>>();
+    let column_spans = table
+        .get_config()
+        .iter_column_spans(shape)
+        .collect::<Vec<_>>();
+
+    let mut spans = Vec::new();
+    for &((row, col), span) in &row_spans {
+        let mut rows = (row..row + span).filter(|&r| keep[r]);
+        if let Some(first) = rows.next() {
+            if first != row {
+                table.get_records_mut().swap((first, col), (row, col));
+            }
+
+            spans.push(((index[first], col), 1 + rows.count()));
+        }
+    }
+
+    let cfg = table.get_config_mut();
+    for &(pos, _) in &row_spans {
+        cfg.set_row_span(pos, 1);
+    }
+
+    for &(pos, _) in &column_spans {
+        cfg.set_column_span(pos, 1);
+    }
+
+    for (pos, span) in spans {
+        cfg.set_row_span(pos, span);
+    }
+
+    for ((row, col), span) in column_spans {
+        if keep[row] {
+            cfg.set_column_span((index[row], col), span);
+        }
+    }
+
+    let records = table.get_records_mut();
+    for row in (0..shape.0).rev() {
+        if !keep[row] {
+            records.remove_row(row);
+        }
+    }
+
+    table.destroy_width_cache();
+    table.destroy_height_cache();
+}
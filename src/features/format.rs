@@ -137,7 +137,7 @@ where
         for pos in entity.iter(count_rows, count_cols) {
             let records = table.get_records();
             let content = records.get_text(pos);
-            let content = (self.f)(content);
+            let content = (self.f)(&content);
             table.get_records_mut().set(pos, content, &width_fn);
         }
 
@@ -174,7 +174,7 @@ where
         for pos in entity.iter(count_rows, count_cols) {
             let records = table.get_records();
             let content = records.get_text(pos);
-            let content = (self.f)(content, pos);
+            let content = (self.f)(&content, pos);
             table.get_records_mut().set(pos, content, &width_fn);
         }
 
@@ -0,0 +1,282 @@
+//! This module contains a [`GroupBy`] structure which splits rows of a [`Table`] into sections.
+//!
+//! # Example
+//!
+//! ```
+//! use std::iter::FromIterator;
+//! use tabled::{builder::Builder, locator::ByColumnName, GroupBy, Style};
+//!
+//! let data = [
+//!     ["region", "service", "status"],
+//!     ["eu", "api", "ok"],
+//!     ["us", "db", "down"],
+//!     ["eu", "cache", "ok"],
+//! ];
+//!
+//! let mut table = Builder::from_iter(data).build();
+//! table
+//!     .with(Style::psql())
+//!     .with(GroupBy::column(ByColumnName::new("region")));
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " service | status \n",
+//!         "---------+--------\n",
+//!         " eu               \n",
+//!         " api     | ok     \n",
+//!         " cache   | ok     \n",
+//!         " us               \n",
+//!         " db      | down   ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::{borrow::Cow, collections::HashMap};
+
+use papergrid::records::{Records, RecordsMut, Resizable};
+
+use crate::{
+    features::panel::{move_row_spans, move_rows_aside, set_text},
+    locator::{locate_column, Locator},
+    object::Columns,
+    sort::reorder_rows,
+    style::{HorizontalLine, Line},
+    summary::{Aggregates, Summary},
+    Disable, Panel, Reorder, Table, TableOption,
+};
+
+/// GroupBy groups rows of a [`Table`] by a value of a key column.
+///
+/// Rows are reordered stably, so groups follow in order of the first appearance of their key.
+/// The key column is removed and each group gets a [`Panel`] with the key above it.
+/// Optionally a group can be followed by subtotal rows made by a [`Summary`].
+///
+/// A header, which is a first row by default, is kept in place.
+///
+/// Notice that only a content of rows is moved, settings set for particular cells are kept in place.
+/// Lines are set by [`GroupBy`] itself, so a [`Style`] must be applied before it.
+///
+/// ```
+/// use std::iter::FromIterator;
+/// use tabled::{
+///     builder::Builder, object::Columns, style::Line, GroupBy, Style, Summary,
+/// };
+///
+/// let data = [
+///     ["region", "service", "cost"],
+///     ["eu", "api", "10"],
+///     ["us", "db", "30"],
+///     ["eu", "cache", "5"],
+/// ];
+///
+/// let mut table = Builder::from_iter(data).build();
+/// table.with(Style::psql()).with(
+///     GroupBy::column(Columns::first())
+///         .subtotal(Summary::new().label(0, "total").sum(Columns::single(1)))
+///         .line(Line::new(Some('-'), Some('-'), None, None)),
+/// );
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " service | cost \n",
+///         "---------+------\n",
+///         " eu             \n",
+///         " api     | 10   \n",
+///         " cache   | 5    \n",
+///         " total   | 15   \n",
+///         "----------------\n",
+///         " us             \n",
+///         " db      | 30   \n",
+///         " total   | 30   ",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`Panel`]: crate::Panel
+/// [`Style`]: crate::Style
+#[derive(Debug)]
+pub struct GroupBy<L, A = ()> {
+    locator: L,
+    header: usize,
+    subtotal: Option<Summary<A>>,
+    line: Option<Line>,
+}
+
+impl<L> GroupBy<L> {
+    /// Creates a [`GroupBy`] by a given key column.
+    ///
+    /// If the locator finds several columns the first one is used.
+    ///
+    /// Available locators are:
+    ///
+    /// - [`Columns`]
+    /// - [`Column`]
+    /// - [`FirstColumn`]
+    /// - [`LastColumn`]
+    /// - [`ByColumnName`]
+    ///
+    /// [`Columns`]: crate::object::Columns
+    /// [`Column`]: crate::object::Column
+    /// [`FirstColumn`]: crate::object::FirstColumn
+    /// [`LastColumn`]: crate::object::LastColumn
+    /// [`ByColumnName`]: crate::locator::ByColumnName
+    pub fn column(locator: L) -> Self
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        Self {
+            locator,
+            header: 1,
+            subtotal: None,
+            line: None,
+        }
+    }
+}
+
+impl<L, A> GroupBy<L, A> {
+    /// Sets a number of first rows which are not grouped.
+    ///
+    /// By default it's 1.
+    pub fn header(mut self, count_rows: usize) -> Self {
+        self.header = count_rows;
+        self
+    }
+
+    /// Sets subtotal rows which are put after each group.
+    ///
+    /// Columns of the [`Summary`] are located after the key column is removed.
+    /// A header setting of the [`Summary`] is ignored.
+    pub fn subtotal<B>(self, summary: Summary<B>) -> GroupBy<L, B> {
+        GroupBy {
+            locator: self.locator,
+            header: self.header,
+            subtotal: Some(summary),
+            line: self.line,
+        }
+    }
+
+    /// Sets a horizontal line between groups.
+    pub fn line(mut self, line: Line) -> Self {
+        self.line = Some(line);
+        self
+    }
+}
+
+impl<L, A, R> TableOption<R> for GroupBy<L, A>
+where
+    L: Locator<Coordinate = usize>,
+    A: Aggregates,
+    R: Records + RecordsMut<String> + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_columns) = table.shape();
+        let key = match locate_column(&mut self.locator, table.get_records()) {
+            Some(key) => key,
+            None => return,
+        };
+
+        let header = self.header.min(count_rows);
+        let groups = group_rows(table.get_records(), key, header);
+
+        let order = groups
+            .iter()
+            .flat_map(|(_, rows)| rows.iter().copied())
+            .collect::<Vec<_>>();
+        reorder_rows(table.get_records_mut(), header, &order);
+
+        // a single column is kept so the panels have something to span
+        if count_columns > 1 {
+            Reorder::move_column(Columns::single(key), count_columns).change(table);
+            Disable::column(Columns::last()).change(table);
+        }
+
+        // resolve subtotals before any row is inserted
+        let mut subtotals = Vec::with_capacity(groups.len());
+        let mut start = header;
+        for (_, rows) in &groups {
+            let end = start + rows.len();
+            let subtotal = match &mut self.subtotal {
+                Some(summary) => summary.build(table.get_records(), start..end),
+                None => (0, Vec::new()),
+            };
+
+            subtotals.push(subtotal);
+            start = end;
+        }
+
+        // rows are inserted from the end so positions of previous groups are kept
+        let mut end = count_rows;
+        for ((text, rows), (count_subtotal_rows, texts)) in groups.iter().zip(&subtotals).rev() {
+            for _ in 0..*count_subtotal_rows {
+                insert_row(table, end);
+            }
+
+            for &((row, col), ref text) in texts {
+                set_text(table, (end + row, col), text.clone());
+            }
+
+            let start = end - rows.len();
+            Panel::horizontal(start).text(text).change(table);
+
+            end = start;
+        }
+
+        let summary_line = self.subtotal.as_ref().and_then(Summary::get_line);
+        let mut row = header;
+        for (i, ((_, rows), (count_subtotal_rows, _))) in groups.iter().zip(&subtotals).enumerate()
+        {
+            if i > 0 {
+                if let Some(line) = self.line {
+                    HorizontalLine::new(row, line).change(table);
+                }
+            }
+
+            row += 1 + rows.len();
+
+            if *count_subtotal_rows > 0 {
+                if let Some(line) = summary_line {
+                    HorizontalLine::new(row, line).change(table);
+                }
+            }
+
+            row += count_subtotal_rows;
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+/// Returns keys along with their rows in order of the first appearance.
+fn group_rows<R>(records: R, key: usize, header: usize) -> Vec<(String, Vec<usize>)>
+where
+    R: Records,
+{
+    let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+    let mut index: HashMap<Cow<'_, str>, usize> = HashMap::new();
+    for row in header..records.count_rows() {
+        let text = records.get_text((row, key));
+        match index.get(&text) {
+            Some(&i) => groups[i].1.push(row),
+            None => {
+                groups.push((text.to_string(), vec![row]));
+                index.insert(text, groups.len() - 1);
+            }
+        }
+    }
+
+    groups
+}
+
+fn insert_row<R>(table: &mut Table<R>, row: usize)
+where
+    R: Records + Resizable,
+{
+    move_rows_aside(table, row);
+    move_row_spans(table, row);
+}
@@ -55,7 +55,7 @@ where
             }
 
             let content = records.get_text(pos);
-            let content = add_lines(content, height - cell_height);
+            let content = add_lines(&content, height - cell_height);
             let ctrl = CfgWidthFunction::from_cfg(table.get_config());
             table.get_records_mut().set(pos, content, &ctrl);
         }
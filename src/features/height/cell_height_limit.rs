@@ -55,7 +55,7 @@ where
             }
 
             let content = records.get_text(pos);
-            let content = limit_lines(content, height);
+            let content = limit_lines(&content, height);
             let ctrl = CfgWidthFunction::from_cfg(table.get_config());
             table.get_records_mut().set(pos, content, &ctrl);
         }
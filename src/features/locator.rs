@@ -30,6 +30,19 @@ pub trait Locator {
         R: Records;
 }
 
+/// Returns a first column found by a locator which exists in the [`Records`].
+pub(crate) fn locate_column<L, R>(locator: &mut L, records: R) -> Option<usize>
+where
+    L: Locator<Coordinate = usize>,
+    R: Records,
+{
+    let count_columns = records.count_columns();
+    locator
+        .locate(records)
+        .into_iter()
+        .find(|&col| col < count_columns)
+}
+
 impl<B> Locator for Columns<B>
 where
     B: RangeBounds<usize>,
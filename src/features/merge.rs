@@ -70,7 +70,7 @@ where
                     }
 
                     repeat_length = 1;
-                    repeat_value = table.get_records().get_text((row, column)).to_owned();
+                    repeat_value = table.get_records().get_text((row, column)).into_owned();
                     repeat_is_set = true;
                     continue;
                 }
@@ -101,7 +101,7 @@ where
                 }
 
                 repeat_length = 1;
-                repeat_value = table.get_records().get_text((row, column)).to_owned();
+                repeat_value = table.get_records().get_text((row, column)).into_owned();
             }
 
             if repeat_length > 1 {
@@ -164,7 +164,7 @@ where
                     }
 
                     repeat_length = 1;
-                    repeat_value = table.get_records().get_text((row, column)).to_owned();
+                    repeat_value = table.get_records().get_text((row, column)).into_owned();
                     repeat_is_set = true;
                     continue;
                 }
@@ -195,7 +195,7 @@ where
                 }
 
                 repeat_length = 1;
-                repeat_value = table.get_records().get_text((row, column)).to_owned();
+                repeat_value = table.get_records().get_text((row, column)).into_owned();
             }
 
             if repeat_length > 1 {
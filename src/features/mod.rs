@@ -7,7 +7,10 @@ pub mod highlight;
 pub mod locator;
 pub mod measurment;
 pub mod peaker;
+pub mod pivot;
+pub mod sort;
 pub mod style;
+pub mod summary;
 pub mod width;
 
 #[cfg(feature = "color")]
@@ -18,11 +21,17 @@ pub mod margin_color;
 pub mod padding_color;
 
 pub(crate) mod concat;
+pub(crate) mod diff;
 pub(crate) mod disable;
 pub(crate) mod extract;
+pub(crate) mod filter;
+pub(crate) mod group_by;
 pub(crate) mod margin;
 pub mod merge;
 pub(crate) mod padding;
+pub(crate) mod pagination;
 pub(crate) mod panel;
+pub(crate) mod reorder;
 pub(crate) mod rotate;
 pub(crate) mod span;
+pub(crate) mod split;
@@ -0,0 +1,363 @@
+//! This module contains a [`Pagination`] which splits a [`Table`] into pages of a limited height.
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{Table, Style};
+//!
+//! let data = ["Hello", "World", "!"];
+//!
+//! let mut table = Table::new(data);
+//! table.with(Style::modern());
+//!
+//! let pages = table.paginate(7);
+//!
+//! assert_eq!(pages.len(), 2);
+//! assert_eq!(
+//!     pages[0].to_string(),
+//!     concat!(
+//!         "┌───────┐\n",
+//!         "│ &str  │\n",
+//!         "├───────┤\n",
+//!         "│ Hello │\n",
+//!         "├───────┤\n",
+//!         "│ World │\n",
+//!         "└───────┘",
+//!     )
+//! );
+//! assert_eq!(
+//!     pages[1].to_string(),
+//!     concat!(
+//!         "┌───────┐\n",
+//!         "│ &str  │\n",
+//!         "├───────┤\n",
+//!         "│ !     │\n",
+//!         "└───────┘",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, Resizable},
+    Entity, GridConfig,
+};
+
+use crate::{util::copy_cell_settings, Table};
+
+/// Pagination splits a [`Table`] into a list of tables,
+/// each of which is not higher than a given number of lines.
+///
+/// Every page has its own borders and repeats the header rows,
+/// which is a first row by default.
+/// A page break never happens inside a row, so a multiline cell or a row span is never split.
+/// If a row doesn't fit into a page alone it's put on a separate page anyway.
+///
+/// All pages keep the columns widths of the original table.
+///
+/// Notice that borders set for particular cells are not moved along with their rows.
+///
+/// ```
+/// use tabled::{Table, Pagination, Panel, Style};
+///
+/// let data = [1, 2, 3, 4];
+///
+/// let mut table = Table::new(data);
+/// table
+///     .with(Style::psql())
+///     .with(Panel::header("Numbers"))
+///     .with(Panel::footer("End"));
+///
+/// let pages = Pagination::new(6).header(2).footer(1).paginate(&table);
+///
+/// assert_eq!(pages.len(), 2);
+/// assert_eq!(
+///     pages[1].to_string(),
+///     concat!(
+///         " Numbers \n",
+///         "---------\n",
+///         " i32     \n",
+///         " 3       \n",
+///         " 4       \n",
+///         " End     ",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Clone)]
+pub struct Pagination {
+    max_height: usize,
+    header: usize,
+    footer: usize,
+}
+
+impl Pagination {
+    /// Creates a new [`Pagination`] with a max height of a page.
+    ///
+    /// By default a first row is repeated on each page.
+    pub fn new(max_height: usize) -> Self {
+        Self {
+            max_height,
+            header: 1,
+            footer: 0,
+        }
+    }
+
+    /// Sets a number of first rows which are repeated on each page.
+    ///
+    /// It may be useful in case a [`Panel::header`] is used.
+    ///
+    /// [`Panel::header`]: crate::Panel::header
+    pub fn header(mut self, count_rows: usize) -> Self {
+        self.header = count_rows;
+        self
+    }
+
+    /// Sets a number of last rows which are repeated on each page.
+    ///
+    /// It may be useful in case a [`Panel::footer`] is used.
+    ///
+    /// [`Panel::footer`]: crate::Panel::footer
+    pub fn footer(mut self, count_rows: usize) -> Self {
+        self.footer = count_rows;
+        self
+    }
+
+    /// Splits a [`Table`] into pages.
+    ///
+    /// [`Table`]: crate::Table
+    pub fn paginate<R>(&self, table: &Table<R>) -> Vec<Table<R>>
+    where
+        R: Records + Resizable + Clone,
+    {
+        let (count_rows, _) = table.shape();
+        let header = std::cmp::min(self.header, count_rows);
+        let footer = std::cmp::min(self.footer, count_rows - header);
+        let body = header..count_rows - footer;
+
+        if body.is_empty() {
+            return vec![table.clone()];
+        }
+
+        let ctx = PageContext {
+            cfg: table.get_config(),
+            heights: table.get_row_heights(),
+            count_rows,
+            header,
+            footer,
+        };
+
+        let joined = find_joined_rows(table.get_config(), table.shape());
+
+        let mut pages = Vec::new();
+        let mut start = body.start;
+        while start < body.end {
+            let mut end = next_break(&joined, start, body.end);
+            loop {
+                if end == body.end {
+                    break;
+                }
+
+                let next = next_break(&joined, end, body.end);
+                if ctx.page_height(start..next) > self.max_height {
+                    break;
+                }
+
+                end = next;
+            }
+
+            pages.push(build_page(table, &ctx, start..end));
+            start = end;
+        }
+
+        pages
+    }
+}
+
+impl<R> Table<R>
+where
+    R: Records + Resizable + Clone,
+{
+    /// Splits a [`Table`] into pages with at most `max_height` lines.
+    ///
+    /// A first row is repeated on each page.
+    /// See [`Pagination`] for more options.
+    pub fn paginate(&self, max_height: usize) -> Vec<Table<R>> {
+        Pagination::new(max_height).paginate(self)
+    }
+}
+
+struct PageContext<'a> {
+    cfg: &'a GridConfig,
+    heights: Vec<usize>,
+    count_rows: usize,
+    header: usize,
+    footer: usize,
+}
+
+impl PageContext<'_> {
+    /// Returns original rows which are present on a page.
+    fn page_rows(&self, body: std::ops::Range<usize>) -> Vec<usize> {
+        (0..self.header)
+            .chain(body)
+            .chain(self.count_rows - self.footer..self.count_rows)
+            .collect()
+    }
+
+    /// Returns original horizontal lines which are used on a page.
+    fn page_lines(&self, rows: &[usize]) -> Vec<usize> {
+        let mut lines = Vec::with_capacity(rows.len() + 1);
+        for (i, &row) in rows.iter().enumerate() {
+            // the first body row and the first footer row
+            // use the lines which are used in the original table after header and before footer.
+            let is_body_start = i == self.header;
+            let is_footer_start = i == rows.len() - self.footer;
+
+            let line = if is_footer_start {
+                self.count_rows - self.footer
+            } else if is_body_start {
+                self.header
+            } else {
+                row
+            };
+
+            lines.push(line);
+        }
+
+        lines.push(self.count_rows);
+
+        lines
+    }
+
+    fn page_height(&self, body: std::ops::Range<usize>) -> usize {
+        let rows = self.page_rows(body);
+        let lines = self.page_lines(&rows);
+
+        let margin = self.cfg.get_margin();
+        let content = rows.iter().map(|&row| self.heights[row]).sum::<usize>();
+        let count_lines = lines
+            .iter()
+            .filter(|&&line| self.cfg.has_horizontal(line, self.count_rows))
+            .count();
+
+        margin.top.size + margin.bottom.size + content + count_lines
+    }
+}
+
+/// Returns a list where `true` means that a row and a next one must be kept on the same page.
+fn find_joined_rows(cfg: &GridConfig, shape: (usize, usize)) -> Vec<bool> {
+    let mut joined = vec![false; shape.0];
+    for ((row, _), span) in cfg.iter_row_spans(shape) {
+        for is_joined in joined.iter_mut().skip(row).take(span - 1) {
+            *is_joined = true;
+        }
+    }
+
+    joined
+}
+
+fn next_break(joined: &[bool], start: usize, end: usize) -> usize {
+    let mut row = start;
+    while row + 1 < end && joined[row] {
+        row += 1;
+    }
+
+    row + 1
+}
+
+fn build_page<R>(table: &Table<R>, ctx: &PageContext<'_>, body: std::ops::Range<usize>) -> Table<R>
+where
+    R: Records + Resizable + Clone,
+{
+    let rows = ctx.page_rows(body);
+    let lines = ctx.page_lines(&rows);
+    let shape = (rows.len(), table.count_columns());
+
+    let mut page = table.clone();
+
+    let records = page.get_records_mut();
+    for (i, &row) in rows.iter().enumerate() {
+        if i != row {
+            records.swap_row(i, row);
+        }
+    }
+
+    for row in (rows.len()..ctx.count_rows).rev() {
+        records.remove_row(row);
+    }
+
+    let heights = rows.iter().map(|&row| ctx.heights[row]).collect();
+    let widths = table.get_column_widths();
+
+    update_config(
+        page.get_config_mut(),
+        table.get_config(),
+        &rows,
+        &lines,
+        shape,
+    );
+
+    page.cache_width(widths);
+    page.cache_height(heights);
+
+    page
+}
+
+fn update_config(
+    cfg: &mut GridConfig,
+    origin: &GridConfig,
+    rows: &[usize],
+    lines: &[usize],
+    shape: (usize, usize),
+) {
+    // the last line is always a bottom line of the original table
+    let origin_shape = (lines[lines.len() - 1], shape.1);
+
+    for (i, &line) in lines.iter().enumerate() {
+        match origin.get_horizontal_line(line) {
+            Some(&border) => cfg.set_horizontal_line(i, border),
+            None => cfg.remove_horizontal_line(i),
+        }
+
+        match (
+            origin.get_split_line_text(line),
+            origin.get_split_line_offset(line),
+        ) {
+            (Some(text), Some(offset)) => cfg.override_split_line(i, text, offset),
+            _ => {
+                cfg.remove_split_line_text(i);
+            }
+        }
+    }
+
+    let spans = cfg.iter_column_spans(origin_shape).collect::<Vec<_>>();
+    for (pos, _) in spans {
+        cfg.set_column_span(pos, 1);
+    }
+
+    let spans = cfg.iter_row_spans(origin_shape).collect::<Vec<_>>();
+    for (pos, _) in spans {
+        cfg.set_row_span(pos, 1);
+    }
+
+    for (i, &row) in rows.iter().enumerate() {
+        for col in 0..shape.1 {
+            let pos = (row, col);
+            let entity = Entity::Cell(row, col);
+            let page_entity = Entity::Cell(i, col);
+
+            if let Some(span) = origin.get_column_span(pos, origin_shape) {
+                cfg.set_column_span((i, col), span);
+            }
+
+            if let Some(span) = origin.get_row_span(pos, origin_shape) {
+                let span = rows[i..].iter().take_while(|&&r| r < row + span).count();
+                cfg.set_row_span((i, col), span);
+            }
+
+            copy_cell_settings(cfg, origin, entity, page_entity);
+        }
+    }
+}
@@ -298,7 +298,7 @@ where
     }
 }
 
-fn move_rows_aside<R>(table: &mut Table<R>, row: usize)
+pub(crate) fn move_rows_aside<R>(table: &mut Table<R>, row: usize)
 where
     R: Records + Resizable,
 {
@@ -326,7 +326,7 @@ where
     }
 }
 
-fn move_row_spans<R>(table: &mut Table<R>, target_row: usize)
+pub(crate) fn move_row_spans<R>(table: &mut Table<R>, target_row: usize)
 where
     R: Records,
 {
@@ -390,7 +390,7 @@ where
     }
 }
 
-fn set_text<R>(table: &mut Table<R>, pos: Position, text: String)
+pub(crate) fn set_text<R>(table: &mut Table<R>, pos: Position, text: String)
 where
     R: RecordsMut<String>,
 {
@@ -0,0 +1,241 @@
+//! This module contains a [`Pivot`] structure which reshapes a [`Table`] into a crosstab.
+//!
+//! # Example
+//!
+//! ```
+//! use std::iter::FromIterator;
+//! use tabled::{builder::Builder, locator::ByColumnName, pivot::{Agg, Pivot}, Style};
+//!
+//! let data = [
+//!     ["region", "month", "sales"],
+//!     ["eu", "jan", "10"],
+//!     ["eu", "feb", "20"],
+//!     ["us", "jan", "5"],
+//!     ["eu", "jan", "1"],
+//! ];
+//!
+//! let table = Builder::from_iter(data).build();
+//!
+//! let mut pivot = Pivot::new(
+//!     ByColumnName::new("region"),
+//!     ByColumnName::new("month"),
+//!     ByColumnName::new("sales"),
+//! )
+//! .aggregate(Agg::Sum)
+//! .default_cell("-");
+//!
+//! let mut table = pivot.pivot(&table);
+//! table.with(Style::psql());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         "        | jan | feb \n",
+//!         "--------+-----+-----\n",
+//!         " region |     |     \n",
+//!         " eu     | 11  | 20  \n",
+//!         " us     | 5   | -   ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::{borrow::Cow, collections::HashMap};
+
+use papergrid::records::Records;
+
+use crate::{
+    builder::Builder,
+    locator::{locate_column, Locator},
+    summary::{self, Function},
+    Table,
+};
+
+/// Pivot turns a long-format [`Table`] of a row key, a column key and a value into a crosstab.
+///
+/// Distinct row keys become an index column and distinct column keys become a header,
+/// both are kept in order of their first appearance.
+/// Values which have the same keys are combined by an [`Agg`] function.
+/// Combinations which have no values are filled with a default cell.
+///
+/// A first row of the table is considered to be a header,
+/// a name of the row key column becomes a name of the index.
+///
+/// If any of the columns is not found an empty table is returned.
+///
+/// [`Table`]: crate::Table
+#[derive(Debug)]
+pub struct Pivot<R, C, V> {
+    row_key: R,
+    column_key: C,
+    value: V,
+    aggregate: Agg,
+    default_cell: String,
+}
+
+impl<R, C, V> Pivot<R, C, V> {
+    /// Creates a [`Pivot`] by a row key column, a column key column and a value column.
+    ///
+    /// Available locators are:
+    ///
+    /// - [`Columns`]
+    /// - [`Column`]
+    /// - [`FirstColumn`]
+    /// - [`LastColumn`]
+    /// - [`ByColumnName`]
+    ///
+    /// [`Columns`]: crate::object::Columns
+    /// [`Column`]: crate::object::Column
+    /// [`FirstColumn`]: crate::object::FirstColumn
+    /// [`LastColumn`]: crate::object::LastColumn
+    /// [`ByColumnName`]: crate::locator::ByColumnName
+    pub fn new(row_key: R, column_key: C, value: V) -> Self
+    where
+        R: Locator<Coordinate = usize>,
+        C: Locator<Coordinate = usize>,
+        V: Locator<Coordinate = usize>,
+    {
+        Self {
+            row_key,
+            column_key,
+            value,
+            aggregate: Agg::First,
+            default_cell: String::new(),
+        }
+    }
+
+    /// Sets a function which combines values with the same keys.
+    ///
+    /// By default it's [`Agg::First`].
+    pub fn aggregate(mut self, aggregate: Agg) -> Self {
+        self.aggregate = aggregate;
+        self
+    }
+
+    /// Sets a cell's content for combinations of keys which have no values.
+    pub fn default_cell(mut self, cell: impl Into<String>) -> Self {
+        self.default_cell = cell.into();
+        self
+    }
+
+    /// Builds a crosstab out of the table.
+    pub fn pivot<T>(&mut self, table: &Table<T>) -> Table
+    where
+        R: Locator<Coordinate = usize>,
+        C: Locator<Coordinate = usize>,
+        V: Locator<Coordinate = usize>,
+        T: Records,
+    {
+        let records = table.get_records();
+        let (row_key, column_key, value) = match (
+            locate_column(&mut self.row_key, records),
+            locate_column(&mut self.column_key, records),
+            locate_column(&mut self.value, records),
+        ) {
+            (Some(row_key), Some(column_key), Some(value)) => (row_key, column_key, value),
+            _ => return Builder::default().build(),
+        };
+
+        let count_rows = records.count_rows();
+
+        let mut row_keys: Vec<Cow<'_, str>> = Vec::new();
+        let mut column_keys: Vec<Cow<'_, str>> = Vec::new();
+        let mut row_index = HashMap::new();
+        let mut column_index = HashMap::new();
+        let mut cells: Vec<Vec<Vec<Cow<'_, str>>>> = Vec::new();
+        for row in 1..count_rows {
+            let row_key = records.get_text((row, row_key));
+            let i = find_or_push(&mut row_keys, &mut row_index, row_key);
+            if i == cells.len() {
+                cells.push(vec![Vec::new(); column_keys.len()]);
+            }
+
+            let column_key = records.get_text((row, column_key));
+            let j = find_or_push(&mut column_keys, &mut column_index, column_key);
+            if j == column_keys.len() - 1 {
+                cells
+                    .iter_mut()
+                    .for_each(|row| row.resize(column_keys.len(), Vec::new()));
+            }
+
+            cells[i][j].push(records.get_text((row, value)));
+        }
+
+        let mut builder = Builder::default();
+
+        let name = if count_rows > 0 {
+            records.get_text((0, row_key)).into_owned()
+        } else {
+            String::new()
+        };
+        let header = column_keys.into_iter().map(Cow::into_owned);
+        builder.set_columns(std::iter::once(name).chain(header));
+
+        for (key, row) in row_keys.into_iter().zip(cells) {
+            let values = row.iter().map(|values| {
+                if values.is_empty() {
+                    self.default_cell.clone()
+                } else {
+                    self.aggregate.apply(values)
+                }
+            });
+
+            builder.add_record(std::iter::once(key.into_owned()).chain(values));
+        }
+
+        let mut builder = builder.index();
+        builder.set_index(0);
+        builder.build()
+    }
+}
+
+/// A function which combines values of a [`Pivot`] cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Agg {
+    /// A first value.
+    First,
+    /// A last value.
+    Last,
+    /// A sum of values.
+    Sum,
+    /// An average of values.
+    Avg,
+    /// A min value.
+    Min,
+    /// A max value.
+    Max,
+    /// A number of not empty values.
+    Count,
+}
+
+impl Agg {
+    fn apply(self, values: &[Cow<'_, str>]) -> String {
+        let function = match self {
+            Agg::First => return values.first().map(|s| s.to_string()).unwrap_or_default(),
+            Agg::Last => return values.last().map(|s| s.to_string()).unwrap_or_default(),
+            Agg::Sum => Function::Sum,
+            Agg::Avg => Function::Avg,
+            Agg::Min => Function::Min,
+            Agg::Max => Function::Max,
+            Agg::Count => Function::Count,
+        };
+
+        summary::aggregate(function, values.iter().map(Cow::as_ref))
+    }
+}
+
+/// Returns an index of a key, adding it to the end of the list if it's not yet there.
+fn find_or_push<'a>(
+    keys: &mut Vec<Cow<'a, str>>,
+    index: &mut HashMap<Cow<'a, str>, usize>,
+    key: Cow<'a, str>,
+) -> usize {
+    if let Some(&i) = index.get(&key) {
+        return i;
+    }
+
+    index.insert(key.clone(), keys.len());
+    keys.push(key);
+    keys.len() - 1
+}
@@ -0,0 +1,266 @@
+//! This module contains a [`Reorder`] structure which changes an order of columns of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use std::iter::FromIterator;
+//! use tabled::{builder::Builder, locator::ByColumnName, Reorder, Style};
+//!
+//! let data = [
+//!     ["id", "size", "name"],
+//!     ["0", "1,024", "tabled"],
+//!     ["1", "512", "papergrid"],
+//! ];
+//!
+//! let mut table = Builder::from_iter(data).build();
+//! table
+//!     .with(Reorder::columns(["name", "id"].map(ByColumnName::new)))
+//!     .with(Style::psql());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " name      | id | size  \n",
+//!         "-----------+----+-------\n",
+//!         " tabled    | 0  | 1,024 \n",
+//!         " papergrid | 1  | 512   ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{
+    records::{Records, Resizable},
+    Entity, GridConfig, Position,
+};
+
+use crate::{
+    locator::Locator,
+    util::{copy_cell_settings, permute},
+    Table, TableOption,
+};
+
+/// Reorder changes an order of columns of a [`Table`].
+///
+/// Settings of columns, like [`Alignment`] and [`Padding`], are moved along with the columns.
+///
+/// [`Table`]: crate::Table
+/// [`Alignment`]: crate::Alignment
+/// [`Padding`]: crate::Padding
+#[derive(Debug)]
+pub struct Reorder<L> {
+    columns: Vec<L>,
+    index: Option<usize>,
+}
+
+impl<L> Reorder<L> {
+    /// Puts the given columns first in the given order.
+    ///
+    /// The rest of the columns are kept in their original order.
+    /// If a locator finds several columns they're all moved.
+    ///
+    /// Available locators are:
+    ///
+    /// - [`Columns`]
+    /// - [`Column`]
+    /// - [`FirstColumn`]
+    /// - [`LastColumn`]
+    /// - [`ByColumnName`]
+    ///
+    /// ```
+    /// use tabled::{object::Columns, Reorder, Table};
+    ///
+    /// let mut table = Table::new([(0, "a", 'b')]);
+    /// table.with(Reorder::columns([Columns::single(2), Columns::single(1)]));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+------+-----+\n\
+    ///      | char | &str | i32 |\n\
+    ///      +------+------+-----+\n\
+    ///      | b    | a    | 0   |\n\
+    ///      +------+------+-----+"
+    /// );
+    /// ```
+    ///
+    /// [`Columns`]: crate::object::Columns
+    /// [`Column`]: crate::object::Column
+    /// [`FirstColumn`]: crate::object::FirstColumn
+    /// [`LastColumn`]: crate::object::LastColumn
+    /// [`ByColumnName`]: crate::locator::ByColumnName
+    pub fn columns<I>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Locator<Coordinate = usize>,
+    {
+        Self {
+            columns: columns.into_iter().collect(),
+            index: None,
+        }
+    }
+
+    /// Moves a column to a given index, shifting the rest of the columns.
+    ///
+    /// If the index is out of bounds the column is moved to the end.
+    ///
+    /// ```
+    /// use tabled::{object::Columns, Reorder, Table};
+    ///
+    /// let mut table = Table::new([(0, "a", 'b')]);
+    /// table.with(Reorder::move_column(Columns::first(), 1));
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+------+-----+------+\n\
+    ///      | &str | i32 | char |\n\
+    ///      +------+-----+------+\n\
+    ///      | a    | 0   | b    |\n\
+    ///      +------+-----+------+"
+    /// );
+    /// ```
+    pub fn move_column(column: L, index: usize) -> Self
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        Self {
+            columns: vec![column],
+            index: Some(index),
+        }
+    }
+}
+
+impl<L, R> TableOption<R> for Reorder<L>
+where
+    L: Locator<Coordinate = usize>,
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_columns) = table.shape();
+
+        let mut moved = Vec::new();
+        for locator in &mut self.columns {
+            for col in locator.locate(table.get_records()) {
+                if col < count_columns && !moved.contains(&col) {
+                    moved.push(col);
+                }
+            }
+        }
+
+        let mut order = (0..count_columns)
+            .filter(|col| !moved.contains(col))
+            .collect::<Vec<_>>();
+
+        let index = self.index.map_or(0, |index| index.min(order.len()));
+        order.splice(index..index, moved);
+
+        if order.iter().enumerate().all(|(i, &col)| i == col) {
+            return;
+        }
+
+        let origin = table.get_config().clone();
+
+        let records = table.get_records_mut();
+        permute(&order, |lhs, rhs| records.swap_column(lhs, rhs));
+
+        let moved_spans = update_spans(table, &order);
+        update_config(table.get_config_mut(), &origin, &order, count_rows);
+
+        for ((row, col), origin_col) in moved_spans {
+            let cell = Entity::Cell(row, origin_col);
+            copy_cell_settings(
+                table.get_config_mut(),
+                &origin,
+                cell,
+                Entity::Cell(row, col),
+            );
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+/// Moves spans along with the columns.
+///
+/// If columns of a horizontal span are still adjacent the span is kept whole,
+/// otherwise it's cut to the columns which are next to the first one.
+///
+/// Returns spans which content was moved to another column, along with their original column.
+fn update_spans<R>(table: &mut Table<R>, order: &[usize]) -> Vec<(Position, usize)>
+where
+    R: Records + Resizable,
+{
+    let shape = table.shape();
+
+    let mut positions = vec![0; order.len()];
+    for (i, &col) in order.iter().enumerate() {
+        positions[col] = i;
+    }
+
+    let column_spans = table
+        .get_config()
+        .iter_column_spans(shape)
+        .collect::<Vec<_>>();
+    let row_spans = table.get_config().iter_row_spans(shape).collect::<Vec<_>>();
+
+    let mut spans = Vec::new();
+    let mut moved = Vec::new();
+    for &((row, col), span) in &column_spans {
+        let covered = (col..col + span).map(|c| positions[c]);
+        let first = covered.clone().min().unwrap_or(positions[col]);
+        let last = covered.max().unwrap_or(positions[col]);
+
+        if last - first + 1 == span {
+            if first != positions[col] {
+                table
+                    .get_records_mut()
+                    .swap((row, first), (row, positions[col]));
+                moved.push(((row, first), col));
+            }
+
+            spans.push(((row, first), span));
+        } else {
+            let i = positions[col];
+            let span = order[i..]
+                .iter()
+                .take_while(|&&c| c >= col && c < col + span)
+                .count();
+
+            spans.push(((row, i), span));
+        }
+    }
+
+    let cfg = table.get_config_mut();
+    for &(pos, _) in &column_spans {
+        cfg.set_column_span(pos, 1);
+    }
+
+    for &(pos, _) in &row_spans {
+        cfg.set_row_span(pos, 1);
+    }
+
+    for (pos, span) in spans {
+        cfg.set_column_span(pos, span);
+    }
+
+    for ((row, col), span) in row_spans {
+        cfg.set_row_span((row, positions[col]), span);
+    }
+
+    moved
+}
+
+fn update_config(cfg: &mut GridConfig, origin: &GridConfig, order: &[usize], count_rows: usize) {
+    for (i, &col) in order.iter().enumerate() {
+        if i == col {
+            continue;
+        }
+
+        copy_cell_settings(cfg, origin, Entity::Column(col), Entity::Column(i));
+
+        for row in 0..count_rows {
+            copy_cell_settings(cfg, origin, Entity::Cell(row, col), Entity::Cell(row, i));
+        }
+    }
+}
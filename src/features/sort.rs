@@ -0,0 +1,454 @@
+//! This module contains a [`Sort`] structure which sorts rows of a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use std::iter::FromIterator;
+//! use tabled::{builder::Builder, locator::ByColumnName, Sort, Style};
+//!
+//! let data = [
+//!     ["name", "size"],
+//!     ["tabled", "1,024"],
+//!     ["papergrid", "512"],
+//!     ["json_to_table", "64"],
+//! ];
+//!
+//! let mut table = Builder::from_iter(data).build();
+//! table
+//!     .with(Sort::column(ByColumnName::new("size")).numeric())
+//!     .with(Style::psql());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " name          | size  \n",
+//!         "---------------+-------\n",
+//!         " json_to_table | 64    \n",
+//!         " papergrid     | 512   \n",
+//!         " tabled        | 1,024 ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::{borrow::Cow, cmp::Ordering, iter::Peekable, str::Chars};
+
+use papergrid::records::{Records, Resizable};
+
+use crate::{
+    locator::Locator,
+    util::{parse_number, permute},
+    Table, TableOption,
+};
+
+/// Sort sorts rows of a [`Table`] by a value of columns.
+///
+/// A column can be chosen by index or by a name via [`ByColumnName`].
+/// Several keys can be combined via [`Sort::then`],
+/// a next key is used only when the previous ones consider the rows equal.
+/// The sort is stable.
+///
+/// A header, which is a first row by default, is kept in place.
+/// Rows which are a part of a span, like a [`Panel`], are also kept in place
+/// and the rows between them are sorted separately.
+///
+/// Notice that only a content is moved, settings set for particular cells are kept in place.
+///
+/// ```
+/// use std::iter::FromIterator;
+/// use tabled::{builder::Builder, object::Columns, Sort, Style};
+///
+/// let data = [
+///     ["1", "file10.txt"],
+///     ["0", "file9.txt"],
+///     ["1", "file1.txt"],
+/// ];
+///
+/// let mut table = Builder::from_iter(data).build();
+/// table
+///     .with(
+///         Sort::column(Columns::single(0))
+///             .descending()
+///             .then(Sort::column(Columns::single(1)).natural())
+///             .header(0),
+///     )
+///     .with(Style::blank());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " 1   file1.txt  \n",
+///         " 1   file10.txt \n",
+///         " 0   file9.txt  ",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+/// [`ByColumnName`]: crate::locator::ByColumnName
+/// [`Panel`]: crate::Panel
+#[derive(Debug)]
+pub struct Sort<L, N = ()> {
+    locator: L,
+    order: Order,
+    comparator: Comparator,
+    header: usize,
+    next: N,
+}
+
+impl<L> Sort<L> {
+    /// Creates a [`Sort`] by the given columns.
+    ///
+    /// If a locator returns several columns, they are used as consecutive keys.
+    /// By default an order is ascending and values are compared lexically.
+    ///
+    /// Available locators are:
+    ///
+    /// - [`Columns`]
+    /// - [`Column`]
+    /// - [`FirstColumn`]
+    /// - [`LastColumn`]
+    /// - [`ByColumnName`]
+    ///
+    /// [`Columns`]: crate::object::Columns
+    /// [`Column`]: crate::object::Column
+    /// [`FirstColumn`]: crate::object::FirstColumn
+    /// [`LastColumn`]: crate::object::LastColumn
+    /// [`ByColumnName`]: crate::locator::ByColumnName
+    pub fn column(locator: L) -> Self
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        Self {
+            locator,
+            order: Order::Ascending,
+            comparator: Comparator::Lexical,
+            header: 1,
+            next: (),
+        }
+    }
+}
+
+impl<L, N> Sort<L, N> {
+    /// Sets an ascending order.
+    pub fn ascending(mut self) -> Self {
+        self.order = Order::Ascending;
+        self
+    }
+
+    /// Sets a descending order.
+    pub fn descending(mut self) -> Self {
+        self.order = Order::Descending;
+        self
+    }
+
+    /// Compares values lexically.
+    ///
+    /// It's a default comparator.
+    pub fn lexical(self) -> Self {
+        self.comparator(Comparator::Lexical)
+    }
+
+    /// Compares values in a natural order,
+    /// where sequences of digits are compared as numbers (`file9` goes before `file10`).
+    pub fn natural(self) -> Self {
+        self.comparator(Comparator::Natural)
+    }
+
+    /// Compares values as numbers.
+    ///
+    /// A value may contain `,` and `_` separators, like `1,024` or `3.5`.
+    /// Values which are not numbers, including `NaN` and `inf`, are compared lexically
+    /// and go after numbers in ascending order (and before them in descending one).
+    pub fn numeric(self) -> Self {
+        self.comparator(Comparator::Numeric)
+    }
+
+    /// Sets a comparator of values.
+    pub fn comparator(mut self, comparator: Comparator) -> Self {
+        self.comparator = comparator;
+        self
+    }
+
+    /// Sets a number of first rows which are kept in place.
+    ///
+    /// By default it's 1.
+    /// Only a value of a first key is used.
+    pub fn header(mut self, count_rows: usize) -> Self {
+        self.header = count_rows;
+        self
+    }
+
+    /// Adds a next key to the sort.
+    pub fn then<L2, N2>(self, sort: Sort<L2, N2>) -> Sort<L, (N, Sort<L2, N2>)> {
+        Sort {
+            locator: self.locator,
+            order: self.order,
+            comparator: self.comparator,
+            header: self.header,
+            next: (self.next, sort),
+        }
+    }
+}
+
+/// A sort order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// From the smallest to the largest value.
+    Ascending,
+    /// From the largest to the smallest value.
+    Descending,
+}
+
+/// A way values are compared by [`Sort`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparator {
+    /// Compares strings as they are.
+    Lexical,
+    /// Compares sequences of digits as numbers and the rest lexically.
+    Natural,
+    /// Compares values as numbers.
+    Numeric,
+}
+
+impl Comparator {
+    fn compare(&self, lhs: &str, rhs: &str) -> Ordering {
+        match self {
+            Comparator::Lexical => lhs.cmp(rhs),
+            Comparator::Natural => compare_natural(lhs, rhs),
+            Comparator::Numeric => compare_numeric(lhs, rhs),
+        }
+    }
+}
+
+/// A resolved key of a [`Sort`].
+#[derive(Debug, Clone, Copy)]
+pub struct SortKey {
+    column: usize,
+    order: Order,
+    comparator: Comparator,
+}
+
+/// A list of keys of a [`Sort`].
+///
+/// It's implemented for [`Sort`] and its combinations made by [`Sort::then`].
+pub trait SortKeys {
+    /// Resolves keys against the records.
+    fn keys<R>(&mut self, records: R, keys: &mut Vec<SortKey>)
+    where
+        R: Records;
+}
+
+impl SortKeys for () {
+    fn keys<R>(&mut self, _: R, _: &mut Vec<SortKey>)
+    where
+        R: Records,
+    {
+    }
+}
+
+impl<A, B> SortKeys for (A, B)
+where
+    A: SortKeys,
+    B: SortKeys,
+{
+    fn keys<R>(&mut self, records: R, keys: &mut Vec<SortKey>)
+    where
+        R: Records,
+    {
+        self.0.keys(&records, keys);
+        self.1.keys(&records, keys);
+    }
+}
+
+impl<L, N> SortKeys for Sort<L, N>
+where
+    L: Locator<Coordinate = usize>,
+    N: SortKeys,
+{
+    fn keys<R>(&mut self, records: R, keys: &mut Vec<SortKey>)
+    where
+        R: Records,
+    {
+        let count_columns = records.count_columns();
+        for column in self.locator.locate(&records) {
+            if column < count_columns {
+                keys.push(SortKey {
+                    column,
+                    order: self.order,
+                    comparator: self.comparator,
+                });
+            }
+        }
+
+        self.next.keys(&records, keys);
+    }
+}
+
+impl<L, N, R> TableOption<R> for Sort<L, N>
+where
+    L: Locator<Coordinate = usize>,
+    N: SortKeys,
+    R: Records + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let mut keys = Vec::new();
+        self.keys(table.get_records(), &mut keys);
+
+        if keys.is_empty() {
+            return;
+        }
+
+        let fixed = find_fixed_rows(table, self.header);
+
+        let mut start = 0;
+        while start < fixed.len() {
+            if fixed[start] {
+                start += 1;
+                continue;
+            }
+
+            let mut end = start;
+            while end < fixed.len() && !fixed[end] {
+                end += 1;
+            }
+
+            let order = sort_rows(table.get_records(), &keys, start, end);
+            reorder_rows(table.get_records_mut(), start, &order);
+
+            start = end;
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+/// Returns a list where `true` means that a row must be kept in place.
+fn find_fixed_rows<R>(table: &Table<R>, header: usize) -> Vec<bool>
+where
+    R: Records,
+{
+    let shape = table.shape();
+    let cfg = table.get_config();
+
+    let mut fixed = vec![false; shape.0];
+    for is_fixed in fixed.iter_mut().take(header) {
+        *is_fixed = true;
+    }
+
+    for ((row, _), span) in cfg.iter_row_spans(shape) {
+        for is_fixed in fixed.iter_mut().skip(row).take(span) {
+            *is_fixed = true;
+        }
+    }
+
+    for ((row, _), _) in cfg.iter_column_spans(shape) {
+        fixed[row] = true;
+    }
+
+    fixed
+}
+
+/// Returns original rows in a sorted order.
+fn sort_rows<R>(records: R, keys: &[SortKey], start: usize, end: usize) -> Vec<usize>
+where
+    R: Records,
+{
+    let values = (start..end)
+        .map(|row| {
+            keys.iter()
+                .map(|key| records.get_text((row, key.column)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<Vec<Cow<'_, str>>>>();
+
+    let mut order = (0..end - start).collect::<Vec<_>>();
+    order.sort_by(|&lhs, &rhs| {
+        for (i, key) in keys.iter().enumerate() {
+            let ord = key.comparator.compare(&values[lhs][i], &values[rhs][i]);
+            let ord = match key.order {
+                Order::Ascending => ord,
+                Order::Descending => ord.reverse(),
+            };
+
+            if ord != Ordering::Equal {
+                return ord;
+            }
+        }
+
+        Ordering::Equal
+    });
+
+    order.into_iter().map(|i| start + i).collect()
+}
+
+/// Moves rows so that a row `order[i]` ends up on `start + i` position.
+pub(crate) fn reorder_rows<R>(records: &mut R, start: usize, order: &[usize])
+where
+    R: Resizable,
+{
+    let order = order.iter().map(|&row| row - start).collect::<Vec<_>>();
+    permute(&order, |lhs, rhs| {
+        records.swap_row(start + lhs, start + rhs)
+    });
+}
+
+fn compare_numeric(lhs: &str, rhs: &str) -> Ordering {
+    match (parse_number(lhs), parse_number(rhs)) {
+        (Some(lhs), Some(rhs)) => lhs.partial_cmp(&rhs).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => lhs.cmp(rhs),
+    }
+}
+
+fn compare_natural(lhs: &str, rhs: &str) -> Ordering {
+    let mut lhs = lhs.chars().peekable();
+    let mut rhs = rhs.chars().peekable();
+
+    loop {
+        let ord = match (lhs.peek(), rhs.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) if a.is_ascii_digit() && b.is_ascii_digit() => {
+                let a = take_digits(&mut lhs);
+                let b = take_digits(&mut rhs);
+                compare_digits(&a, &b)
+            }
+            (Some(a), Some(b)) => {
+                let ord = a.cmp(b);
+                lhs.next();
+                rhs.next();
+                ord
+            }
+        };
+
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+}
+
+fn take_digits(chars: &mut Peekable<Chars<'_>>) -> String {
+    let mut digits = String::new();
+    while let Some(&c) = chars.peek() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+
+        digits.push(c);
+        chars.next();
+    }
+
+    digits
+}
+
+fn compare_digits(lhs: &str, rhs: &str) -> Ordering {
+    let lhs = lhs.trim_start_matches('0');
+    let rhs = rhs.trim_start_matches('0');
+
+    lhs.len().cmp(&rhs.len()).then_with(|| lhs.cmp(rhs))
+}
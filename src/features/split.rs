@@ -0,0 +1,281 @@
+//! This module contains a [`Split`] which splits a wide [`Table`] into blocks of columns.
+//!
+//! # Example
+//!
+//! ```
+//! use std::iter::FromIterator;
+//! use tabled::{builder::Builder, object::Columns, Split, Style};
+//!
+//! let data = [
+//!     ["id", "name", "language", "license"],
+//!     ["0", "tabled", "rust", "MIT"],
+//!     ["1", "pandas", "python", "BSD"],
+//! ];
+//!
+//! let mut table = Builder::from_iter(data).build();
+//! table.with(Style::psql());
+//!
+//! let blocks = Split::columns(25).keep(Columns::first()).split(&table);
+//!
+//! assert_eq!(blocks.len(), 2);
+//! assert_eq!(
+//!     blocks[0].to_string(),
+//!     concat!(
+//!         " id | name   | language \n",
+//!         "----+--------+----------\n",
+//!         " 0  | tabled | rust     \n",
+//!         " 1  | pandas | python   ",
+//!     )
+//! );
+//! assert_eq!(
+//!     blocks[1].to_string(),
+//!     concat!(
+//!         " id | license \n",
+//!         "----+---------\n",
+//!         " 0  | MIT     \n",
+//!         " 1  | BSD     ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::ops::Range;
+
+use papergrid::{
+    records::{Records, Resizable},
+    Entity, GridConfig,
+};
+
+use crate::{locator::Locator, object::Columns, util::copy_cell_settings, Table};
+
+/// Split splits a wide [`Table`] into a list of tables,
+/// each of which is not wider than a given width.
+///
+/// It's an alternative to [`Width::wrap`] and [`Width::truncate`],
+/// which keeps the content as it is but stacks columns into several blocks.
+///
+/// A set of key columns can be repeated in each block,
+/// either by an index or by a name via [`ByColumnName`].
+/// The columns are kept in their original order.
+///
+/// The columns widths are estimated on the original table.
+/// If a column doesn't fit into a block alone it's put into a separate block anyway.
+///
+/// Notice that borders set for particular cells are not moved along with their columns.
+///
+/// [`Table`]: crate::Table
+/// [`Width::wrap`]: crate::Width::wrap
+/// [`Width::truncate`]: crate::Width::truncate
+/// [`ByColumnName`]: crate::locator::ByColumnName
+#[derive(Debug)]
+pub struct Split<L = Columns<Range<usize>>> {
+    max_width: usize,
+    keep: L,
+}
+
+impl Split {
+    /// Creates a new [`Split`] with a max width of a block.
+    ///
+    /// By default no columns are repeated.
+    pub fn columns(max_width: usize) -> Self {
+        Self {
+            max_width,
+            keep: Columns::new(0..0),
+        }
+    }
+}
+
+impl<L> Split<L> {
+    /// Sets columns which are repeated in each block.
+    ///
+    /// ```
+    /// use tabled::{locator::ByColumnName, Split};
+    ///
+    /// let split = Split::columns(80).keep(ByColumnName::new("id"));
+    /// ```
+    pub fn keep<K>(self, locator: K) -> Split<K>
+    where
+        K: Locator<Coordinate = usize>,
+    {
+        Split {
+            max_width: self.max_width,
+            keep: locator,
+        }
+    }
+}
+
+impl<L> Split<L>
+where
+    L: Locator<Coordinate = usize>,
+{
+    /// Splits a [`Table`] into blocks of columns.
+    ///
+    /// [`Table`]: crate::Table
+    pub fn split<R>(&mut self, table: &Table<R>) -> Vec<Table<R>>
+    where
+        R: Records + Resizable + Clone,
+    {
+        if table.is_empty() {
+            return vec![table.clone()];
+        }
+
+        let count_columns = table.count_columns();
+
+        let mut keep = self
+            .keep
+            .locate(table.get_records())
+            .into_iter()
+            .filter(|&col| col < count_columns)
+            .collect::<Vec<_>>();
+        keep.sort_unstable();
+        keep.dedup();
+
+        let rest = (0..count_columns)
+            .filter(|col| !keep.contains(col))
+            .collect::<Vec<_>>();
+
+        if rest.is_empty() {
+            return vec![table.clone()];
+        }
+
+        let widths = table.get_column_widths();
+        let cfg = table.get_config();
+
+        let mut blocks = Vec::new();
+        let mut start = 0;
+        while start < rest.len() {
+            let mut end = start + 1;
+            while end < rest.len() {
+                let columns = block_columns(&keep, &rest[start..end + 1]);
+                if block_width(cfg, &widths, &columns, count_columns) > self.max_width {
+                    break;
+                }
+
+                end += 1;
+            }
+
+            let columns = block_columns(&keep, &rest[start..end]);
+            blocks.push(build_block(table, &columns));
+
+            start = end;
+        }
+
+        blocks
+    }
+}
+
+fn block_columns(keep: &[usize], columns: &[usize]) -> Vec<usize> {
+    let mut list = keep.iter().chain(columns).copied().collect::<Vec<_>>();
+    list.sort_unstable();
+    list
+}
+
+/// Returns original vertical lines which are used in a block.
+///
+/// A line on the left of a column is used,
+/// except the last line which is always a right border.
+fn block_lines(columns: &[usize], count_columns: usize) -> Vec<usize> {
+    let mut lines = columns.to_vec();
+    lines[0] = 0;
+    lines.push(count_columns);
+    lines
+}
+
+fn block_width(
+    cfg: &GridConfig,
+    widths: &[usize],
+    columns: &[usize],
+    count_columns: usize,
+) -> usize {
+    let margin = cfg.get_margin();
+    let content = columns.iter().map(|&col| widths[col]).sum::<usize>();
+    let count_lines = block_lines(columns, count_columns)
+        .into_iter()
+        .filter(|&line| cfg.has_vertical(line, count_columns))
+        .count();
+
+    margin.left.size + margin.right.size + content + count_lines
+}
+
+fn build_block<R>(table: &Table<R>, columns: &[usize]) -> Table<R>
+where
+    R: Records + Resizable + Clone,
+{
+    let (count_rows, count_columns) = table.shape();
+    let origin = table.get_config();
+
+    let mut block = table.clone();
+
+    // a text of a spanned cell is moved to a first visible column of the span
+    let mut sources = Vec::new();
+    for ((row, col), span) in origin.iter_column_spans(table.shape()) {
+        let first = columns.iter().position(|&c| c >= col && c < col + span);
+        if let Some(i) = first {
+            if columns[i] != col {
+                block.get_records_mut().swap((row, columns[i]), (row, col));
+            }
+
+            let count = columns[i..].iter().take_while(|&&c| c < col + span).count();
+
+            sources.push(((row, i), col, count));
+        }
+    }
+
+    let records = block.get_records_mut();
+    for (i, &col) in columns.iter().enumerate() {
+        if i != col {
+            records.swap_column(i, col);
+        }
+    }
+
+    for col in (columns.len()..count_columns).rev() {
+        records.remove_column(col);
+    }
+
+    let cfg = block.get_config_mut();
+    let lines = block_lines(columns, count_columns);
+    for (i, &line) in lines.iter().enumerate() {
+        match origin.get_vertical_line(line) {
+            Some(&border) => cfg.set_vertical_line(i, border),
+            None => cfg.remove_vertical_line(i),
+        }
+    }
+
+    let spans = cfg.iter_column_spans(table.shape()).collect::<Vec<_>>();
+    for (pos, _) in spans {
+        cfg.set_column_span(pos, 1);
+    }
+
+    let spans = cfg.iter_row_spans(table.shape()).collect::<Vec<_>>();
+    for (pos, _) in spans {
+        cfg.set_row_span(pos, 1);
+    }
+
+    for &(pos, _, span) in &sources {
+        cfg.set_column_span(pos, span);
+    }
+
+    for row in 0..count_rows {
+        for (i, &col) in columns.iter().enumerate() {
+            let col = sources
+                .iter()
+                .find(|(pos, _, _)| *pos == (row, i))
+                .map_or(col, |&(_, origin_col, _)| origin_col);
+
+            if let Some(span) = origin.get_row_span((row, col), table.shape()) {
+                cfg.set_row_span((row, i), span);
+            }
+
+            let entity = Entity::Cell(row, col);
+            let block_entity = Entity::Cell(row, i);
+
+            copy_cell_settings(cfg, origin, entity, block_entity);
+        }
+    }
+
+    block.destroy_width_cache();
+    block.destroy_height_cache();
+
+    block
+}
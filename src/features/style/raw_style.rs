@@ -187,6 +187,18 @@ impl RawStyle {
         self
     }
 
+    pub(crate) fn get_borders(&self) -> &Borders<char> {
+        &self.borders
+    }
+
+    pub(crate) fn get_horizontals(&self) -> &HashMap<usize, Line> {
+        &self.horizontals
+    }
+
+    pub(crate) fn get_verticals(&self) -> &HashMap<usize, Line> {
+        &self.verticals
+    }
+
     /// Get a left char.
     pub fn get_left(&self) -> Option<char> {
         self.borders.vertical_left
@@ -0,0 +1,457 @@
+//! This module contains a [`Summary`] structure which appends aggregate rows to a [`Table`].
+//!
+//! # Example
+//!
+//! ```
+//! use std::iter::FromIterator;
+//! use tabled::{builder::Builder, object::Columns, Style, Summary};
+//!
+//! let data = [
+//!     ["item", "price", "count"],
+//!     ["apple", "1.50", "10"],
+//!     ["orange", "2.25", "3"],
+//!     ["melon", "1,024.00", "1"],
+//! ];
+//!
+//! let mut table = Builder::from_iter(data).build();
+//! table
+//!     .with(Summary::new().sum(Columns::new(1..)).label(0, "Total"))
+//!     .with(Style::psql());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " item   | price    | count \n",
+//!         "--------+----------+-------\n",
+//!         " apple  | 1.50     | 10    \n",
+//!         " orange | 2.25     | 3     \n",
+//!         " melon  | 1,024.00 | 1     \n",
+//!         " Total  | 1,027.75 | 14    ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+
+use std::{borrow::Cow, ops::Range};
+
+use papergrid::{
+    records::{Records, RecordsMut, Resizable},
+    Position,
+};
+
+use crate::{
+    features::panel::set_text,
+    locator::Locator,
+    style::{HorizontalLine, Line},
+    util::parse_number,
+    Table, TableOption,
+};
+
+/// Summary appends rows with aggregated values of columns to a [`Table`].
+///
+/// Values are parsed from a cell text, a value may contain `,` and `_` separators.
+/// Cells which are not numbers are ignored.
+/// A result keeps a max decimal precision of the values,
+/// and it uses `,` separators if any of the values does.
+///
+/// A header, which is a first row by default, is not aggregated.
+///
+/// ```
+/// use tabled::{object::Columns, style::Line, Style, Summary, Table};
+///
+/// let data = [3, 1, 2];
+///
+/// let mut table = Table::new(data);
+/// table
+///     .with(Style::psql())
+///     .with(
+///         Summary::new()
+///             .max(Columns::first())
+///             .row()
+///             .min(Columns::first())
+///             .line(Line::new(Some('-'), Some('+'), None, None)),
+///     );
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " i32 \n",
+///         "-----\n",
+///         " 3   \n",
+///         " 1   \n",
+///         " 2   \n",
+///         "-----\n",
+///         " 3   \n",
+///         " 1   ",
+///     )
+/// );
+/// ```
+///
+/// [`Table`]: crate::Table
+#[derive(Debug)]
+pub struct Summary<A = ()> {
+    aggregates: A,
+    labels: Vec<(usize, usize, String)>,
+    row: usize,
+    header: usize,
+    line: Option<Line>,
+}
+
+impl Summary {
+    /// Creates an empty [`Summary`].
+    pub fn new() -> Self {
+        Self {
+            aggregates: (),
+            labels: Vec::new(),
+            row: 0,
+            header: 1,
+            line: None,
+        }
+    }
+}
+
+impl Default for Summary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> Summary<A> {
+    /// Puts a sum of values of the given columns.
+    ///
+    /// Available locators are:
+    ///
+    /// - [`Columns`]
+    /// - [`Column`]
+    /// - [`FirstColumn`]
+    /// - [`LastColumn`]
+    /// - [`ByColumnName`]
+    ///
+    /// [`Columns`]: crate::object::Columns
+    /// [`Column`]: crate::object::Column
+    /// [`FirstColumn`]: crate::object::FirstColumn
+    /// [`LastColumn`]: crate::object::LastColumn
+    /// [`ByColumnName`]: crate::locator::ByColumnName
+    pub fn sum<L>(self, columns: L) -> Summary<(A, Aggregate<L>)>
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        self.aggregate(columns, Function::Sum)
+    }
+
+    /// Puts an average of values of the given columns.
+    pub fn avg<L>(self, columns: L) -> Summary<(A, Aggregate<L>)>
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        self.aggregate(columns, Function::Avg)
+    }
+
+    /// Puts a min value of the given columns.
+    pub fn min<L>(self, columns: L) -> Summary<(A, Aggregate<L>)>
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        self.aggregate(columns, Function::Min)
+    }
+
+    /// Puts a max value of the given columns.
+    pub fn max<L>(self, columns: L) -> Summary<(A, Aggregate<L>)>
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        self.aggregate(columns, Function::Max)
+    }
+
+    /// Puts a number of not empty cells of the given columns.
+    pub fn count<L>(self, columns: L) -> Summary<(A, Aggregate<L>)>
+    where
+        L: Locator<Coordinate = usize>,
+    {
+        self.aggregate(columns, Function::Count)
+    }
+
+    /// Puts a text to a given column of a current row.
+    pub fn label<S>(mut self, column: usize, text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.labels.push((self.row, column, text.into()));
+        self
+    }
+
+    /// Starts a next row.
+    ///
+    /// All the following aggregates and labels are put into it.
+    pub fn row(mut self) -> Self {
+        self.row += 1;
+        self
+    }
+
+    /// Sets a number of first rows which are not aggregated.
+    ///
+    /// By default it's 1.
+    pub fn header(mut self, count_rows: usize) -> Self {
+        self.header = count_rows;
+        self
+    }
+
+    /// Sets a horizontal line above the summary rows.
+    pub fn line(mut self, line: Line) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    fn aggregate<L>(self, columns: L, function: Function) -> Summary<(A, Aggregate<L>)> {
+        let aggregate = Aggregate {
+            locator: columns,
+            function,
+            row: self.row,
+        };
+
+        Summary {
+            aggregates: (self.aggregates, aggregate),
+            labels: self.labels,
+            row: self.row,
+            header: self.header,
+            line: self.line,
+        }
+    }
+}
+
+/// An aggregate function used by [`Summary`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Function {
+    /// A sum of values.
+    Sum,
+    /// An average of values.
+    Avg,
+    /// A min value.
+    Min,
+    /// A max value.
+    Max,
+    /// A number of not empty cells.
+    Count,
+}
+
+/// An aggregate of columns of a [`Summary`].
+#[derive(Debug)]
+pub struct Aggregate<L> {
+    locator: L,
+    function: Function,
+    row: usize,
+}
+
+/// A list of aggregates of a [`Summary`].
+///
+/// It's implemented for the combinations made by [`Summary`] methods.
+pub trait Aggregates {
+    /// Resolves aggregates against the records,
+    /// returning a row of a summary, a column and a function.
+    fn resolve<R>(&mut self, records: R, list: &mut Vec<(usize, usize, Function)>)
+    where
+        R: Records;
+}
+
+impl Aggregates for () {
+    fn resolve<R>(&mut self, _: R, _: &mut Vec<(usize, usize, Function)>)
+    where
+        R: Records,
+    {
+    }
+}
+
+impl<A, L> Aggregates for (A, Aggregate<L>)
+where
+    A: Aggregates,
+    L: Locator<Coordinate = usize>,
+{
+    fn resolve<R>(&mut self, records: R, list: &mut Vec<(usize, usize, Function)>)
+    where
+        R: Records,
+    {
+        self.0.resolve(&records, list);
+
+        let aggregate = &mut self.1;
+        let count_columns = records.count_columns();
+        for col in aggregate.locator.locate(&records) {
+            if col < count_columns {
+                list.push((aggregate.row, col, aggregate.function));
+            }
+        }
+    }
+}
+
+impl<A> Summary<A>
+where
+    A: Aggregates,
+{
+    /// Builds texts of the summary rows out of the given data rows.
+    ///
+    /// Returns a number of the summary rows and the texts with their position within them.
+    pub(crate) fn build<R>(
+        &mut self,
+        records: R,
+        rows: Range<usize>,
+    ) -> (usize, Vec<(Position, String)>)
+    where
+        R: Records,
+    {
+        let count_columns = records.count_columns();
+
+        let mut aggregates = Vec::new();
+        self.aggregates.resolve(&records, &mut aggregates);
+
+        let count_summary_rows = aggregates
+            .iter()
+            .map(|&(row, _, _)| row)
+            .chain(self.labels.iter().map(|&(row, _, _)| row))
+            .max()
+            .map_or(0, |row| row + 1);
+
+        let mut texts = self
+            .labels
+            .iter()
+            .filter(|&&(_, col, _)| col < count_columns)
+            .map(|(row, col, text)| ((*row, *col), text.clone()))
+            .collect::<Vec<_>>();
+
+        for (row, col, function) in aggregates {
+            let values = rows
+                .clone()
+                .map(|r| records.get_text((r, col)))
+                .collect::<Vec<_>>();
+            let value = aggregate(function, values.iter().map(Cow::as_ref));
+
+            texts.push(((row, col), value));
+        }
+
+        (count_summary_rows, texts)
+    }
+
+    pub(crate) fn get_line(&self) -> Option<Line> {
+        self.line
+    }
+}
+
+impl<A, R> TableOption<R> for Summary<A>
+where
+    A: Aggregates,
+    R: Records + RecordsMut<String> + Resizable,
+{
+    fn change(&mut self, table: &mut Table<R>) {
+        let (count_rows, count_columns) = table.shape();
+        if count_columns == 0 {
+            return;
+        }
+
+        let rows = self.header.min(count_rows)..count_rows;
+        let (count_summary_rows, texts) = self.build(table.get_records(), rows);
+        if count_summary_rows == 0 {
+            return;
+        }
+
+        for _ in 0..count_summary_rows {
+            table.get_records_mut().push_row();
+        }
+
+        for ((row, col), text) in texts {
+            set_text(table, (count_rows + row, col), text);
+        }
+
+        if let Some(line) = self.line {
+            HorizontalLine::new(count_rows, line).change(table);
+        }
+
+        table.destroy_width_cache();
+        table.destroy_height_cache();
+    }
+}
+
+pub(crate) fn aggregate<'a, I>(function: Function, texts: I) -> String
+where
+    I: Iterator<Item = &'a str>,
+{
+    let reduce: fn(&[f64]) -> f64 = match function {
+        Function::Count => {
+            return texts
+                .filter(|text| !text.trim().is_empty())
+                .count()
+                .to_string()
+        }
+        Function::Sum => |values| values.iter().sum(),
+        Function::Avg => |values| values.iter().sum::<f64>() / values.len() as f64,
+        Function::Min => |values| values.iter().copied().fold(f64::INFINITY, f64::min),
+        Function::Max => |values| values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+    };
+
+    let numbers = texts.filter_map(Number::parse).collect::<Vec<_>>();
+    if numbers.is_empty() {
+        return String::new();
+    }
+
+    let precision = numbers.iter().map(|n| n.precision).max().unwrap_or(0);
+    let grouped = numbers.iter().any(|n| n.grouped);
+    let values = numbers.iter().map(|n| n.value).collect::<Vec<_>>();
+
+    format_number(reduce(&values), precision, grouped)
+}
+
+struct Number {
+    value: f64,
+    precision: usize,
+    grouped: bool,
+}
+
+impl Number {
+    fn parse(text: &str) -> Option<Self> {
+        let value = parse_number(text)?;
+        let precision = text.split_once('.').map_or(0, |(_, fraction)| {
+            fraction.chars().take_while(char::is_ascii_digit).count()
+        });
+
+        Some(Self {
+            value,
+            precision,
+            grouped: text.contains(','),
+        })
+    }
+}
+
+fn format_number(value: f64, precision: usize, grouped: bool) -> String {
+    // round half away from zero, as formatting rounds half to even
+    let scale = 10f64.powi(precision as i32);
+    let value = (value * scale).round() / scale;
+
+    let text = format!("{:.*}", precision, value);
+    if !grouped {
+        return text;
+    }
+
+    let (sign, text) = match text.strip_prefix('-') {
+        Some(text) => ("-", text),
+        None => ("", text.as_str()),
+    };
+
+    let (integer, fraction) = match text.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (text, None),
+    };
+
+    let mut buf = String::from(sign);
+    for (i, c) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            buf.push(',');
+        }
+
+        buf.push(c);
+    }
+
+    if let Some(fraction) = fraction {
+        buf.push('.');
+        buf.push_str(fraction);
+    }
+
+    buf
+}
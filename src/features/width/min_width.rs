@@ -117,7 +117,7 @@ where
             }
 
             let content = records.get_text(pos);
-            let content = increase_width(content, width, self.fill);
+            let content = increase_width(&content, width, self.fill);
             let records = table.get_records_mut();
             records.set(pos, content, &width_ctrl);
         }
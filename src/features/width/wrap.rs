@@ -112,7 +112,7 @@ where
             // todo: Think about it.
             //       We could eliminate this allcation if we would be allowed to cut '\t' with unknown characters.
             //       Currently we don't do that.
-            let text = papergrid::util::replace_tab(text, table.get_config().get_tab_width());
+            let text = papergrid::util::replace_tab(&text, table.get_config().get_tab_width());
             let wrapped = wrap_text(&text, width, self.keep_words);
 
             debug_assert!(
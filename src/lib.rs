@@ -238,14 +238,25 @@
 )]
 
 mod features;
+mod markdown;
 mod modify;
 mod table;
 mod table_iterator_ext;
+mod table_writer;
 mod tabled;
+mod util;
 
 pub mod builder;
+#[cfg(feature = "csv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+pub mod csv;
 pub mod display;
 pub mod object;
+pub mod parse;
+pub mod records;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod ser;
 
 #[cfg(feature = "macros")]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
@@ -257,26 +268,36 @@ pub use crate::{
     features::{
         alignment::{self, Alignment},
         concat::Concat,
+        diff::TableDiff,
         disable::Disable,
         extract::Extract,
+        filter::Filter,
         format, formatting,
+        group_by::GroupBy,
         height::{self, Height},
         highlight::Highlight,
         locator,
         margin::Margin,
         measurment, merge,
         padding::Padding,
+        pagination::Pagination,
         panel::{Footer, Header, Panel},
         peaker,
+        pivot::{self, Pivot},
+        reorder::Reorder,
         rotate::Rotate,
+        sort::{self, Sort},
         span::Span,
+        split::Split,
         style::{self, Border, BorderText, Style},
+        summary::{self, Summary},
         width::{self, Width},
     },
     modify::{CellSettingsList, Modify, ModifyList, ModifyObject},
     table::{CellOption, Table, TableOption},
     table_iterator_ext::TableIteratorExt,
-    tabled::Tabled,
+    table_writer::TableWriter,
+    tabled::{HumanDuration, KeyValue, Tabled, TabledDynamic},
 };
 
 #[cfg(feature = "color")]
@@ -471,6 +492,59 @@ pub use crate::features::{color, highlight, margin_color, padding_color};
 ///     price: f32,
 /// }
 /// ```
+///
+/// ### Column layout
+///
+/// An alignment, a width and a color of a column can be set on a field.
+/// They are applied to the values only, a header is left as it is.
+///
+/// A `color` attribute requires a `color` feature, otherwise it's a compile error.
+///
+#[cfg_attr(feature = "color", doc = "```")]
+#[cfg_attr(not(feature = "color"), doc = "```compile_fail")]
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Status {
+///     #[tabled(align = "right", color = "bright_red")]
+///     code: u16,
+///     #[tabled(max_width = 10, wrap)]
+///     message: &'static str,
+/// }
+///
+/// let table = Table::new([Status { code: 500, message: "Internal Server Error" }]).to_string();
+///
+/// assert!(table.contains("\u{1b}[91m500\u{1b}[39m"));
+/// ```
+///
+/// ### Flatten a map
+///
+/// A map field can be turned into columns by `#[tabled(flatten_map)]`.
+///
+/// As the columns are known only at runtime such a type implements [`TabledDynamic`] **instead of** [`Tabled`],
+/// so it must be used with [`Table::from_dynamic`] rather than [`Table::new`].
+///
+/// A field must implement [`TabledDynamic`], like a [`BTreeMap`] or a [`HashMap`] does.
+/// The entries of a [`HashMap`] are sorted by a key, so the order of columns is stable.
+///
+/// ```rust,no_run
+/// use std::collections::BTreeMap;
+/// use tabled::{Table, Tabled};
+///
+/// #[derive(Tabled)]
+/// struct Host {
+///     name: &'static str,
+///     #[tabled(flatten_map)]
+///     metrics: BTreeMap<&'static str, f32>,
+/// }
+///
+/// let hosts = [Host { name: "alpha", metrics: BTreeMap::from([("cpu", 0.5)]) }];
+///
+/// let table = Table::from_dynamic(hosts);
+/// ```
+///
+/// [`HashMap`]: std::collections::HashMap
+/// [`BTreeMap`]: std::collections::BTreeMap
 // @todo: Move the comment to tabled_derive
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
@@ -0,0 +1,134 @@
+//! This module contains a GitHub Flavored Markdown export of a [`Table`].
+//!
+//! [`Table`]: crate::Table
+
+use papergrid::{records::Records, util::string_width, AlignmentHorizontal, Entity};
+
+use crate::{util::strip_ansi, Table};
+
+/// Renders a table as a GFM table, where a first row is a header.
+pub(crate) fn to_markdown<R>(table: &Table<R>) -> String
+where
+    R: Records,
+{
+    let shape = table.shape();
+    let (count_rows, count_columns) = shape;
+    if count_rows == 0 || count_columns == 0 {
+        return String::new();
+    }
+
+    let cfg = table.get_config();
+    let records = table.get_records();
+
+    let rows = (0..count_rows)
+        .map(|row| {
+            (0..count_columns)
+                .map(|col| {
+                    if cfg.is_cell_visible((row, col), shape) {
+                        escape(&records.get_text((row, col)))
+                    } else {
+                        String::new()
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let alignments = (0..count_columns)
+        .map(|col| *cfg.get_alignment_horizontal(Entity::Column(col)))
+        .collect::<Vec<_>>();
+
+    // a left alignment is a default one so it's marked only if a column has it set explicitly
+    let marked = (0..count_columns)
+        .map(|col| cfg.is_alignment_horizontal_set(Entity::Column(col)))
+        .collect::<Vec<_>>();
+
+    // a delimiter cell needs at least a single `-` besides colons
+    let widths = (0..count_columns)
+        .map(|col| {
+            rows.iter()
+                .map(|row| string_width(&row[col]))
+                .max()
+                .unwrap_or(0)
+                .max(1)
+        })
+        .collect::<Vec<_>>();
+
+    let mut lines = Vec::with_capacity(count_rows + 1);
+    for (i, row) in rows.iter().enumerate() {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .zip(&alignments)
+            .map(|((text, &width), &alignment)| pad(text, width, alignment));
+        lines.push(make_line(cells));
+
+        if i == 0 {
+            let delimiters =
+                widths.iter().zip(&alignments).zip(&marked).map(
+                    |((&width, &alignment), &marked)| make_delimiter(width, alignment, marked),
+                );
+            lines.push(make_line(delimiters));
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn make_line<I>(cells: I) -> String
+where
+    I: Iterator<Item = String>,
+{
+    let mut line = String::from("|");
+    for cell in cells {
+        line.push_str(&cell);
+        line.push('|');
+    }
+
+    line
+}
+
+fn make_delimiter(width: usize, alignment: AlignmentHorizontal, marked: bool) -> String {
+    let dashes = "-".repeat(width);
+    match alignment {
+        AlignmentHorizontal::Left if marked => format!(":{}-", dashes),
+        AlignmentHorizontal::Left => format!("-{}-", dashes),
+        AlignmentHorizontal::Center => format!(":{}:", dashes),
+        AlignmentHorizontal::Right => format!("-{}:", dashes),
+    }
+}
+
+fn pad(text: &str, width: usize, alignment: AlignmentHorizontal) -> String {
+    let rest = width - string_width(text);
+    let (left, right) = match alignment {
+        AlignmentHorizontal::Left => (0, rest),
+        AlignmentHorizontal::Right => (rest, 0),
+        AlignmentHorizontal::Center => (rest / 2, rest - rest / 2),
+    };
+
+    format!(" {}{}{} ", " ".repeat(left), text, " ".repeat(right))
+}
+
+/// Makes a text safe to be put in a cell.
+///
+/// ANSI sequences are removed, pipes and backslashes are escaped and new lines are replaced by `<br>`.
+fn escape(text: &str) -> String {
+    let text = strip_ansi(text);
+
+    let mut buf = String::with_capacity(text.len());
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            buf.push_str("<br>");
+        }
+
+        for c in line.chars() {
+            if c == '\\' || c == '|' {
+                buf.push('\\');
+            }
+
+            buf.push(c);
+        }
+    }
+
+    buf
+}
@@ -0,0 +1,369 @@
+//! This module contains a [`Rendered`] structure which parses a rendered table back into data.
+//!
+//! It's used by [`Builder::from_rendered`].
+//!
+//! # Example
+//!
+//! ```
+//! use tabled::{parse::Rendered, Style};
+//!
+//! let text = concat!(
+//!     "+----+-----------+\n",
+//!     "| id | name      |\n",
+//!     "+----+-----------+\n",
+//!     "| 0  | tabled    |\n",
+//!     "+----+-----------+\n",
+//!     "| 1  | papergrid |\n",
+//!     "+----+-----------+",
+//! );
+//!
+//! let rendered = Rendered::parse(text, Style::ascii());
+//!
+//! assert_eq!(
+//!     rendered.get_rows(),
+//!     [["id", "name"], ["0", "tabled"], ["1", "papergrid"]],
+//! );
+//! ```
+//!
+//! [`Builder::from_rendered`]: crate::builder::Builder::from_rendered
+
+use std::{collections::BTreeSet, ops::Range};
+
+use papergrid::{util::string_width, Borders, Position};
+
+use crate::{
+    builder::Builder,
+    object::Cell,
+    style::{Line, RawStyle},
+    Modify, Span, Table,
+};
+
+/// Rendered is a table which was parsed from its text.
+///
+/// Borders are recognized by the characters of a given style,
+/// so any of the [`Style`] presets can be used, as well as a custom [`RawStyle`].
+///
+/// A first row is considered to be a header.
+///
+/// Multi-line cells are recovered only when rows are split by horizontal lines,
+/// like in [`Style::ascii`] or [`Style::modern`].
+/// Otherwise each line is considered to be a separate row.
+///
+/// Columns are found by intersections of horizontal lines and by vertical lines.
+/// If a vertical line is missing in a row the cells around it are considered to be spanned.
+/// A style which uses spaces as vertical lines, like [`Style::blank`],
+/// relies on horizontal lines if there are any and otherwise on columns of at least 3 spaces.
+///
+/// If lines have different widths, like in a handwritten markdown table,
+/// each line is split by vertical lines instead.
+///
+/// Cell text is trimmed, so alignment and padding are not recovered.
+///
+/// [`Style`]: crate::Style
+/// [`Style::ascii`]: crate::Style::ascii
+/// [`Style::modern`]: crate::Style::modern
+/// [`Style::blank`]: crate::Style::blank
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Rendered {
+    rows: Vec<Vec<String>>,
+    spans: Vec<(Position, usize)>,
+}
+
+impl Rendered {
+    /// Parses a table rendered with a given style.
+    pub fn parse<S>(text: &str, style: S) -> Self
+    where
+        S: Into<RawStyle>,
+    {
+        parse(text, &style.into())
+    }
+
+    /// Returns a text of cells by rows.
+    ///
+    /// A spanned cell has a text in its first column, the rest of its columns are empty.
+    pub fn get_rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    /// Returns column spans by a position of a spanned cell.
+    pub fn get_spans(&self) -> &[(Position, usize)] {
+        &self.spans
+    }
+
+    /// Builds a [`Table`] out of the parsed data, including column spans.
+    ///
+    /// [`Table`]: crate::Table
+    pub fn build(self) -> Table {
+        let spans = self.spans.clone();
+
+        let mut table = Builder::from(self).build();
+        for ((row, col), span) in spans {
+            table.with(Modify::new(Cell(row, col)).with(Span::column(span)));
+        }
+
+        table
+    }
+}
+
+impl From<Rendered> for Builder<'_> {
+    fn from(rendered: Rendered) -> Self {
+        let mut rows = rendered.rows.into_iter();
+
+        let mut builder = Builder::default();
+        if let Some(header) = rows.next() {
+            builder.set_columns(header);
+        }
+
+        for row in rows {
+            builder.add_record(row);
+        }
+
+        builder
+    }
+}
+
+fn parse(text: &str, style: &RawStyle) -> Rendered {
+    let borders = style.get_borders();
+    let lines = text.lines().map(split_columns).collect::<Vec<_>>();
+
+    let horizontal_lines = [
+        Line::new(
+            borders.top,
+            borders.top_intersection,
+            borders.top_left,
+            borders.top_right,
+        ),
+        Line::new(
+            borders.bottom,
+            borders.bottom_intersection,
+            borders.bottom_left,
+            borders.bottom_right,
+        ),
+        Line::new(
+            borders.horizontal,
+            borders.intersection,
+            borders.horizontal_left,
+            borders.horizontal_right,
+        ),
+    ];
+    let horizontal_lines = horizontal_lines
+        .iter()
+        .chain(style.get_horizontals().values())
+        .collect::<Vec<_>>();
+
+    let mains = horizontal_lines
+        .iter()
+        .filter_map(|line| line.main)
+        .collect::<Vec<_>>();
+    let line_chars = horizontal_lines
+        .iter()
+        .flat_map(|line| {
+            [
+                line.main,
+                line.intersection,
+                line.connector1,
+                line.connector2,
+            ]
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+    let intersections = line_chars
+        .iter()
+        .copied()
+        .filter(|c| !mains.contains(c))
+        .collect::<Vec<_>>();
+
+    let verticals = [
+        borders.vertical_left,
+        borders.vertical_right,
+        borders.vertical,
+    ]
+    .iter()
+    .copied()
+    .chain(style.get_verticals().values().map(|line| line.main))
+    .flatten()
+    .collect::<Vec<_>>();
+
+    let is_border_line = |line: &[char]| {
+        line.iter().any(|c| mains.contains(c)) && line.iter().all(|c| line_chars.contains(c))
+    };
+
+    let has_border_lines = lines.iter().any(|line| is_border_line(line));
+    let content_lines = lines
+        .iter()
+        .filter(|line| !is_border_line(line))
+        .collect::<Vec<_>>();
+
+    let width = lines.iter().map(Vec::len).max().unwrap_or(0);
+    if content_lines.iter().any(|line| line.len() != width) {
+        return parse_unaligned(&content_lines, &verticals, borders);
+    }
+
+    let mut boundaries = BTreeSet::new();
+    for line in lines.iter().filter(|line| is_border_line(line)) {
+        for (i, c) in line.iter().enumerate() {
+            if intersections.contains(c) {
+                boundaries.insert(i);
+            }
+        }
+    }
+
+    let is_spaced = verticals.contains(&' ');
+    let is_ambiguous = is_spaced && has_border_lines;
+    if !(verticals.is_empty() || content_lines.is_empty() || is_ambiguous) {
+        let is_vertical = (0..width)
+            .map(|i| {
+                content_lines
+                    .iter()
+                    .all(|line| matches!(line.get(i), Some(c) if verticals.contains(c)))
+            })
+            .collect::<Vec<_>>();
+
+        let is_run = |start: usize| (start..start + 3).all(|i| is_vertical.get(i) == Some(&true));
+
+        for i in (0..width).filter(|&i| is_vertical[i]) {
+            // a single space is more likely a part of a text than a vertical line with padding
+            let is_boundary = !is_spaced || (i.saturating_sub(2)..=i).any(is_run);
+            if is_boundary {
+                boundaries.insert(i);
+            }
+        }
+    }
+
+    let mut columns = Vec::new();
+    let mut start = 0;
+    for &boundary in boundaries.iter().chain(std::iter::once(&width)) {
+        if boundary > start {
+            columns.push(start..boundary);
+        }
+
+        start = boundary + 1;
+    }
+
+    // rows are separated by lines only when each row has one
+    let is_row_separated = borders.horizontal.is_some();
+
+    let mut groups: Vec<Vec<&[char]>> = Vec::new();
+    let mut group = Vec::new();
+    for line in &lines {
+        if is_border_line(line) {
+            if !group.is_empty() {
+                groups.push(std::mem::take(&mut group));
+            }
+        } else if is_row_separated {
+            group.push(line.as_slice());
+        } else {
+            groups.push(vec![line.as_slice()]);
+        }
+    }
+
+    if !group.is_empty() {
+        groups.push(group);
+    }
+
+    let mut rows = Vec::with_capacity(groups.len());
+    let mut spans = Vec::new();
+    for (row, lines) in groups.iter().enumerate() {
+        let is_separator = |pos: usize| {
+            verticals.is_empty() || !matches!(lines[0].get(pos), Some(c) if !verticals.contains(c))
+        };
+
+        let mut cells = vec![String::new(); columns.len()];
+        let mut col = 0;
+        while col < columns.len() {
+            let mut last = col;
+            while last + 1 < columns.len() && !is_separator(columns[last].end) {
+                last += 1;
+            }
+
+            cells[col] = cell_text(lines, columns[col].start..columns[last].end);
+            if last > col {
+                spans.push(((row, col), last - col + 1));
+            }
+
+            col = last + 1;
+        }
+
+        rows.push(cells);
+    }
+
+    Rendered { rows, spans }
+}
+
+/// Parses a table which lines are not aligned, like a handwritten markdown table.
+///
+/// Each line is split by vertical lines.
+fn parse_unaligned(lines: &[&Vec<char>], verticals: &[char], borders: &Borders<char>) -> Rendered {
+    let mut rows = lines
+        .iter()
+        .map(|line| {
+            let mut cells = line
+                .split(|c| verticals.contains(c))
+                .map(|cell| cell.iter().filter(|&&c| c != '\0').collect::<String>())
+                .map(|cell| cell.trim().to_owned())
+                .collect::<Vec<_>>();
+
+            if borders.vertical_right.is_some()
+                && cells.len() > 1
+                && cells[cells.len() - 1].is_empty()
+            {
+                cells.pop();
+            }
+
+            if borders.vertical_left.is_some() && cells.len() > 1 && cells[0].is_empty() {
+                cells.remove(0);
+            }
+
+            cells
+        })
+        .collect::<Vec<_>>();
+
+    let count_columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(count_columns, String::new());
+    }
+
+    Rendered {
+        rows,
+        spans: Vec::new(),
+    }
+}
+
+/// Splits a line into display columns.
+///
+/// A wide character is followed by `'\0'`s so positions are the same across lines.
+fn split_columns(line: &str) -> Vec<char> {
+    let mut columns = Vec::with_capacity(line.len());
+    let mut buf = [0; 4];
+    for c in line.chars() {
+        columns.push(c);
+
+        let width = string_width(c.encode_utf8(&mut buf));
+        columns.resize(columns.len() + width.saturating_sub(1), '\0');
+    }
+
+    columns
+}
+
+fn cell_text(lines: &[&[char]], range: Range<usize>) -> String {
+    let lines = lines
+        .iter()
+        .map(|line| {
+            let end = range.end.min(line.len());
+            let start = range.start.min(end);
+            let text = line[start..end]
+                .iter()
+                .filter(|&&c| c != '\0')
+                .collect::<String>();
+
+            text.trim().to_owned()
+        })
+        .collect::<Vec<_>>();
+
+    let first = lines.iter().position(|line| !line.is_empty());
+    let last = lines.iter().rposition(|line| !line.is_empty());
+    match (first, last) {
+        (Some(first), Some(last)) => lines[first..=last].join("\n"),
+        _ => String::new(),
+    }
+}
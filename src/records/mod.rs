@@ -0,0 +1,7 @@
+//! A module which contains [`Records`] implementations which are specific to this crate.
+//!
+//! [`Records`]: papergrid::records::Records
+
+mod tabled_records;
+
+pub use tabled_records::TabledRecords;
@@ -0,0 +1,221 @@
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    fmt::{self, Debug},
+};
+
+use papergrid::{
+    records::Records,
+    util::{count_lines, get_lines},
+    width::WidthFunc,
+    Position,
+};
+
+use crate::Tabled;
+
+/// [`TabledRecords`] is a [`Records`] implementation over a slice of [`Tabled`] values.
+///
+/// Unlike [`Table::new`] it doesn't copy the data into cells,
+/// [`Tabled::fields`] is called lazily when a cell is requested.
+/// Only the fields of a last requested row are kept.
+///
+/// A first row is a header built from [`Tabled::headers`].
+///
+/// If a type expands a value into several rows by [`Tabled::rows`],
+/// a number of rows of each value is counted once it's created.
+///
+/// ```
+/// use tabled::{Style, Table};
+///
+/// let data = vec![(1, "Hello"), (2, "World")];
+///
+/// let mut table = Table::lazy(&data);
+/// table.with(Style::psql());
+///
+/// assert_eq!(
+///     table.to_string(),
+///     concat!(
+///         " i32 | &str  \n",
+///         "-----+-------\n",
+///         " 1   | Hello \n",
+///         " 2   | World ",
+///     )
+/// );
+/// ```
+///
+/// [`Records`]: papergrid::records::Records
+/// [`Table::new`]: crate::Table::new
+pub struct TabledRecords<'a, T> {
+    records: &'a [T],
+    header: Vec<Cow<'static, str>>,
+    index: Option<Vec<(usize, usize)>>,
+    row: RefCell<Option<(usize, Vec<Cow<'a, str>>)>>,
+}
+
+impl<'a, T> TabledRecords<'a, T>
+where
+    T: Tabled,
+{
+    /// Creates a new [`TabledRecords`] from a slice.
+    pub fn new(records: &'a [T]) -> Self {
+        let index = if T::expanded_columns().is_empty() {
+            None
+        } else {
+            let index = records
+                .iter()
+                .enumerate()
+                .flat_map(|(i, record)| (0..record.rows().len()).map(move |j| (i, j)))
+                .collect();
+            Some(index)
+        };
+
+        Self {
+            records,
+            header: T::headers(),
+            index,
+            row: RefCell::default(),
+        }
+    }
+
+    /// Returns a list of a first row and a number of rows of values which take more then 1 row.
+    pub(crate) fn expanded_rows(&self) -> Vec<(usize, usize)> {
+        let index = match &self.index {
+            Some(index) => index,
+            None => return Vec::new(),
+        };
+
+        let mut rows = Vec::new();
+        for (row, &(i, j)) in index.iter().enumerate() {
+            let is_last = !matches!(index.get(row + 1), Some(&(next, _)) if next == i);
+            if is_last && j > 0 {
+                rows.push((row - j + 1, j + 1));
+            }
+        }
+
+        rows
+    }
+
+    fn with_cell<F, O>(&self, (row, col): Position, f: F) -> O
+    where
+        F: FnOnce(&Cow<'a, str>) -> O,
+    {
+        if row == 0 {
+            return f(&self.header[col]);
+        }
+
+        let mut cache = self.row.borrow_mut();
+        let is_cached = matches!(&*cache, Some((i, _)) if *i == row);
+        if !is_cached {
+            let fields = match &self.index {
+                Some(index) => {
+                    let (i, j) = index[row - 1];
+                    let record: &'a T = &self.records[i];
+                    record.rows().swap_remove(j)
+                }
+                None => {
+                    let record: &'a T = &self.records[row - 1];
+                    record.fields()
+                }
+            };
+
+            *cache = Some((row, fields));
+        }
+
+        let (_, fields) = cache.as_ref().expect("the row must be cached");
+
+        f(&fields[col])
+    }
+}
+
+impl<T> TabledRecords<'_, T> {
+    /// Returns a reference to an underlying slice.
+    pub fn as_slice(&self) -> &[T] {
+        self.records
+    }
+}
+
+impl<'a, T> Records for TabledRecords<'a, T>
+where
+    T: Tabled,
+{
+    fn count_rows(&self) -> usize {
+        self.index.as_ref().map_or(self.records.len(), Vec::len) + 1
+    }
+
+    fn count_columns(&self) -> usize {
+        T::LENGTH
+    }
+
+    fn get_text(&self, pos: Position) -> Cow<'_, str> {
+        self.with_cell(pos, Cow::clone)
+    }
+
+    fn get_line(&self, pos: Position, i: usize) -> Cow<'_, str> {
+        self.with_cell(pos, |text| match text {
+            Cow::Borrowed(text) => get_lines(text).nth(i).unwrap_or_default(),
+            Cow::Owned(text) => {
+                let line = get_lines(text).nth(i).unwrap_or_default();
+                Cow::Owned(line.into_owned())
+            }
+        })
+    }
+
+    fn count_lines(&self, pos: Position) -> usize {
+        self.with_cell(pos, |text| count_lines(text))
+    }
+
+    fn get_width<W>(&self, pos: Position, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        self.with_cell(pos, |text| width_ctrl.width_multiline(text))
+    }
+
+    fn get_line_width<W>(&self, pos: Position, i: usize, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        self.with_cell(pos, |text| {
+            get_lines(text)
+                .nth(i)
+                .map_or(0, |line| width_ctrl.width(&line))
+        })
+    }
+
+    fn fmt_text_prefix<F>(&self, _: &mut F, _: Position) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
+        Ok(())
+    }
+
+    fn fmt_text_suffix<F>(&self, _: &mut F, _: Position) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
+        Ok(())
+    }
+}
+
+impl<T> Clone for TabledRecords<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            records: self.records,
+            header: self.header.clone(),
+            index: self.index.clone(),
+            row: RefCell::default(),
+        }
+    }
+}
+
+impl<T> Debug for TabledRecords<'_, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TabledRecords")
+            .field("records", &self.records)
+            .field("header", &self.header)
+            .finish()
+    }
+}
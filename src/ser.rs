@@ -0,0 +1,966 @@
+//! This module contains a [`Serializer`] which builds a [`Table`] from any type implementing [`serde::Serialize`].
+//!
+//! It's useful for types which can't implement [`Tabled`], like foreign ones.
+//!
+//! - A sequence of structs or maps becomes rows with a header made of field names.
+//! - A single struct or map becomes a key/value table with a `key`/`value` header.
+//!   Nested ones are shown as key/value tables without a header, like [`ExpandedDisplay`].
+//! - Anything else becomes a table without a header.
+//!
+//! Nested structs and maps become prefixed columns or nested tables, see [`Nested`].
+//! Nested sequences are always rendered as nested tables.
+//!
+//! # Example
+//!
+//! ```
+//! use serde::Serialize;
+//! use tabled::{ser, Style};
+//!
+//! #[derive(Serialize)]
+//! struct Release {
+//!     version: &'static str,
+//!     date: Date,
+//! }
+//!
+//! #[derive(Serialize)]
+//! struct Date {
+//!     year: u16,
+//!     month: u8,
+//! }
+//!
+//! let releases = [
+//!     Release { version: "0.8.0", date: Date { year: 2022, month: 7 } },
+//!     Release { version: "0.9.0", date: Date { year: 2022, month: 9 } },
+//! ];
+//!
+//! let mut table = ser::to_table(&releases).unwrap();
+//! table.with(Style::psql());
+//!
+//! assert_eq!(
+//!     table.to_string(),
+//!     concat!(
+//!         " version | date.year | date.month \n",
+//!         "---------+-----------+------------\n",
+//!         " 0.8.0   | 2022      | 7          \n",
+//!         " 0.9.0   | 2022      | 9          ",
+//!     )
+//! );
+//! ```
+//!
+//! [`Table`]: crate::Table
+//! [`Tabled`]: crate::Tabled
+//! [`ExpandedDisplay`]: crate::display::ExpandedDisplay
+
+use std::fmt::{self, Display};
+
+use serde::ser::{self, Serialize};
+
+use crate::{builder::Builder, Table};
+
+/// Builds a [`Table`] from a value using a default [`Serializer`].
+///
+/// [`Table`]: crate::Table
+pub fn to_table<T>(value: &T) -> Result<Table, Error>
+where
+    T: ?Sized + Serialize,
+{
+    value.serialize(Serializer::new())
+}
+
+/// Serializer is a [`serde::Serializer`] which produces a [`Table`].
+///
+/// [`Table`]: crate::Table
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Serializer {
+    nested: Nested,
+}
+
+impl Serializer {
+    /// Creates a [`Serializer`] which inlines nested structs.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how nested structs and maps are shown.
+    pub fn nested(mut self, nested: Nested) -> Self {
+        self.nested = nested;
+        self
+    }
+
+    fn build(self, value: Value) -> Result<Table, Error> {
+        Ok(build_table(value, self.nested, true))
+    }
+}
+
+/// Nested sets how nested structs and maps are shown.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Nested {
+    /// Fields of a nested struct become separate columns prefixed by a name of the struct field,
+    /// like `date.year`, similar to `#[tabled(inline)]`.
+    #[default]
+    Inline,
+    /// A nested struct is rendered as a nested key/value table in a single cell.
+    Table,
+}
+
+/// An error which may be returned by a [`Serialize`] implementation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(String);
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: Display,
+    {
+        Self(msg.to_string())
+    }
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = Table;
+    type Error = Error;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = SeqSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = MapSerializer;
+
+    fn serialize_bool(self, v: bool) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_bool(v)?)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_i8(v)?)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_i16(v)?)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_i32(v)?)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_i64(v)?)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_i128(v)?)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_u8(v)?)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_u16(v)?)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_u32(v)?)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_u64(v)?)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_u128(v)?)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_f32(v)?)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_f64(v)?)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_char(v)?)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_str(v)?)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_bytes(v)?)
+    }
+
+    fn serialize_none(self) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_none()?)
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Table, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.build(ValueSerializer.serialize_some(value)?)
+    }
+
+    fn serialize_unit(self) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_unit()?)
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Table, Error> {
+        self.build(ValueSerializer.serialize_unit_struct(name)?)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Table, Error> {
+        let value = ValueSerializer.serialize_unit_variant(name, variant_index, variant)?;
+        self.build(value)
+    }
+
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<Table, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.build(ValueSerializer.serialize_newtype_struct(name, value)?)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Table, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value =
+            ValueSerializer.serialize_newtype_variant(name, variant_index, variant, value)?;
+        self.build(value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, Error> {
+        let inner = ValueSerializer.serialize_seq(len)?;
+        Ok(SeqSerializer::new(inner, self.nested))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, Error> {
+        let inner = ValueSerializer.serialize_tuple(len)?;
+        Ok(SeqSerializer::new(inner, self.nested))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, Error> {
+        let inner = ValueSerializer.serialize_tuple_struct(name, len)?;
+        Ok(SeqSerializer::new(inner, self.nested))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, Error> {
+        let inner = ValueSerializer.serialize_tuple_variant(name, variant_index, variant, len)?;
+        Ok(SeqSerializer::new(inner, self.nested))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapSerializer, Error> {
+        let inner = ValueSerializer.serialize_map(len)?;
+        Ok(MapSerializer::new(inner, self.nested))
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<MapSerializer, Error> {
+        let inner = ValueSerializer.serialize_struct(name, len)?;
+        Ok(MapSerializer::new(inner, self.nested))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<MapSerializer, Error> {
+        let inner = ValueSerializer.serialize_struct_variant(name, variant_index, variant, len)?;
+        Ok(MapSerializer::new(inner, self.nested))
+    }
+}
+
+/// SeqSerializer builds a [`Table`] from a sequence, a tuple or a tuple variant.
+///
+/// It's returned by [`Serializer`].
+///
+/// [`Table`]: crate::Table
+#[derive(Debug)]
+pub struct SeqSerializer {
+    inner: ValueSeq,
+    nested: Nested,
+}
+
+impl SeqSerializer {
+    fn new(inner: ValueSeq, nested: Nested) -> Self {
+        Self { inner, nested }
+    }
+
+    fn end(self) -> Result<Table, Error> {
+        Ok(build_table(self.inner.finish(), self.nested, true))
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Table;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> Result<Table, Error> {
+        SeqSerializer::end(self)
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Table;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> Result<Table, Error> {
+        SeqSerializer::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Table;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> Result<Table, Error> {
+        SeqSerializer::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for SeqSerializer {
+    type Ok = Table;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> Result<Table, Error> {
+        SeqSerializer::end(self)
+    }
+}
+
+/// MapSerializer builds a [`Table`] from a map, a struct or a struct variant.
+///
+/// It's returned by [`Serializer`].
+///
+/// [`Table`]: crate::Table
+#[derive(Debug)]
+pub struct MapSerializer {
+    inner: ValueMap,
+    nested: Nested,
+}
+
+impl MapSerializer {
+    fn new(inner: ValueMap, nested: Nested) -> Self {
+        Self { inner, nested }
+    }
+
+    fn end(self) -> Result<Table, Error> {
+        Ok(build_table(self.inner.finish(), self.nested, true))
+    }
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Table;
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.key(key)
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.value(value)
+    }
+
+    fn end(self) -> Result<Table, Error> {
+        MapSerializer::end(self)
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = Table;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.field(key, value)
+    }
+
+    fn end(self) -> Result<Table, Error> {
+        MapSerializer::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for MapSerializer {
+    type Ok = Table;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.field(key, value)
+    }
+
+    fn end(self) -> Result<Table, Error> {
+        MapSerializer::end(self)
+    }
+}
+
+/// An intermediate representation of a serialized value.
+///
+/// Enums are represented externally tagged, like `{ "Variant": value }`.
+#[derive(Debug)]
+enum Value {
+    Text(String),
+    Seq(Vec<Value>),
+    Map(Vec<(String, Value)>),
+}
+
+struct ValueSerializer;
+
+impl ValueSerializer {
+    fn text<T>(text: T) -> Result<Value, Error>
+    where
+        T: Display,
+    {
+        Ok(Value::Text(text.to_string()))
+    }
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = Error;
+    type SerializeSeq = ValueSeq;
+    type SerializeTuple = ValueSeq;
+    type SerializeTupleStruct = ValueSeq;
+    type SerializeTupleVariant = ValueSeq;
+    type SerializeMap = ValueMap;
+    type SerializeStruct = ValueMap;
+    type SerializeStructVariant = ValueMap;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        Self::text(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Error> {
+        let bytes = v.iter().map(|b| Value::Text(b.to_string())).collect();
+        Ok(Value::Seq(bytes))
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        Self::text("")
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Value, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Self::text("")
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Value, Error> {
+        Self::text("")
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        Self::text(variant)
+    }
+
+    fn serialize_newtype_struct<T>(self, _: &'static str, value: &T) -> Result<Value, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = value.serialize(self)?;
+        Ok(Value::Map(vec![(variant.to_owned(), value)]))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<ValueSeq, Error> {
+        Ok(ValueSeq::new(len.unwrap_or(0), None))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<ValueSeq, Error> {
+        Ok(ValueSeq::new(len, None))
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, len: usize) -> Result<ValueSeq, Error> {
+        Ok(ValueSeq::new(len, None))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<ValueSeq, Error> {
+        Ok(ValueSeq::new(len, Some(variant)))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<ValueMap, Error> {
+        Ok(ValueMap::new(len.unwrap_or(0), None))
+    }
+
+    fn serialize_struct(self, _: &'static str, len: usize) -> Result<ValueMap, Error> {
+        Ok(ValueMap::new(len, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<ValueMap, Error> {
+        Ok(ValueMap::new(len, Some(variant)))
+    }
+}
+
+#[derive(Debug)]
+struct ValueSeq {
+    items: Vec<Value>,
+    variant: Option<&'static str>,
+}
+
+impl ValueSeq {
+    fn new(len: usize, variant: Option<&'static str>) -> Self {
+        Self {
+            items: Vec::with_capacity(len),
+            variant,
+        }
+    }
+
+    fn push<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.items.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Value {
+        let value = Value::Seq(self.items);
+        match self.variant {
+            Some(variant) => Value::Map(vec![(variant.to_owned(), value)]),
+            None => value,
+        }
+    }
+}
+
+impl ser::SerializeSeq for ValueSeq {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTuple for ValueSeq {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleStruct for ValueSeq {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleVariant for ValueSeq {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+#[derive(Debug)]
+struct ValueMap {
+    entries: Vec<(String, Value)>,
+    key: Option<String>,
+    variant: Option<&'static str>,
+}
+
+impl ValueMap {
+    fn new(len: usize, variant: Option<&'static str>) -> Self {
+        Self {
+            entries: Vec::with_capacity(len),
+            key: None,
+            variant,
+        }
+    }
+
+    fn key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let key = key.serialize(ValueSerializer)?;
+        self.key = Some(render(key, Nested::default()));
+        Ok(())
+    }
+
+    fn value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let key = self.key.take().ok_or_else(|| {
+            <Error as ser::Error>::custom("a map value is serialized before a key")
+        })?;
+        self.entries.push((key, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+
+    fn field<T>(&mut self, key: &str, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = value.serialize(ValueSerializer)?;
+        self.entries.push((key.to_owned(), value));
+        Ok(())
+    }
+
+    fn finish(self) -> Value {
+        let value = Value::Map(self.entries);
+        match self.variant {
+            Some(variant) => Value::Map(vec![(variant.to_owned(), value)]),
+            None => value,
+        }
+    }
+}
+
+impl ser::SerializeMap for ValueMap {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.key(key)
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.value(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeStruct for ValueMap {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeStructVariant for ValueMap {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+/// Builds a table out of a value.
+///
+/// A single map gets a `key`/`value` header if `header` is set, which is done only for a root table.
+fn build_table(value: Value, nested: Nested, header: bool) -> Table {
+    let mut builder = Builder::default();
+
+    match value {
+        Value::Text(text) => {
+            builder.add_record([text]);
+        }
+        Value::Map(entries) => {
+            if header {
+                builder.set_columns(["key", "value"]);
+            }
+
+            let mut fields = Vec::new();
+            flatten(entries, "", nested, &mut fields);
+
+            for (key, text) in fields {
+                builder.add_record([key, text]);
+            }
+        }
+        Value::Seq(items) => {
+            let is_records = !items.is_empty() && items.iter().all(|v| matches!(v, Value::Map(_)));
+            if is_records {
+                add_records(&mut builder, items, nested);
+            } else {
+                for item in items {
+                    let row = match item {
+                        Value::Seq(values) => {
+                            values.into_iter().map(|v| render(v, nested)).collect()
+                        }
+                        Value::Map(entries) => {
+                            let mut fields = Vec::new();
+                            flatten(entries, "", nested, &mut fields);
+                            fields.into_iter().map(|(_, text)| text).collect()
+                        }
+                        value => vec![render(value, nested)],
+                    };
+
+                    builder.add_record(row);
+                }
+            }
+        }
+    }
+
+    builder.build()
+}
+
+/// Adds a row for each map, where a header is a union of their keys in order of appearance.
+fn add_records(builder: &mut Builder<'_>, items: Vec<Value>, nested: Nested) {
+    let mut columns: Vec<String> = Vec::new();
+    let mut rows = Vec::with_capacity(items.len());
+    for item in items {
+        let mut fields = Vec::new();
+        if let Value::Map(entries) = item {
+            flatten(entries, "", nested, &mut fields);
+        }
+
+        for (key, _) in &fields {
+            if !columns.contains(key) {
+                columns.push(key.clone());
+            }
+        }
+
+        rows.push(fields);
+    }
+
+    for mut fields in rows {
+        let row = columns
+            .iter()
+            .map(
+                |column| match fields.iter().position(|(key, _)| key == column) {
+                    Some(i) => fields.swap_remove(i).1,
+                    None => String::new(),
+                },
+            )
+            .collect::<Vec<_>>();
+
+        builder.add_record(row);
+    }
+
+    builder.set_columns(columns);
+}
+
+/// Converts entries of a map into a list of fields,
+/// where nested maps are either inlined with a prefix or rendered as tables.
+fn flatten(
+    entries: Vec<(String, Value)>,
+    prefix: &str,
+    nested: Nested,
+    fields: &mut Vec<(String, String)>,
+) {
+    for (key, value) in entries {
+        let key = format!("{}{}", prefix, key);
+        match value {
+            Value::Map(entries) if nested == Nested::Inline => {
+                flatten(entries, &format!("{}.", key), nested, fields)
+            }
+            value => fields.push((key, render(value, nested))),
+        }
+    }
+}
+
+fn render(value: Value, nested: Nested) -> String {
+    match value {
+        Value::Text(text) => text,
+        value => build_table(value, nested, false).to_string(),
+    }
+}
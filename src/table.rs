@@ -1,6 +1,6 @@
 //! This module contains a main table representation of this crate [`Table`].
 
-use std::{borrow::Cow, fmt, iter::FromIterator};
+use std::{borrow::Cow, collections::HashMap, fmt, io, iter::FromIterator, ops::Range};
 
 use papergrid::{
     height::HeightEstimator,
@@ -13,9 +13,11 @@ use papergrid::{
     Estimate, Grid, GridConfig,
 };
 
+#[cfg(feature = "csv")]
+use crate::csv::CsvOptions;
 use crate::{
-    builder::Builder, height::get_table_total_height, object::Entity, width::get_table_total_width,
-    Tabled,
+    builder::Builder, height::get_table_total_height, object::Entity, records::TabledRecords,
+    width::get_table_total_width, Tabled, TabledDynamic,
 };
 
 /// A trait which is responsilbe for configuration of a [`Table`].
@@ -90,6 +92,11 @@ impl Table<VecRecords<CellInfo<'static>>> {
     ///
     /// If you use a reference iterator you'd better use [`FromIterator`] instead.
     /// As it has a different lifetime constraints and make less copies therefore.
+    ///
+    /// If a value is expanded into several rows by [`Tabled::rows`]
+    /// the cells which are not in [`Tabled::expanded_columns`] span all rows of the value.
+    ///
+    /// A layout of columns set by [`Tabled::configure`] is applied afterwards.
     pub fn new<I, T>(iter: I) -> Self
     where
         I: IntoIterator<Item = T>,
@@ -103,10 +110,117 @@ impl Table<VecRecords<CellInfo<'static>>> {
         }
 
         let mut records = vec![header];
-        for row in iter.into_iter() {
-            let mut list = vec![CellInfo::default(); T::LENGTH];
-            for (text, cell) in row.fields().into_iter().zip(list.iter_mut()) {
-                CellMut::set(cell, text.into_owned(), &ctrl);
+        let mut expanded = Vec::new();
+        for value in iter.into_iter() {
+            let rows = value.rows();
+            if rows.len() > 1 {
+                expanded.push((records.len(), rows.len()));
+            }
+
+            for row in rows {
+                let mut list = vec![CellInfo::default(); T::LENGTH];
+                for (text, cell) in row.into_iter().zip(list.iter_mut()) {
+                    CellMut::set(cell, text.into_owned(), &ctrl);
+                }
+
+                records.push(list);
+            }
+        }
+
+        let mut table = Builder::custom(VecRecords::from(records)).build();
+        set_expanded_spans(&mut table, &expanded, T::expanded_columns());
+
+        T::configure(&mut table);
+
+        table
+    }
+
+    /// Creates a Table instance from values which columns are known only at runtime.
+    ///
+    /// The columns are a union of headers of all values, in order they were met.
+    /// A header which first appears in a later value is put before the next header
+    /// of that value which is already known, so related columns are kept together.
+    /// A cell which a value doesn't have is left empty.
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use tabled::Table;
+    ///
+    /// let metrics = vec![
+    ///     BTreeMap::from([("cpu", "12%")]),
+    ///     BTreeMap::from([("cpu", "7%"), ("mem", "1.2G")]),
+    /// ];
+    ///
+    /// let table = Table::from_dynamic(metrics).to_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     "+-----+------+\n\
+    ///      | cpu | mem  |\n\
+    ///      +-----+------+\n\
+    ///      | 12% |      |\n\
+    ///      +-----+------+\n\
+    ///      | 7%  | 1.2G |\n\
+    ///      +-----+------+"
+    /// );
+    /// ```
+    pub fn from_dynamic<I, T>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: TabledDynamic,
+    {
+        let ctrl = CfgWidthFunction::new(4);
+
+        // columns are identified by an index in `headers` and `order` keeps their position
+        let mut headers = Vec::new();
+        let mut columns = HashMap::new();
+        let mut order = Vec::new();
+        let mut rows = Vec::new();
+        for value in iter.into_iter() {
+            let fields = value.fields();
+
+            let mut row = Vec::with_capacity(fields.len());
+            for (i, (header, _)) in fields.iter().enumerate() {
+                if let Some(&column) = columns.get(header.as_ref()) {
+                    row.push(column);
+                    continue;
+                }
+
+                let column = headers.len();
+                let next_known = fields[i + 1..]
+                    .iter()
+                    .find_map(|(header, _)| columns.get(header.as_ref()));
+                let position = next_known
+                    .and_then(|next| order.iter().position(|column| column == next))
+                    .unwrap_or(order.len());
+
+                order.insert(position, column);
+                columns.insert(header.to_string(), column);
+                headers.push(header.to_string());
+                row.push(column);
+            }
+
+            let texts = fields.into_iter().map(|(_, text)| text.into_owned());
+            rows.push(row.into_iter().zip(texts).collect::<Vec<_>>());
+        }
+
+        let count_columns = headers.len();
+
+        let mut positions = vec![0; count_columns];
+        for (position, &column) in order.iter().enumerate() {
+            positions[column] = position;
+        }
+
+        let mut header = vec![CellInfo::default(); count_columns];
+        for (column, text) in headers.into_iter().enumerate() {
+            CellMut::set(&mut header[positions[column]], text, &ctrl);
+        }
+
+        let mut records = vec![header];
+        for row in rows {
+            let mut list = vec![CellInfo::default(); count_columns];
+            for (column, text) in row {
+                CellMut::set(&mut list[positions[column]], text, &ctrl);
             }
 
             records.push(list);
@@ -116,6 +230,36 @@ impl Table<VecRecords<CellInfo<'static>>> {
     }
 }
 
+impl<'a, T> Table<TabledRecords<'a, T>>
+where
+    T: Tabled,
+{
+    /// Creates a [`Table`] over a slice of [`Tabled`] values without copying them into cells.
+    ///
+    /// The fields are built on demand when a table is printed, nothing is kept in memory.
+    /// So it may be used to print a big data set.
+    ///
+    /// The records can't be changed, therefore only settings which change a [`GridConfig`] can be applied.
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let data = vec!["Hello", "World"];
+    /// let table = Table::lazy(&data);
+    ///
+    /// assert_eq!(table.to_string(), Table::new(&data).to_string());
+    /// ```
+    pub fn lazy(records: &'a [T]) -> Self {
+        let records = TabledRecords::new(records);
+        let expanded = records.expanded_rows();
+
+        let mut table = Builder::custom(records).build();
+        set_expanded_spans(&mut table, &expanded, T::expanded_columns());
+
+        table
+    }
+}
+
 impl Table<()> {
     /// Creates a builder from a data set given.
     ///
@@ -171,13 +315,15 @@ impl Table<()> {
     {
         let ctrl = CfgWidthFunction::new(4);
         let mut records = Vec::new();
-        for row in iter {
-            let mut list = vec![CellInfo::default(); T::LENGTH];
-            for (text, cell) in row.fields().into_iter().zip(list.iter_mut()) {
-                CellMut::set(cell, text.into_owned(), &ctrl);
+        for value in iter {
+            for row in value.rows() {
+                let mut list = vec![CellInfo::default(); T::LENGTH];
+                for (text, cell) in row.into_iter().zip(list.iter_mut()) {
+                    CellMut::set(cell, text.into_owned(), &ctrl);
+                }
+
+                records.push(list);
             }
-
-            records.push(list);
         }
 
         let mut b = Builder::from(records);
@@ -275,6 +421,120 @@ where
         get_table_total_height(&self.records, &self.cfg, &ctrl)
     }
 
+    /// Prints a table into a given [`fmt::Write`].
+    ///
+    /// The output is the same as [`Table`] produces with `to_string()`,
+    /// but it doesn't allocate an intermediate [`String`].
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new(["Hello", "World"]);
+    ///
+    /// let mut buf = String::new();
+    /// table.fmt_to(&mut buf).unwrap();
+    ///
+    /// assert_eq!(buf, table.to_string());
+    /// ```
+    pub fn fmt_to<W>(&self, f: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        let width = self.get_width_ctrl();
+        let height = self.get_height_ctrl();
+
+        Grid::new(&self.records, &self.cfg, &width, &height).build(f)
+    }
+
+    /// Prints a table into a given [`io::Write`].
+    ///
+    /// It's useful to print a big table straight into a [`std::io::Stdout`] or a [`std::fs::File`].
+    /// Notice that the writer is not flushed,
+    /// so you may want to wrap it into a [`std::io::BufWriter`].
+    ///
+    /// ```
+    /// use tabled::Table;
+    ///
+    /// let table = Table::new(["Hello", "World"]);
+    ///
+    /// let mut buf = Vec::new();
+    /// table.write_to(&mut buf).unwrap();
+    ///
+    /// assert_eq!(buf, table.to_string().as_bytes());
+    /// ```
+    pub fn write_to<W>(&self, out: W) -> io::Result<()>
+    where
+        W: io::Write,
+    {
+        let mut writer = IoWriter::new(out);
+        match self.fmt_to(&mut writer) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(writer.into_error()),
+        }
+    }
+
+    /// Renders a table as a GitHub Flavored Markdown table, where a first row is a header.
+    ///
+    /// Unlike [`Style::markdown`] it puts alignment markers by a horizontal alignment of columns.
+    /// A left alignment is marked only when it's set for a column explicitly.
+    /// Pipes and backslashes in cells are escaped, new lines are replaced by `<br>`
+    /// and ANSI escape sequences are removed.
+    /// Markdown has no spans, so cells covered by a span are left empty.
+    ///
+    /// ```
+    /// use tabled::{object::Columns, Alignment, ModifyObject, Table};
+    ///
+    /// let mut table = Table::new([("a|b", 1), ("line 1\nline 2", 20)]);
+    /// table.with(Columns::single(1).modify().with(Alignment::right()));
+    ///
+    /// assert_eq!(
+    ///     table.to_markdown(),
+    ///     concat!(
+    ///         "| &str             | i32 |\n",
+    ///         "|------------------|----:|\n",
+    ///         "| a\\|b             |   1 |\n",
+    ///         "| line 1<br>line 2 |  20 |",
+    ///     )
+    /// );
+    /// ```
+    ///
+    /// [`Style::markdown`]: crate::Style::markdown
+    pub fn to_markdown(&self) -> String {
+        crate::markdown::to_markdown(self)
+    }
+
+    /// Writes a table into a given [`io::Write`] as CSV.
+    ///
+    /// Cells are read through [`Records`], so it works for any backend.
+    /// A text of spanned cells is written according to [`CsvOptions::spans`].
+    ///
+    /// ```
+    /// use tabled::{
+    ///     csv::{CsvOptions, Quote},
+    ///     Table,
+    /// };
+    ///
+    /// let table = Table::new([("apple", 10), ("orange", 3)]);
+    ///
+    /// let mut buf = Vec::new();
+    /// table
+    ///     .to_csv(&mut buf, CsvOptions::new().quote_style(Quote::NonNumeric))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(buf, b"\"&str\",\"i32\"\n\"apple\",10\n\"orange\",3\n");
+    /// ```
+    ///
+    /// [`Records`]: papergrid::records::Records
+    /// [`CsvOptions::spans`]: crate::csv::CsvOptions::spans
+    #[cfg(feature = "csv")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+    pub fn to_csv<W>(&self, writer: W, options: CsvOptions) -> Result<(), crate::csv::Error>
+    where
+        W: io::Write,
+    {
+        crate::csv::write(self, writer, &options)
+    }
+
     fn get_width_ctrl(&self) -> CachedEstimator<'_, WidthEstimator> {
         match &self.widths {
             Some(widths) => CachedEstimator::Cached(widths),
@@ -296,6 +556,20 @@ where
             }
         }
     }
+
+    pub(crate) fn get_column_widths(&self) -> Vec<usize> {
+        let ctrl = self.get_width_ctrl();
+        (0..self.count_columns())
+            .map(|col| Estimate::<&R>::get(&ctrl, col).unwrap_or(0))
+            .collect()
+    }
+
+    pub(crate) fn get_row_heights(&self) -> Vec<usize> {
+        let ctrl = self.get_height_ctrl();
+        (0..self.count_rows())
+            .map(|row| Estimate::<&R>::get(&ctrl, row).unwrap_or(0))
+            .collect()
+    }
 }
 
 impl<R> Table<R>
@@ -328,7 +602,7 @@ where
 
         let grid = Grid::new(&self.records, &cfg, &width, &height);
 
-        write!(f, "{}", grid)
+        grid.build(f)
     }
 }
 
@@ -362,16 +636,46 @@ where
         }
 
         let mut records = vec![header];
-        for row in iter.into_iter() {
-            let mut list = vec![CellInfo::default(); T::LENGTH];
-            for (text, cell) in row.fields().into_iter().zip(list.iter_mut()) {
-                CellMut::set(cell, text, &ctrl);
+        let mut expanded = Vec::new();
+        for value in iter.into_iter() {
+            let rows = value.rows();
+            if rows.len() > 1 {
+                expanded.push((records.len(), rows.len()));
             }
 
-            records.push(list);
+            for row in rows {
+                let mut list = vec![CellInfo::default(); T::LENGTH];
+                for (text, cell) in row.into_iter().zip(list.iter_mut()) {
+                    CellMut::set(cell, text, &ctrl);
+                }
+
+                records.push(list);
+            }
         }
 
-        Builder::custom(VecRecords::from(records)).build()
+        let mut table = Builder::custom(VecRecords::from(records)).build();
+        set_expanded_spans(&mut table, &expanded, T::expanded_columns());
+
+        T::configure(&mut table);
+
+        table
+    }
+}
+
+/// Makes the cells of the columns which are not expanded span all rows of a value.
+///
+/// The values are given as a list of a first row and a number of rows.
+fn set_expanded_spans<R>(table: &mut Table<R>, values: &[(usize, usize)], expanded: Range<usize>)
+where
+    R: Records,
+{
+    let count_columns = table.shape().1;
+    for &(row, count_rows) in values {
+        for column in (0..count_columns).filter(|column| !expanded.contains(column)) {
+            table
+                .get_config_mut()
+                .set_row_span((row, column), count_rows);
+        }
     }
 }
 
@@ -403,6 +707,35 @@ where
     }
 }
 
+/// An adapter of [`io::Write`] to [`fmt::Write`] which keeps an underlying IO error.
+struct IoWriter<W> {
+    out: W,
+    error: Option<io::Error>,
+}
+
+impl<W> IoWriter<W> {
+    fn new(out: W) -> Self {
+        Self { out, error: None }
+    }
+
+    fn into_error(self) -> io::Error {
+        self.error
+            .unwrap_or_else(|| io::Error::from(io::ErrorKind::Other))
+    }
+}
+
+impl<W> fmt::Write for IoWriter<W>
+where
+    W: io::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.out.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
 fn set_align_table(f: &fmt::Formatter<'_>, cfg: &mut Cow<'_, GridConfig>) {
     if let Some(alignment) = f.align() {
         let alignment = convert_fmt_alignment(alignment);
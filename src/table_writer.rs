@@ -0,0 +1,326 @@
+//! This module contains a [`TableWriter`] which renders a table row by row into an [`io::Write`].
+//!
+//! It's useful when a data set is too big to be collected into a [`Table`]
+//! or when the output must be shown as soon as the data arrives.
+
+use std::io;
+
+use papergrid::{
+    height::HeightEstimator,
+    records::{empty::EmptyRecords, vec_records::VecRecords},
+    util::{cut_str, get_lines},
+    width::{CfgWidthFunction, WidthEstimator, WidthFunc},
+    Border, Entity, Estimate, Grid, GridConfig, VerticalLine,
+};
+
+use crate::{builder::Builder, Table, TableOption};
+
+const DEFAULT_SAMPLE_SIZE: usize = 100;
+
+/// [`TableWriter`] renders a table into an [`io::Write`] as rows are pushed,
+/// without keeping the whole data set in memory.
+///
+/// The top border is printed together with a first row,
+/// each next row is printed together with a split line above it,
+/// and the bottom border is printed on [`TableWriter::finish`].
+///
+/// The column widths can be set explicitly by [`TableWriter::widths`],
+/// otherwise they are calculated from a first N rows (see [`TableWriter::sample`]).
+/// The content which doesn't fit a column is truncated.
+///
+/// All global and column settings, like [`Style`], [`Padding`] and [`Alignment`], are supported.
+///
+/// # Example
+///
+/// ```
+/// use tabled::{TableWriter, Style};
+///
+/// let mut writer = TableWriter::new(Vec::new(), 3);
+/// writer.with(Style::psql()).widths([2, 7, 5]);
+///
+/// writer.push(["id", "name", "value"]).unwrap();
+/// writer.push(["0", "Hello", "1.23"]).unwrap();
+/// writer.push(["1", "World", "3.21"]).unwrap();
+///
+/// let output = writer.finish().unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     concat!(
+///         " id | name    | value \n",
+///         "----+---------+-------\n",
+///         " 0  | Hello   | 1.23  \n",
+///         " 1  | World   | 3.21  ",
+///     )
+/// );
+/// ```
+///
+/// [`Style`]: crate::Style
+/// [`Padding`]: crate::Padding
+/// [`Alignment`]: crate::Alignment
+#[derive(Debug)]
+pub struct TableWriter<W> {
+    out: W,
+    table: Table<EmptyRecords>,
+    widths: Option<Vec<usize>>,
+    sample_size: usize,
+    sample: Vec<Vec<String>>,
+    count_rows: usize,
+}
+
+impl<W> TableWriter<W> {
+    /// Creates a new [`TableWriter`] with a given number of columns.
+    ///
+    /// The default style is the same as [`Table`] has.
+    pub fn new(out: W, count_columns: usize) -> Self {
+        let table = Builder::custom(EmptyRecords::new(2, count_columns)).build();
+
+        Self {
+            out,
+            table,
+            widths: None,
+            sample_size: DEFAULT_SAMPLE_SIZE,
+            sample: Vec::new(),
+            count_rows: 0,
+        }
+    }
+
+    /// With is a generic function which applies options to the [`TableWriter`].
+    ///
+    /// Only options which are not bound to particular rows take an affect.
+    pub fn with<O>(&mut self, mut option: O) -> &mut Self
+    where
+        O: TableOption<EmptyRecords>,
+    {
+        option.change(&mut self.table);
+        self
+    }
+
+    /// Sets a width of the columns content.
+    ///
+    /// A padding is not included in the width.
+    pub fn widths<I>(&mut self, widths: I) -> &mut Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let widths = widths.into_iter().collect();
+        self.widths = Some(widths);
+        self
+    }
+
+    /// Sets a number of rows which are used to calculate the columns widths,
+    /// in case [`TableWriter::widths`] was not set.
+    ///
+    /// The rows are printed only after the sample is collected.
+    pub fn sample(&mut self, count_rows: usize) -> &mut Self {
+        self.sample_size = count_rows;
+        self
+    }
+
+    /// Returns a number of rows which were pushed.
+    pub fn count_rows(&self) -> usize {
+        self.count_rows + self.sample.len()
+    }
+}
+
+impl<W> TableWriter<W>
+where
+    W: io::Write,
+{
+    /// Pushes a row to the table.
+    ///
+    /// The row is extended with empty cells or cut if its length is different from a number of columns.
+    pub fn push<I, T>(&mut self, row: I) -> io::Result<()>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let count_columns = self.table.count_columns();
+        let mut list = Vec::with_capacity(count_columns);
+        for text in row.into_iter().take(count_columns) {
+            list.push(text.as_ref().to_owned());
+        }
+
+        list.resize(count_columns, String::new());
+
+        if self.widths.is_none() {
+            self.sample.push(list);
+            if self.sample.len() >= self.sample_size {
+                self.flush_sample()?;
+            }
+
+            return Ok(());
+        }
+
+        self.write_row(list)
+    }
+
+    /// Prints a bottom border of the table and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.flush_sample()?;
+
+        if self.count_rows == 0 {
+            return Ok(self.out);
+        }
+
+        let row = self.count_rows;
+        let mut cfg = self.create_row_config(row, (row, self.table.count_columns()));
+
+        let mut margin = *cfg.get_margin();
+        margin.top.size = 0;
+        cfg.set_margin(margin);
+
+        let widths = self.get_column_widths();
+
+        if cfg.has_horizontal(0, 1) {
+            self.out.write_all(b"\n")?;
+        }
+
+        let records = EmptyRecords::new(1, self.table.count_columns());
+        let width = WidthEstimator::from(widths);
+        let height = HeightEstimator::from(vec![0]);
+        let grid = Grid::new(&records, &cfg, &width, &height);
+        write!(self.out, "{}", grid)?;
+
+        self.out.flush()?;
+
+        Ok(self.out)
+    }
+
+    fn flush_sample(&mut self) -> io::Result<()> {
+        if self.widths.is_none() {
+            let widths = estimate_widths(&self.sample, self.table.get_config());
+            self.widths = Some(widths);
+        }
+
+        for row in std::mem::take(&mut self.sample) {
+            self.write_row(row)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_row(&mut self, row: Vec<String>) -> io::Result<()> {
+        let count_columns = self.table.count_columns();
+        let shape = (self.count_rows + 2, count_columns);
+        let mut cfg = self.create_row_config(self.count_rows, shape);
+
+        let mut margin = *cfg.get_margin();
+        margin.bottom.size = 0;
+        if self.count_rows > 0 {
+            margin.top.size = 0;
+        }
+        cfg.set_margin(margin);
+
+        let widths = self.get_column_widths();
+        let ctrl = CfgWidthFunction::from_cfg(&cfg);
+        let row = row
+            .iter()
+            .enumerate()
+            .map(|(col, text)| fit_text(text, get_content_width(&cfg, &widths, col), &ctrl))
+            .collect::<Vec<_>>();
+
+        let records = VecRecords::new(vec![row], (1, count_columns), &ctrl);
+        let width = WidthEstimator::from(widths);
+        let mut height = HeightEstimator::default();
+        height.estimate(&records, &cfg);
+
+        if self.count_rows > 0 {
+            self.out.write_all(b"\n")?;
+        }
+
+        let grid = Grid::new(&records, &cfg, &width, &height);
+        write!(self.out, "{}", grid)?;
+
+        self.count_rows += 1;
+
+        Ok(())
+    }
+
+    fn get_column_widths(&self) -> Vec<usize> {
+        let cfg = self.table.get_config();
+        let widths = self.widths.as_deref().unwrap_or_default();
+        (0..self.table.count_columns())
+            .map(|col| {
+                let width = widths.get(col).copied().unwrap_or(0);
+                let padding = cfg.get_padding(Entity::Column(col));
+                width + padding.left.size + padding.right.size
+            })
+            .collect()
+    }
+
+    /// Creates a config for 1 row grid,
+    /// which has the borders of a row at a given index on a table of a given shape.
+    fn create_row_config(&self, row: usize, shape: (usize, usize)) -> GridConfig {
+        let table_cfg = self.table.get_config();
+        let (count_rows, count_columns) = shape;
+
+        let mut cfg = table_cfg.clone();
+        cfg.clear_theme();
+
+        for col in 0..=count_columns {
+            if let Some(&c) = table_cfg.get_vertical((row, col), count_columns) {
+                let line = VerticalLine {
+                    main: Some(c),
+                    ..Default::default()
+                };
+
+                cfg.set_vertical_line(col, line);
+            }
+        }
+
+        if table_cfg.has_horizontal(row, count_rows) {
+            for col in 0..count_columns {
+                let border = Border {
+                    top: table_cfg.get_horizontal((row, col), count_rows).copied(),
+                    left_top_corner: table_cfg.get_intersection((row, col), shape).copied(),
+                    right_top_corner: table_cfg.get_intersection((row, col + 1), shape).copied(),
+                    ..Default::default()
+                };
+
+                cfg.set_border((0, col), border);
+            }
+        }
+
+        cfg
+    }
+}
+
+fn estimate_widths(rows: &[Vec<String>], cfg: &GridConfig) -> Vec<usize> {
+    let count_columns = rows.first().map_or(0, Vec::len);
+    let ctrl = CfgWidthFunction::from_cfg(cfg);
+    let records = VecRecords::new(rows, (rows.len(), count_columns), &ctrl);
+
+    let mut estimator = WidthEstimator::default();
+    estimator.estimate(&records, cfg);
+
+    let widths: Vec<usize> = estimator.into();
+    widths
+        .into_iter()
+        .enumerate()
+        .map(|(col, width)| {
+            let padding = cfg.get_padding(Entity::Column(col));
+            width.saturating_sub(padding.left.size + padding.right.size)
+        })
+        .collect()
+}
+
+fn get_content_width(cfg: &GridConfig, widths: &[usize], col: usize) -> usize {
+    let padding = cfg.get_padding(Entity::Column(col));
+    widths[col].saturating_sub(padding.left.size + padding.right.size)
+}
+
+fn fit_text<W>(text: &str, width: usize, ctrl: W) -> String
+where
+    W: WidthFunc,
+{
+    let is_fit = get_lines(text).all(|line| ctrl.width(&line) <= width);
+    if is_fit {
+        return text.to_owned();
+    }
+
+    get_lines(text)
+        .map(|line| cut_str(&line, width).into_owned())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
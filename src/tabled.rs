@@ -1,4 +1,16 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Display},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    ops::Range,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use papergrid::records::{Records, RecordsMut};
+
+use crate::Table;
 
 /// Tabled a trait responsible for providing a header fields and a row fields.
 ///
@@ -18,6 +30,36 @@ pub trait Tabled {
     fn fields(&self) -> Vec<Cow<'_, str>>;
     /// Headers must return a list of column names.
     fn headers() -> Vec<Cow<'static, str>>;
+
+    /// Rows method returns a list of rows which represent a value.
+    ///
+    /// By default it's a single row of [`Tabled::fields`],
+    /// but a value may be expanded into several rows, like a field with `#[tabled(expand)]`.
+    fn rows(&self) -> Vec<Vec<Cow<'_, str>>> {
+        vec![self.fields()]
+    }
+
+    /// Returns a range of columns which are spread into several rows by [`Tabled::rows`].
+    ///
+    /// The rest of the columns are kept in a single cell which spans all rows of a value.
+    /// By default it's empty.
+    fn expanded_columns() -> Range<usize> {
+        0..0
+    }
+
+    /// Configure method sets a layout of the columns, like an alignment or a width.
+    ///
+    /// It's called by [`Table::new`] and by collecting into a [`Table`] once a table is built.
+    /// [`Table::lazy`] and [`TableWriter`] don't call it, so the layout isn't applied there.
+    /// By default it does nothing, but it may be set by attributes like `#[tabled(align = "right")]`.
+    ///
+    /// [`TableWriter`]: crate::TableWriter
+    fn configure<R>(table: &mut Table<R>)
+    where
+        R: Records + RecordsMut<String>,
+    {
+        let _ = table;
+    }
 }
 
 impl<T> Tabled for &T
@@ -32,6 +74,18 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn rows(&self) -> Vec<Vec<Cow<'_, str>>> {
+        T::rows(self)
+    }
+    fn expanded_columns() -> Range<usize> {
+        T::expanded_columns()
+    }
+    fn configure<R>(table: &mut Table<R>)
+    where
+        R: Records + RecordsMut<String>,
+    {
+        T::configure(table)
+    }
 }
 
 impl<T> Tabled for Box<T>
@@ -46,6 +100,18 @@ where
     fn headers() -> Vec<Cow<'static, str>> {
         T::headers()
     }
+    fn rows(&self) -> Vec<Vec<Cow<'_, str>>> {
+        T::rows(self)
+    }
+    fn expanded_columns() -> Range<usize> {
+        T::expanded_columns()
+    }
+    fn configure<R>(table: &mut Table<R>)
+    where
+        R: Records + RecordsMut<String>,
+    {
+        T::configure(table)
+    }
 }
 
 macro_rules! tuple_table {
@@ -131,6 +197,11 @@ default_table!(i128);
 default_table!(f32);
 default_table!(f64);
 
+default_table!(IpAddr);
+default_table!(Ipv4Addr);
+default_table!(Ipv6Addr);
+default_table!(SocketAddr);
+
 impl<T, const N: usize> Tabled for [T; N]
 where
     T: std::fmt::Display,
@@ -148,3 +219,398 @@ where
         (0..N).map(|i| Cow::Owned(format!("{}", i))).collect()
     }
 }
+
+impl<T> Tabled for Option<T>
+where
+    T: Tabled,
+{
+    const LENGTH: usize = T::LENGTH;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        match self {
+            Some(value) => value.fields(),
+            None => vec![Cow::Borrowed(""); T::LENGTH],
+        }
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        T::headers()
+    }
+
+    fn rows(&self) -> Vec<Vec<Cow<'_, str>>> {
+        match self {
+            Some(value) => value.rows(),
+            None => vec![vec![Cow::Borrowed(""); T::LENGTH]],
+        }
+    }
+
+    fn expanded_columns() -> Range<usize> {
+        T::expanded_columns()
+    }
+
+    fn configure<R>(table: &mut Table<R>)
+    where
+        R: Records + RecordsMut<String>,
+    {
+        T::configure(table)
+    }
+}
+
+impl<T, E> Tabled for Result<T, E>
+where
+    T: Tabled,
+    E: Display,
+{
+    const LENGTH: usize = T::LENGTH + 1;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        match self {
+            Ok(value) => {
+                let mut fields = value.fields();
+                fields.push(Cow::Borrowed(""));
+                fields
+            }
+            Err(err) => {
+                let mut fields = vec![Cow::Borrowed(""); T::LENGTH];
+                fields.push(Cow::Owned(err.to_string()));
+                fields
+            }
+        }
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        let mut headers = T::headers();
+        headers.push(Cow::Borrowed("error"));
+        headers
+    }
+
+    fn rows(&self) -> Vec<Vec<Cow<'_, str>>> {
+        match self {
+            Ok(value) => value
+                .rows()
+                .into_iter()
+                .map(|mut row| {
+                    row.push(Cow::Borrowed(""));
+                    row
+                })
+                .collect(),
+            Err(_) => vec![self.fields()],
+        }
+    }
+
+    fn expanded_columns() -> Range<usize> {
+        T::expanded_columns()
+    }
+
+    fn configure<R>(table: &mut Table<R>)
+    where
+        R: Records + RecordsMut<String>,
+    {
+        T::configure(table)
+    }
+}
+
+impl Tabled for PathBuf {
+    const LENGTH: usize = 1;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        vec![self.to_string_lossy()]
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        vec![Cow::Borrowed("PathBuf")]
+    }
+}
+
+impl Tabled for Path {
+    const LENGTH: usize = 1;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        vec![self.to_string_lossy()]
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        vec![Cow::Borrowed("Path")]
+    }
+}
+
+/// A [`Duration`] is printed in a [`Debug`] format, like `1.5s`.
+///
+/// Use [`HumanDuration`] to get a more readable output.
+///
+/// [`Debug`]: std::fmt::Debug
+impl Tabled for Duration {
+    const LENGTH: usize = 1;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        vec![Cow::Owned(format!("{:?}", self))]
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        vec![Cow::Borrowed("Duration")]
+    }
+}
+
+/// A [`SystemTime`] is printed as a UTC date in RFC 3339 format, like `2022-10-05T12:00:00Z`.
+impl Tabled for SystemTime {
+    const LENGTH: usize = 1;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        vec![Cow::Owned(format_system_time(self))]
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        vec![Cow::Borrowed("SystemTime")]
+    }
+}
+
+/// HumanDuration is a wrapper of a [`Duration`] which is printed in a human readable format,
+/// like `1h 2m 30s`.
+///
+/// ```
+/// use std::time::Duration;
+/// use tabled::HumanDuration;
+///
+/// assert_eq!(HumanDuration(Duration::from_secs(3750)).to_string(), "1h 2m 30s");
+/// assert_eq!(HumanDuration(Duration::from_millis(1500)).to_string(), "1s 500ms");
+/// assert_eq!(HumanDuration(Duration::from_micros(15)).to_string(), "15µs");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanDuration(pub Duration);
+
+impl From<Duration> for HumanDuration {
+    fn from(duration: Duration) -> Self {
+        Self(duration)
+    }
+}
+
+impl Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.0.as_secs();
+        let units = [
+            (secs / 86400, "d"),
+            (secs / 3600 % 24, "h"),
+            (secs / 60 % 60, "m"),
+            (secs % 60, "s"),
+            (u64::from(self.0.subsec_millis()), "ms"),
+        ];
+
+        let mut is_empty = true;
+        for (value, unit) in units.iter().filter(|(value, _)| *value > 0) {
+            if !is_empty {
+                f.write_str(" ")?;
+            }
+
+            write!(f, "{}{}", value, unit)?;
+            is_empty = false;
+        }
+
+        if is_empty {
+            let nanos = self.0.subsec_nanos();
+            if nanos >= 1000 {
+                write!(f, "{}µs", nanos / 1000)?;
+            } else {
+                write!(f, "{}ns", nanos)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Tabled for HumanDuration {
+    const LENGTH: usize = 1;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        vec![Cow::Owned(self.to_string())]
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        vec![Cow::Borrowed("Duration")]
+    }
+}
+
+/// KeyValue is a pair of a key and a value which is printed in `key` and `value` columns.
+///
+/// It's handy to print a map, as only [`Display`] is required from its keys and values.
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use tabled::{KeyValue, Style, Table};
+///
+/// let map = BTreeMap::from([("alpha", 1), ("beta", 2)]);
+///
+/// let table = Table::new(map.iter().map(KeyValue::from))
+///     .with(Style::psql())
+///     .to_string();
+///
+/// assert_eq!(
+///     table,
+///     concat!(
+///         " key   | value \n",
+///         "-------+-------\n",
+///         " alpha | 1     \n",
+///         " beta  | 2     ",
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeyValue<K, V>(pub K, pub V);
+
+impl<K, V> From<(K, V)> for KeyValue<K, V> {
+    fn from((key, value): (K, V)) -> Self {
+        Self(key, value)
+    }
+}
+
+impl<K, V> Tabled for KeyValue<K, V>
+where
+    K: Display,
+    V: Display,
+{
+    const LENGTH: usize = 2;
+
+    fn fields(&self) -> Vec<Cow<'_, str>> {
+        vec![
+            Cow::Owned(self.0.to_string()),
+            Cow::Owned(self.1.to_string()),
+        ]
+    }
+
+    fn headers() -> Vec<Cow<'static, str>> {
+        vec![Cow::Borrowed("key"), Cow::Borrowed("value")]
+    }
+}
+
+fn format_system_time(time: &SystemTime) -> String {
+    let (secs, nanos) = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => (duration.as_secs() as i64, duration.subsec_nanos()),
+        Err(err) => {
+            let duration = err.duration();
+            let mut secs = -(duration.as_secs() as i64);
+            let mut nanos = duration.subsec_nanos();
+            if nanos > 0 {
+                secs -= 1;
+                nanos = 1_000_000_000 - nanos;
+            }
+
+            (secs, nanos)
+        }
+    };
+
+    let days = secs.div_euclid(86400);
+    let day_secs = secs.rem_euclid(86400);
+    let (year, month, day) = civil_from_days(days);
+
+    let mut text = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        day_secs / 3600,
+        day_secs / 60 % 60,
+        day_secs % 60
+    );
+
+    if nanos > 0 {
+        let fraction = format!("{:09}", nanos);
+        text.push('.');
+        text.push_str(fraction.trim_end_matches('0'));
+    }
+
+    text.push('Z');
+    text
+}
+
+/// Converts a number of days since 1970-01-01 into a (year, month, day) date.
+///
+/// It's an algorithm by Howard Hinnant <http://howardhinnant.github.io/date_algorithms.html>.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// TabledDynamic a trait for types which columns are known only at runtime, like maps.
+///
+/// Unlike [`Tabled`] a list of columns is built from a whole collection,
+/// as a union of all headers, by [`Table::from_dynamic`].
+///
+/// ```
+/// use std::collections::BTreeMap;
+/// use tabled::Table;
+///
+/// let mut metrics = BTreeMap::new();
+/// metrics.insert("cpu", "12%");
+/// metrics.insert("mem", "1.2G");
+///
+/// let table = Table::from_dynamic([metrics]).to_string();
+///
+/// assert_eq!(
+///     table,
+///     "+-----+------+\n\
+///      | cpu | mem  |\n\
+///      +-----+------+\n\
+///      | 12% | 1.2G |\n\
+///      +-----+------+"
+/// );
+/// ```
+pub trait TabledDynamic {
+    /// Fields method must return a list of pairs of a header and a cell.
+    fn fields(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)>;
+}
+
+impl<T> TabledDynamic for &T
+where
+    T: TabledDynamic,
+{
+    fn fields(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        T::fields(self)
+    }
+}
+
+impl<T> TabledDynamic for Box<T>
+where
+    T: TabledDynamic,
+{
+    fn fields(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        T::fields(self)
+    }
+}
+
+/// As an order of a [`HashMap`] is arbitrary the fields are sorted by their headers.
+impl<K, V, S> TabledDynamic for HashMap<K, V, S>
+where
+    K: Display,
+    V: Display,
+{
+    fn fields(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        let mut fields = self
+            .iter()
+            .map(|(key, value)| (Cow::Owned(key.to_string()), Cow::Owned(value.to_string())))
+            .collect::<Vec<(Cow<'_, str>, Cow<'_, str>)>>();
+        fields.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+        fields
+    }
+}
+
+impl<K, V> TabledDynamic for BTreeMap<K, V>
+where
+    K: Display,
+    V: Display,
+{
+    fn fields(&self) -> Vec<(Cow<'_, str>, Cow<'_, str>)> {
+        self.iter()
+            .map(|(key, value)| (Cow::Owned(key.to_string()), Cow::Owned(value.to_string())))
+            .collect()
+    }
+}
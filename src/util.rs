@@ -0,0 +1,115 @@
+//! This module contains helpers shared by different parts of the crate.
+
+use papergrid::{Entity, GridConfig};
+
+/// Removes ANSI escape sequences from a text.
+pub(crate) fn strip_ansi(text: &str) -> String {
+    if !text.contains('\u{1b}') {
+        return text.to_owned();
+    }
+
+    let mut buf = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            buf.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            // CSI sequence ends with a byte in `@`..=`~` range
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC sequence, like a hyperlink, ends with BEL or ST
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\u{7}' {
+                        break;
+                    }
+
+                    if c == '\u{1b}' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    buf
+}
+
+/// Parses a number from a text, ignoring `,` and `_` separators.
+///
+/// Not finite values like `NaN` or `inf` are not considered numbers.
+pub(crate) fn parse_number(text: &str) -> Option<f64> {
+    let text = text
+        .trim()
+        .chars()
+        .filter(|&c| c != ',' && c != '_')
+        .collect::<String>();
+
+    text.parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+/// Applies a permutation via swaps so that an element `order[i]` ends up on `i` position.
+///
+/// The `swap` callback is expected to exchange 2 elements by their current positions.
+pub(crate) fn permute<F>(order: &[usize], mut swap: F)
+where
+    F: FnMut(usize, usize),
+{
+    // an element which is currently on a position and a position of an element
+    let mut elements = (0..order.len()).collect::<Vec<_>>();
+    let mut positions = elements.clone();
+
+    for (i, &element) in order.iter().enumerate() {
+        let pos = positions[element];
+        if pos == i {
+            continue;
+        }
+
+        swap(i, pos);
+
+        let moved = elements[i];
+        elements.swap(i, pos);
+        positions[moved] = pos;
+        positions[element] = i;
+    }
+}
+
+/// Copies padding, alignment and formatting settings of an entity from one config to an entity of another.
+///
+/// A setting is changed only when it differs, so no redundant entries are created.
+pub(crate) fn copy_cell_settings(
+    cfg: &mut GridConfig,
+    origin: &GridConfig,
+    from: Entity,
+    to: Entity,
+) {
+    let padding = *origin.get_padding(from);
+    if *cfg.get_padding(to) != padding {
+        cfg.set_padding(to, padding);
+    }
+
+    let alignment = *origin.get_alignment_horizontal(from);
+    if *cfg.get_alignment_horizontal(to) != alignment {
+        cfg.set_alignment_horizontal(to, alignment);
+    }
+
+    let alignment = *origin.get_alignment_vertical(from);
+    if *cfg.get_alignment_vertical(to) != alignment {
+        cfg.set_alignment_vertical(to, alignment);
+    }
+
+    let formatting = *origin.get_formatting(from);
+    if *cfg.get_formatting(to) != formatting {
+        cfg.set_formatting(to, formatting);
+    }
+}
@@ -493,6 +493,16 @@ impl Element for &str {
     }
 }
 
+impl Element for Cow<'_, str> {
+    fn display(&self, ctx: &mut Context<'_, '_>) -> fmt::Result {
+        self.as_ref().display(ctx)
+    }
+
+    fn is_empty(&self) -> bool {
+        str::is_empty(self)
+    }
+}
+
 fn block<F>(f: F) -> Block<F>
 where
     F: IntoIterator + Clone,
@@ -0,0 +1,38 @@
+//! The example can be run by this command
+//! `cargo run --example basic`
+
+use table_to_latex::{LatexStyle, LatexTable};
+use tabled::{object::Columns, Alignment, ModifyObject, Panel, Style, Table, Tabled};
+
+#[derive(Debug, Tabled)]
+struct Benchmark {
+    name: &'static str,
+    time_ns: u64,
+    throughput: &'static str,
+}
+
+fn main() {
+    let data = [
+        Benchmark {
+            name: "build",
+            time_ns: 1_210,
+            throughput: "826 MiB/s",
+        },
+        Benchmark {
+            name: "render",
+            time_ns: 3_902,
+            throughput: "256 MiB/s",
+        },
+    ];
+
+    let mut table = Table::new(data);
+    table
+        .with(Style::psql())
+        .with(Columns::new(1..).modify().with(Alignment::right()))
+        .with(Panel::header("Benchmarks"));
+
+    let mut latex_table = LatexTable::from(table);
+    latex_table.set_style(LatexStyle::Booktabs);
+
+    println!("{}", latex_table);
+}
@@ -0,0 +1,548 @@
+#![warn(
+    rust_2018_idioms,
+    rust_2018_compatibility,
+    rust_2021_compatibility,
+    missing_debug_implementations,
+    unreachable_pub,
+    missing_docs
+)]
+#![deny(unused_must_use)]
+
+//! The library provides a interface to build a LaTeX table (`tabular`) from a [`Table`].
+//!
+//! Columns get their specification from a horizontal alignment of a column,
+//! and vertical and horizontal lines of a [`Style`] become `|` and `\hline`.
+//! A [`LatexStyle::Booktabs`] can be used to get `\toprule`, `\midrule` and `\bottomrule` instead.
+//!
+//! Spans are converted into `\multicolumn` and `\multirow`,
+//! so a `multirow` package must be used in case there are row spans.
+//!
+//! # Example
+//!
+//! ```rust
+//! use table_to_latex::LatexTable;
+//! use tabled::{object::Columns, Alignment, ModifyObject, Style, Table};
+//!
+//! let data = [("Debian", 32.5), ("Arch", 9.75), ("Manjaro", 100.0)];
+//!
+//! let mut table = Table::new(data);
+//! table
+//!     .with(Style::psql())
+//!     .with(Columns::single(1).modify().with(Alignment::right()));
+//!
+//! let latex_table = LatexTable::from(table);
+//!
+//! assert_eq!(
+//!     latex_table.to_string(),
+//!     concat!(
+//!         "\\begin{tabular}{l|r}\n",
+//!         "    \\&str & f64 \\\\\n",
+//!         "    \\hline\n",
+//!         "    Debian & 32.5 \\\\\n",
+//!         "    Arch & 9.75 \\\\\n",
+//!         "    Manjaro & 100 \\\\\n",
+//!         "\\end{tabular}",
+//!     )
+//! );
+//! ```
+//!
+//! [`Style`]: tabled::Style
+
+use std::fmt::{self, Display};
+
+use tabled::{
+    object::Entity,
+    papergrid::{records::Records, AlignmentHorizontal, GridConfig},
+    Table,
+};
+
+/// The structure represents a LaTeX `tabular`.
+///
+/// You can create it using [From] [Table].
+#[derive(Debug, Clone)]
+pub struct LatexTable<T = Table> {
+    style: LatexStyle,
+    table: T,
+}
+
+impl<T> LatexTable<T> {
+    /// Set a kind of lines which are used.
+    ///
+    /// Default value is [`LatexStyle::Tabular`].
+    pub fn set_style(&mut self, style: LatexStyle) {
+        self.style = style;
+    }
+}
+
+impl<R> From<Table<R>> for LatexTable<Table<R>> {
+    fn from(table: Table<R>) -> Self {
+        Self {
+            table,
+            style: LatexStyle::Tabular,
+        }
+    }
+}
+
+impl<R> Display for LatexTable<Table<R>>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        convert_to_latex_table(f, &self.table, self.style)
+    }
+}
+
+/// LatexStyle represents a kind of lines used in a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LatexStyle {
+    /// Horizontal lines are `\hline` and vertical lines are `|`.
+    Tabular,
+    /// Horizontal lines are `\toprule`, `\midrule` and `\bottomrule` of a `booktabs` package.
+    ///
+    /// Vertical lines are not used as they're not supposed to be used with `booktabs`.
+    Booktabs,
+}
+
+fn convert_to_latex_table<R>(
+    f: &mut fmt::Formatter<'_>,
+    table: &Table<R>,
+    style: LatexStyle,
+) -> fmt::Result
+where
+    R: Records,
+{
+    let ctx = Context::new(table, style);
+
+    let mut spec = String::new();
+    for col in 0..ctx.count_columns {
+        if ctx.has_vertical(col) {
+            spec.push('|');
+        }
+
+        spec.push(alignment_spec(ctx.column_alignment(col)));
+    }
+
+    if ctx.has_vertical(ctx.count_columns) {
+        spec.push('|');
+    }
+
+    writeln!(f, "\\begin{{tabular}}{{{}}}", spec)?;
+
+    for row in 0..ctx.count_rows {
+        write_rule(f, &ctx, row)?;
+
+        let cells = make_row(&ctx, table.get_records(), row);
+        writeln!(f, "    {} \\\\", cells.join(" & "))?;
+    }
+
+    write_rule(f, &ctx, ctx.count_rows)?;
+
+    f.write_str("\\end{tabular}")
+}
+
+fn make_row<R>(ctx: &Context<'_>, records: &R, row: usize) -> Vec<String>
+where
+    R: Records,
+{
+    let mut cells = Vec::new();
+    let mut col = 0;
+    while col < ctx.count_columns {
+        match ctx.origins[row][col] {
+            // a cell is hidden by a column span
+            Some((origin_row, _)) if origin_row == row => {
+                col += 1;
+            }
+            // a cell is hidden by a row span, it still needs to take place
+            Some(origin) => {
+                let span = ctx.column_span(origin);
+                let cell = if span > 1 {
+                    let alignment = ctx.cell_alignment(origin);
+                    multicolumn(ctx, col, span, alignment, "")
+                } else {
+                    String::new()
+                };
+
+                cells.push(cell);
+                col += span;
+            }
+            None => {
+                let pos = (row, col);
+                let column_span = ctx.column_span(pos);
+                let row_span = ctx.row_span(pos);
+                let alignment = ctx.cell_alignment(pos);
+
+                let mut text = format_text(&records.get_text(pos), alignment);
+                if row_span > 1 {
+                    text = format!("\\multirow{{{}}}{{*}}{{{}}}", row_span, text);
+                }
+
+                if column_span > 1 || alignment != ctx.column_alignment(col) {
+                    text = multicolumn(ctx, col, column_span, alignment, &text);
+                }
+
+                cells.push(text);
+                col += column_span;
+            }
+        }
+    }
+
+    cells
+}
+
+fn multicolumn(
+    ctx: &Context<'_>,
+    col: usize,
+    span: usize,
+    alignment: AlignmentHorizontal,
+    text: &str,
+) -> String {
+    let mut spec = String::new();
+    if col == 0 && ctx.has_vertical(0) {
+        spec.push('|');
+    }
+
+    spec.push(alignment_spec(alignment));
+
+    if ctx.has_vertical(col + span) {
+        spec.push('|');
+    }
+
+    format!("\\multicolumn{{{}}}{{{}}}{{{}}}", span, spec, text)
+}
+
+/// Writes a horizontal line which goes before a given row.
+///
+/// A line is partial in case some cells are spanned over it.
+fn write_rule(f: &mut fmt::Formatter<'_>, ctx: &Context<'_>, row: usize) -> fmt::Result {
+    if !ctx.has_horizontal(row) {
+        return Ok(());
+    }
+
+    let is_crossed = |col: usize| {
+        row < ctx.count_rows && matches!(ctx.origins[row][col], Some((r, _)) if r < row)
+    };
+
+    if !(0..ctx.count_columns).any(is_crossed) {
+        let rule = match ctx.style {
+            LatexStyle::Tabular => "\\hline",
+            LatexStyle::Booktabs if row == 0 => "\\toprule",
+            LatexStyle::Booktabs if row == ctx.count_rows => "\\bottomrule",
+            LatexStyle::Booktabs => "\\midrule",
+        };
+
+        return writeln!(f, "    {}", rule);
+    }
+
+    let rule = match ctx.style {
+        LatexStyle::Tabular => "\\cline",
+        LatexStyle::Booktabs => "\\cmidrule",
+    };
+
+    let mut rules = Vec::new();
+    let mut start = None;
+    for col in 0..=ctx.count_columns {
+        let is_line = col < ctx.count_columns && !is_crossed(col);
+        match (is_line, start) {
+            (true, None) => start = Some(col),
+            (false, Some(first)) => {
+                rules.push(format!("{}{{{}-{}}}", rule, first + 1, col));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if rules.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(f, "    {}", rules.join(" "))
+}
+
+struct Context<'a> {
+    cfg: &'a GridConfig,
+    style: LatexStyle,
+    count_rows: usize,
+    count_columns: usize,
+    /// A position of a spanned cell for each cell which is hidden by it.
+    origins: Vec<Vec<Option<(usize, usize)>>>,
+}
+
+impl<'a> Context<'a> {
+    fn new<R>(table: &'a Table<R>, style: LatexStyle) -> Self
+    where
+        R: Records,
+    {
+        let shape = table.shape();
+        let (count_rows, count_columns) = shape;
+        let cfg = table.get_config();
+
+        // there's nothing to render without columns
+        let count_rows = if count_columns == 0 { 0 } else { count_rows };
+
+        let mut origins = vec![vec![None; count_columns]; count_rows];
+        let spans = cfg
+            .iter_column_spans(shape)
+            .chain(cfg.iter_row_spans(shape))
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+        for (row, col) in spans {
+            let column_span = cfg.get_column_span((row, col), shape).unwrap_or(1);
+            let row_span = cfg.get_row_span((row, col), shape).unwrap_or(1);
+
+            let rows = origins.iter_mut().enumerate().skip(row).take(row_span);
+            for (r, cells) in rows {
+                let cells = cells.iter_mut().enumerate().skip(col).take(column_span);
+                for (c, origin) in cells {
+                    if (r, c) != (row, col) {
+                        *origin = Some((row, col));
+                    }
+                }
+            }
+        }
+
+        Self {
+            cfg,
+            style,
+            count_rows,
+            count_columns,
+            origins,
+        }
+    }
+
+    /// Checks whether a vertical line is drawn, as a line of spaces is not considered to be a line.
+    fn has_vertical(&self, col: usize) -> bool {
+        self.style == LatexStyle::Tabular
+            && (0..self.count_rows).any(|row| {
+                let c = self.cfg.get_vertical((row, col), self.count_columns);
+                matches!(c, Some(&c) if c != ' ')
+            })
+    }
+
+    /// Checks whether a horizontal line is drawn, as a line of spaces is not considered to be a line.
+    fn has_horizontal(&self, row: usize) -> bool {
+        (0..self.count_columns).any(|col| {
+            let c = self.cfg.get_horizontal((row, col), self.count_rows);
+            matches!(c, Some(&c) if c != ' ')
+        })
+    }
+
+    fn column_alignment(&self, col: usize) -> AlignmentHorizontal {
+        *self.cfg.get_alignment_horizontal(Entity::Column(col))
+    }
+
+    fn cell_alignment(&self, pos: (usize, usize)) -> AlignmentHorizontal {
+        *self
+            .cfg
+            .get_alignment_horizontal(Entity::Cell(pos.0, pos.1))
+    }
+
+    fn column_span(&self, pos: (usize, usize)) -> usize {
+        let shape = (self.count_rows, self.count_columns);
+        self.cfg.get_column_span(pos, shape).unwrap_or(1)
+    }
+
+    fn row_span(&self, pos: (usize, usize)) -> usize {
+        let shape = (self.count_rows, self.count_columns);
+        self.cfg.get_row_span(pos, shape).unwrap_or(1)
+    }
+}
+
+fn alignment_spec(alignment: AlignmentHorizontal) -> char {
+    match alignment {
+        AlignmentHorizontal::Left => 'l',
+        AlignmentHorizontal::Center => 'c',
+        AlignmentHorizontal::Right => 'r',
+    }
+}
+
+/// Escapes a text, a multiline text is put into a `\shortstack`.
+fn format_text(text: &str, alignment: AlignmentHorizontal) -> String {
+    let lines = text.lines().map(escape).collect::<Vec<_>>();
+    if lines.len() > 1 {
+        let alignment = alignment_spec(alignment);
+        return format!("\\shortstack[{}]{{{}}}", alignment, lines.join(" \\\\ "));
+    }
+
+    lines.into_iter().next().unwrap_or_default()
+}
+
+fn escape(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            '\\' => buf.push_str("\\textbackslash{}"),
+            '~' => buf.push_str("\\textasciitilde{}"),
+            '^' => buf.push_str("\\textasciicircum{}"),
+            _ => buf.push(c),
+        }
+    }
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use tabled::{
+        object::{Cell, Columns, Rows},
+        Alignment, Modify, ModifyObject, Panel, Span, Style,
+    };
+
+    use super::*;
+
+    #[test]
+    fn basic() {
+        let table = Table::new([["123", "324", "zxc"], ["123", "324", "zxc"]]);
+        let table = LatexTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            "\\begin{tabular}{|l|l|l|}\n    \\hline\n    0 & 1 & 2 \\\\\n    \\hline\n    123 & 324 & zxc \\\\\n    \\hline\n    123 & 324 & zxc \\\\\n    \\hline\n\\end{tabular}"
+        );
+    }
+
+    #[test]
+    fn booktabs() {
+        let mut table = Table::new([["123", "324"], ["123", "324"]]);
+        table.with(Style::modern());
+
+        let mut table = LatexTable::from(table);
+        table.set_style(LatexStyle::Booktabs);
+
+        let table = table.to_string();
+
+        assert_eq!(
+            table,
+            "\\begin{tabular}{ll}\n    \\toprule\n    0 & 1 \\\\\n    \\midrule\n    123 & 324 \\\\\n    \\midrule\n    123 & 324 \\\\\n    \\bottomrule\n\\end{tabular}"
+        );
+    }
+
+    #[test]
+    fn booktabs_header_only() {
+        let mut table = Table::new([["123", "324"], ["123", "324"]]);
+        table.with(Style::psql());
+
+        let mut table = LatexTable::from(table);
+        table.set_style(LatexStyle::Booktabs);
+
+        let table = table.to_string();
+
+        assert_eq!(
+            table,
+            "\\begin{tabular}{ll}\n    0 & 1 \\\\\n    \\midrule\n    123 & 324 \\\\\n    123 & 324 \\\\\n\\end{tabular}"
+        );
+    }
+
+    #[test]
+    fn no_lines() {
+        let mut table = Table::new([["123", "324"]]);
+        table.with(Style::blank());
+
+        let table = LatexTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            "\\begin{tabular}{ll}\n    0 & 1 \\\\\n    123 & 324 \\\\\n\\end{tabular}"
+        );
+    }
+
+    #[test]
+    fn alignment() {
+        let mut table = Table::new([["123", "324", "zxc"]]);
+        table
+            .with(Style::markdown())
+            .with(Columns::single(1).modify().with(Alignment::center()))
+            .with(Columns::single(2).modify().with(Alignment::right()))
+            .with(Rows::first().modify().with(Alignment::center()));
+
+        let table = LatexTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            "\\begin{tabular}{|l|c|r|}\n    \\multicolumn{1}{|c|}{0} & 1 & \\multicolumn{1}{c|}{2} \\\\\n    \\hline\n    123 & 324 & zxc \\\\\n\\end{tabular}"
+        );
+    }
+
+    #[test]
+    fn escape_special_characters() {
+        let table = Table::new([["50% & $5", "a_b#{c}", "~^\\"]]);
+        let table = LatexTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            "\\begin{tabular}{|l|l|l|}\n    \\hline\n    0 & 1 & 2 \\\\\n    \\hline\n    50\\% \\& \\$5 & a\\_b\\#\\{c\\} & \\textasciitilde{}\\textasciicircum{}\\textbackslash{} \\\\\n    \\hline\n\\end{tabular}"
+        );
+    }
+
+    #[test]
+    fn multiline() {
+        let mut table = Table::new([["1\n2\n3", "324"]]);
+        table.with(Style::blank());
+
+        let table = LatexTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            "\\begin{tabular}{ll}\n    0 & 1 \\\\\n    \\shortstack[l]{1 \\\\ 2 \\\\ 3} & 324 \\\\\n\\end{tabular}"
+        );
+    }
+
+    #[test]
+    fn col_span() {
+        let mut table = Table::new([["123", "324", "zxc"], ["123", "324", "zxc"]]);
+        table.with(Panel::header("Hello World!"));
+
+        let table = LatexTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            "\\begin{tabular}{|l|l|l|}\n    \\hline\n    \\multicolumn{3}{|l|}{Hello World!} \\\\\n    \\hline\n    0 & 1 & 2 \\\\\n    \\hline\n    123 & 324 & zxc \\\\\n    \\hline\n    123 & 324 & zxc \\\\\n    \\hline\n\\end{tabular}"
+        );
+    }
+
+    #[test]
+    fn row_span() {
+        let mut table = Table::new([["123", "324", "zxc"], ["123", "324", "zxc"]]);
+        table.with(Modify::new(Cell(1, 1)).with(Span::row(2)));
+
+        let table = LatexTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            "\\begin{tabular}{|l|l|l|}\n    \\hline\n    0 & 1 & 2 \\\\\n    \\hline\n    123 & \\multirow{2}{*}{324} & zxc \\\\\n    \\cline{1-1} \\cline{3-3}\n    123 &  & zxc \\\\\n    \\hline\n\\end{tabular}"
+        );
+    }
+
+    #[test]
+    fn row_and_col_span() {
+        let mut table = Table::new([["123", "324", "zxc"], ["123", "324", "zxc"]]);
+        table
+            .with(
+                Modify::new(Cell(1, 0))
+                    .with(Span::row(2))
+                    .with(Span::column(2)),
+            )
+            .with(Style::psql());
+
+        let mut table = LatexTable::from(table);
+        table.set_style(LatexStyle::Booktabs);
+
+        let table = table.to_string();
+
+        assert_eq!(
+            table,
+            "\\begin{tabular}{lll}\n    0 & 1 & 2 \\\\\n    \\midrule\n    \\multicolumn{2}{l}{\\multirow{2}{*}{123}} & zxc \\\\\n    \\multicolumn{2}{l}{} & zxc \\\\\n\\end{tabular}"
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let table = LatexTable::from(Table::new([[""; 0]; 0])).to_string();
+
+        assert_eq!(table, "\\begin{tabular}{}\n\\end{tabular}");
+    }
+}
@@ -0,0 +1,41 @@
+//! The example can be run by this command
+//! `cargo run --example basic > table.svg`
+
+use table_to_svg::SvgTable;
+use tabled::{object::Columns, Alignment, ModifyObject, Style, Table, Tabled};
+
+#[derive(Debug, Tabled)]
+struct Distribution {
+    name: &'static str,
+    based_on: &'static str,
+    is_active: bool,
+}
+
+fn main() {
+    let data = [
+        Distribution {
+            name: "Debian",
+            based_on: "",
+            is_active: true,
+        },
+        Distribution {
+            name: "Arch",
+            based_on: "",
+            is_active: true,
+        },
+        Distribution {
+            name: "Manjaro",
+            based_on: "Arch",
+            is_active: true,
+        },
+    ];
+
+    let mut table = Table::new(data);
+    table
+        .with(Style::modern())
+        .with(Columns::single(2).modify().with(Alignment::center()));
+
+    let svg_table = SvgTable::from(table);
+
+    println!("{}", svg_table);
+}
@@ -0,0 +1,1002 @@
+#![warn(
+    rust_2018_idioms,
+    rust_2018_compatibility,
+    rust_2021_compatibility,
+    missing_debug_implementations,
+    unreachable_pub,
+    missing_docs
+)]
+#![deny(unused_must_use)]
+
+//! The library provides a interface to build a SVG image from a [`Table`].
+//!
+//! A table is laid out on a monospace grid the same way it's printed in a terminal.
+//! Cells become `<text>` elements and borders become `<line>` elements.
+//!
+//! ANSI colors of a text are converted into a `fill` color.
+//! With a `color` feature colors of borders are converted into a `stroke` color.
+//!
+//! # Example
+//!
+//! ```rust
+//! use table_to_svg::SvgTable;
+//! use tabled::{Style, Table};
+//!
+//! let mut table = Table::new(["Debian", "Arch"]);
+//! table.with(Style::psql());
+//!
+//! let svg_table = SvgTable::from(table);
+//!
+//! assert_eq!(
+//!     svg_table.to_string(),
+//!     concat!(
+//!         "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"80\" height=\"80\" viewBox=\"0 0 80 80\" font-family=\"monospace\" font-size=\"16\">\n",
+//!         "    <g stroke=\"black\">\n",
+//!         "        <line x1=\"0\" y1=\"30\" x2=\"80\" y2=\"30\" />\n",
+//!         "    </g>\n",
+//!         "    <g fill=\"black\" xml:space=\"preserve\">\n",
+//!         "        <text x=\"10\" y=\"15\">&amp;str</text>\n",
+//!         "        <text x=\"10\" y=\"55\">Debian</text>\n",
+//!         "        <text x=\"10\" y=\"75\">Arch</text>\n",
+//!         "    </g>\n",
+//!         "</svg>",
+//!     )
+//! );
+//! ```
+
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+use tabled::{
+    object::Entity,
+    papergrid::{
+        height::HeightEstimator,
+        records::Records,
+        util::string_width,
+        width::{WidthEstimator, WidthFunc},
+        AlignmentHorizontal, AlignmentVertical, Estimate, GridConfig, Position,
+    },
+    Table,
+};
+
+/// The structure represents a SVG image of a table.
+///
+/// You can create it using [From] [Table].
+#[derive(Debug, Clone)]
+pub struct SvgTable<T = Table> {
+    char_width: usize,
+    char_height: usize,
+    font_family: String,
+    color: String,
+    table: T,
+}
+
+impl<T> SvgTable<T> {
+    /// Set a size of a single character in pixels.
+    ///
+    /// A font size is set to 4/5 of the height.
+    ///
+    /// Default value is `10x20`.
+    pub fn set_char_size(&mut self, width: usize, height: usize) {
+        self.char_width = width;
+        self.char_height = height;
+    }
+
+    /// Set a font family, it's supposed to be a monospace font.
+    ///
+    /// Default value is `monospace`.
+    pub fn set_font_family(&mut self, family: impl Into<String>) {
+        self.font_family = family.into();
+    }
+
+    /// Set a color which is used for a text and borders without a color.
+    ///
+    /// Default value is `black`.
+    pub fn set_color(&mut self, color: impl Into<String>) {
+        self.color = color.into();
+    }
+}
+
+impl<R> From<Table<R>> for SvgTable<Table<R>> {
+    fn from(table: Table<R>) -> Self {
+        Self {
+            table,
+            char_width: 10,
+            char_height: 20,
+            font_family: "monospace".into(),
+            color: "black".into(),
+        }
+    }
+}
+
+impl<R> Display for SvgTable<Table<R>>
+where
+    R: Records,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        convert_to_svg(f, self)
+    }
+}
+
+fn convert_to_svg<R>(f: &mut fmt::Formatter<'_>, svg: &SvgTable<Table<R>>) -> fmt::Result
+where
+    R: Records,
+{
+    let table = &svg.table;
+    let layout = Layout::new(table);
+
+    let (cw, ch) = (svg.char_width, svg.char_height);
+    let (width, height) = (layout.width * cw, layout.height * ch);
+
+    writeln!(
+        f,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\" font-family=\"{}\" font-size=\"{}\">",
+        escape(&svg.font_family),
+        ch * 4 / 5,
+    )?;
+
+    let lines = make_lines(table, &layout, cw, ch);
+    if !lines.is_empty() {
+        writeln!(f, "    <g stroke=\"{}\">", escape(&svg.color))?;
+        for line in lines {
+            write!(
+                f,
+                "        <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"",
+                line.x1, line.y1, line.x2, line.y2
+            )?;
+
+            if let Some(color) = &line.color {
+                write!(f, " stroke=\"{}\"", color)?;
+            }
+
+            writeln!(f, " />")?;
+        }
+
+        writeln!(f, "    </g>")?;
+    }
+
+    let texts = make_texts(table, &layout);
+    if !texts.is_empty() {
+        writeln!(
+            f,
+            "    <g fill=\"{}\" xml:space=\"preserve\">",
+            escape(&svg.color)
+        )?;
+        for text in texts {
+            let (x, y) = (text.x * cw, text.y * ch + ch * 3 / 4);
+            write!(f, "        <text x=\"{}\" y=\"{}\">", x, y)?;
+
+            for (part, color) in text.parts {
+                match color {
+                    Some(color) => {
+                        write!(f, "<tspan fill=\"{}\">{}</tspan>", color, escape(&part))?
+                    }
+                    None => f.write_str(&escape(&part))?,
+                }
+            }
+
+            writeln!(f, "</text>")?;
+        }
+
+        writeln!(f, "    </g>")?;
+    }
+
+    f.write_str("</svg>")
+}
+
+/// Positions of columns, rows and lines on a grid of characters.
+struct Layout {
+    columns: Vec<usize>,
+    widths: Vec<usize>,
+    verticals: Vec<Option<usize>>,
+    rows: Vec<usize>,
+    heights: Vec<usize>,
+    horizontals: Vec<Option<usize>>,
+    width: usize,
+    height: usize,
+    /// A position of a cell which takes a given cell, which differs in case of spans.
+    origins: Vec<Vec<(usize, usize)>>,
+}
+
+impl Layout {
+    fn new<R>(table: &Table<R>) -> Self
+    where
+        R: Records,
+    {
+        let shape = table.shape();
+        let (count_rows, count_columns) = shape;
+        let cfg = table.get_config();
+        let records = table.get_records();
+
+        if table.is_empty() {
+            return Self {
+                columns: Vec::new(),
+                widths: Vec::new(),
+                verticals: Vec::new(),
+                rows: Vec::new(),
+                heights: Vec::new(),
+                horizontals: Vec::new(),
+                width: 0,
+                height: 0,
+                origins: Vec::new(),
+            };
+        }
+
+        // widths are measured on a visible text as ANSI sequences are turned into colors
+        let plain = PlainRecords(records);
+        let mut width_estimator = WidthEstimator::default();
+        width_estimator.estimate(&plain, cfg);
+        let widths = (0..count_columns)
+            .map(|col| Estimate::<&PlainRecords<'_, R>>::get(&width_estimator, col).unwrap_or(0))
+            .collect::<Vec<_>>();
+
+        let mut height_estimator = HeightEstimator::default();
+        height_estimator.estimate(records, cfg);
+        let heights = (0..count_rows)
+            .map(|row| Estimate::<&R>::get(&height_estimator, row).unwrap_or(0))
+            .collect::<Vec<_>>();
+
+        let margin = cfg.get_margin();
+
+        let (columns, verticals, width) = place(&widths, margin.left.size, |col| {
+            cfg.has_vertical(col, count_columns)
+        });
+        let (rows, horizontals, height) = place(&heights, margin.top.size, |row| {
+            cfg.has_horizontal(row, count_rows)
+        });
+
+        let mut origins = (0..count_rows)
+            .map(|row| (0..count_columns).map(|col| (row, col)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let spans = cfg
+            .iter_column_spans(shape)
+            .chain(cfg.iter_row_spans(shape))
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+        for (row, col) in spans {
+            let column_span = cfg.get_column_span((row, col), shape).unwrap_or(1);
+            let row_span = cfg.get_row_span((row, col), shape).unwrap_or(1);
+
+            let rows = origins.iter_mut().skip(row).take(row_span);
+            for cells in rows {
+                for origin in cells.iter_mut().skip(col).take(column_span) {
+                    *origin = (row, col);
+                }
+            }
+        }
+
+        Self {
+            columns,
+            widths,
+            verticals,
+            rows,
+            heights,
+            horizontals,
+            width: width + margin.right.size,
+            height: height + margin.bottom.size,
+            origins,
+        }
+    }
+
+    fn count_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn count_columns(&self) -> usize {
+        self.columns.len()
+    }
+}
+
+/// Places columns or rows one after another along with lines between them.
+///
+/// Returns starts of the columns, positions of the lines and a total size.
+fn place<F>(sizes: &[usize], start: usize, has_line: F) -> (Vec<usize>, Vec<Option<usize>>, usize)
+where
+    F: Fn(usize) -> bool,
+{
+    let mut starts = Vec::with_capacity(sizes.len());
+    let mut lines = Vec::with_capacity(sizes.len() + 1);
+    let mut pos = start;
+    for i in 0..=sizes.len() {
+        if has_line(i) {
+            lines.push(Some(pos));
+            pos += 1;
+        } else {
+            lines.push(None);
+        }
+
+        if let Some(size) = sizes.get(i) {
+            starts.push(pos);
+            pos += size;
+        }
+    }
+
+    (starts, lines, pos)
+}
+
+/// Records which hide ANSI sequences of a text.
+struct PlainRecords<'a, R>(&'a R);
+
+impl<R> Records for PlainRecords<'_, R>
+where
+    R: Records,
+{
+    fn count_rows(&self) -> usize {
+        self.0.count_rows()
+    }
+
+    fn count_columns(&self) -> usize {
+        self.0.count_columns()
+    }
+
+    fn get_text(&self, pos: Position) -> Cow<'_, str> {
+        Cow::Owned(strip_ansi(&self.0.get_text(pos)))
+    }
+
+    fn get_line(&self, pos: Position, i: usize) -> Cow<'_, str> {
+        Cow::Owned(strip_ansi(&self.0.get_line(pos, i)))
+    }
+
+    fn count_lines(&self, pos: Position) -> usize {
+        self.0.count_lines(pos)
+    }
+
+    fn get_width<W>(&self, pos: Position, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        width_ctrl.width_multiline(&self.get_text(pos))
+    }
+
+    fn get_line_width<W>(&self, pos: Position, i: usize, width_ctrl: W) -> usize
+    where
+        W: WidthFunc,
+    {
+        width_ctrl.width(&self.get_line(pos, i))
+    }
+
+    fn fmt_text_prefix<F>(&self, _: &mut F, _: Position) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
+        Ok(())
+    }
+
+    fn fmt_text_suffix<F>(&self, _: &mut F, _: Position) -> fmt::Result
+    where
+        F: fmt::Write + ?Sized,
+    {
+        Ok(())
+    }
+}
+
+struct Line {
+    x1: usize,
+    y1: usize,
+    x2: usize,
+    y2: usize,
+    color: Option<String>,
+}
+
+fn make_lines<R>(table: &Table<R>, layout: &Layout, cw: usize, ch: usize) -> Vec<Line> {
+    let cfg = table.get_config();
+    let (count_rows, count_columns) = (layout.count_rows(), layout.count_columns());
+    if count_rows == 0 || count_columns == 0 {
+        return Vec::new();
+    }
+
+    // lines go through a center of a character
+    let line_x = |col: usize| layout.verticals[col].map(|x| x * cw + cw / 2);
+    let line_y = |row: usize| layout.horizontals[row].map(|y| y * ch + ch / 2);
+
+    let mut lines = Vec::new();
+
+    for row in 0..=count_rows {
+        let y = match line_y(row) {
+            Some(y) => y,
+            None => continue,
+        };
+
+        for col in 0..count_columns {
+            let is_split = row == 0
+                || row == count_rows
+                || layout.origins[row - 1][col] != layout.origins[row][col];
+            let c = cfg.get_horizontal((row, col), count_rows);
+            if !is_split || !matches!(c, Some(&c) if c != ' ') {
+                continue;
+            }
+
+            let x1 = line_x(col).unwrap_or(layout.columns[col] * cw);
+            let x2 = line_x(col + 1).unwrap_or((layout.columns[col] + layout.widths[col]) * cw);
+            let color = horizontal_color(cfg, (row, col), count_rows);
+
+            push_line(
+                &mut lines,
+                Line {
+                    x1,
+                    y1: y,
+                    x2,
+                    y2: y,
+                    color,
+                },
+            );
+        }
+    }
+
+    for col in 0..=count_columns {
+        let x = match line_x(col) {
+            Some(x) => x,
+            None => continue,
+        };
+
+        for row in 0..count_rows {
+            let is_split = col == 0
+                || col == count_columns
+                || layout.origins[row][col - 1] != layout.origins[row][col];
+            let c = cfg.get_vertical((row, col), count_columns);
+            if !is_split || !matches!(c, Some(&c) if c != ' ') {
+                continue;
+            }
+
+            let y1 = line_y(row).unwrap_or(layout.rows[row] * ch);
+            let y2 = line_y(row + 1).unwrap_or((layout.rows[row] + layout.heights[row]) * ch);
+            let color = vertical_color(cfg, (row, col), count_columns);
+
+            push_line(
+                &mut lines,
+                Line {
+                    x1: x,
+                    y1,
+                    x2: x,
+                    y2,
+                    color,
+                },
+            );
+        }
+    }
+
+    lines
+}
+
+/// Pushes a line, merging it with a previous one if it continues it.
+fn push_line(lines: &mut Vec<Line>, line: Line) {
+    if let Some(last) = lines.last_mut() {
+        let is_continued = last.x2 == line.x1
+            && last.y2 == line.y1
+            && (last.x1 == line.x2 || last.y1 == line.y2)
+            && last.color == line.color;
+        if is_continued {
+            last.x2 = line.x2;
+            last.y2 = line.y2;
+            return;
+        }
+    }
+
+    lines.push(line);
+}
+
+#[cfg(feature = "color")]
+fn horizontal_color(cfg: &GridConfig, pos: (usize, usize), count_rows: usize) -> Option<String> {
+    cfg.get_horizontal_color(pos, count_rows)
+        .and_then(|color| parse_ansi(color.get_prefix()).1)
+}
+
+#[cfg(not(feature = "color"))]
+fn horizontal_color(_: &GridConfig, _: (usize, usize), _: usize) -> Option<String> {
+    None
+}
+
+#[cfg(feature = "color")]
+fn vertical_color(cfg: &GridConfig, pos: (usize, usize), count_columns: usize) -> Option<String> {
+    cfg.get_vertical_color(pos, count_columns)
+        .and_then(|color| parse_ansi(color.get_prefix()).1)
+}
+
+#[cfg(not(feature = "color"))]
+fn vertical_color(_: &GridConfig, _: (usize, usize), _: usize) -> Option<String> {
+    None
+}
+
+struct Text {
+    x: usize,
+    y: usize,
+    parts: Parts,
+}
+
+fn make_texts<R>(table: &Table<R>, layout: &Layout) -> Vec<Text>
+where
+    R: Records,
+{
+    let cfg = table.get_config();
+    let records = table.get_records();
+
+    let mut texts = Vec::new();
+    for row in 0..layout.count_rows() {
+        for col in 0..layout.count_columns() {
+            if layout.origins[row][col] != (row, col) {
+                continue;
+            }
+
+            let last_row = (row..layout.count_rows())
+                .take_while(|&r| layout.origins[r][col] == (row, col))
+                .last()
+                .unwrap_or(row);
+            let last_col = (col..layout.count_columns())
+                .take_while(|&c| layout.origins[row][c] == (row, col))
+                .last()
+                .unwrap_or(col);
+
+            let x = layout.columns[col];
+            let y = layout.rows[row];
+            let width = layout.columns[last_col] + layout.widths[last_col] - x;
+            let height = layout.rows[last_row] + layout.heights[last_row] - y;
+
+            let padding = cfg.get_padding(Entity::Cell(row, col));
+            let available_width = width.saturating_sub(padding.left.size + padding.right.size);
+            let available_height = height.saturating_sub(padding.top.size + padding.bottom.size);
+
+            let text = records.get_text((row, col));
+            let tab = " ".repeat(cfg.get_tab_width());
+            let lines = text
+                .split('\n')
+                .map(|line| parse_ansi(&line.replace('\t', &tab)).0)
+                .collect::<Vec<_>>();
+
+            let alignment = *cfg.get_alignment_vertical(Entity::Cell(row, col));
+            let top = match alignment {
+                AlignmentVertical::Top => 0,
+                AlignmentVertical::Bottom => available_height.saturating_sub(lines.len()),
+                AlignmentVertical::Center => available_height.saturating_sub(lines.len()) / 2,
+            };
+
+            let alignment = *cfg.get_alignment_horizontal(Entity::Cell(row, col));
+            for (i, parts) in lines.into_iter().enumerate() {
+                let line_width = parts.iter().map(|(part, _)| string_width(part)).sum();
+                let left = match alignment {
+                    AlignmentHorizontal::Left => 0,
+                    AlignmentHorizontal::Right => available_width.saturating_sub(line_width),
+                    AlignmentHorizontal::Center => available_width.saturating_sub(line_width) / 2,
+                };
+
+                let (indent, parts) = trim_parts(parts);
+                if parts.is_empty() {
+                    continue;
+                }
+
+                texts.push(Text {
+                    x: x + padding.left.size + left + indent,
+                    y: y + padding.top.size + top + i,
+                    parts,
+                });
+            }
+        }
+    }
+
+    texts
+}
+
+/// Removes spaces around a line, returning a width of the removed leading spaces.
+fn trim_parts(parts: Parts) -> (usize, Parts) {
+    let mut indent = 0;
+    let mut is_start = true;
+    let mut trimmed = Vec::with_capacity(parts.len());
+    for (part, color) in parts {
+        let part = if is_start {
+            let rest = part.trim_start_matches(' ');
+            indent += part.len() - rest.len();
+            rest.to_owned()
+        } else {
+            part
+        };
+
+        if !part.is_empty() {
+            is_start = false;
+            trimmed.push((part, color));
+        }
+    }
+
+    while let Some((part, _)) = trimmed.last_mut() {
+        let len = part.trim_end_matches(' ').len();
+        part.truncate(len);
+
+        if !part.is_empty() {
+            break;
+        }
+
+        trimmed.pop();
+    }
+
+    (indent, trimmed)
+}
+
+/// Parts of a text along with their colors.
+type Parts = Vec<(String, Option<String>)>;
+
+/// Splits a text into parts of the same foreground color, removing ANSI escape sequences.
+///
+/// Returns the parts and a color which is set at the end.
+fn parse_ansi(text: &str) -> (Parts, Option<String>) {
+    let mut parts: Parts = Vec::new();
+    let mut color = None;
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            match parts.last_mut() {
+                Some((part, part_color)) if *part_color == color => part.push(c),
+                _ => parts.push((c.to_string(), color.clone())),
+            }
+
+            continue;
+        }
+
+        match chars.next() {
+            Some('[') => {
+                let mut params = String::new();
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        if c == 'm' {
+                            apply_sgr(&params, &mut color);
+                        }
+
+                        break;
+                    }
+
+                    params.push(c);
+                }
+            }
+            // OSC sequence, like a hyperlink, ends with BEL or ST
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\u{7}' {
+                        break;
+                    }
+
+                    if c == '\u{1b}' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (parts, color)
+}
+
+/// Removes ANSI escape sequences from a text.
+fn strip_ansi(text: &str) -> String {
+    parse_ansi(text)
+        .0
+        .into_iter()
+        .map(|(part, _)| part)
+        .collect()
+}
+
+/// Applies SGR parameters to a foreground color.
+fn apply_sgr(params: &str, color: &mut Option<String>) {
+    let codes = params
+        .split(';')
+        .map(|code| code.parse::<u8>().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    let mut i = 0;
+    while i < codes.len() {
+        match codes[i] {
+            0 | 39 => *color = None,
+            code @ 30..=37 => *color = Some(color_256(code - 30)),
+            code @ 90..=97 => *color = Some(color_256(code - 90 + 8)),
+            code @ (38 | 48) => {
+                let value = match codes.get(i + 1) {
+                    Some(5) => {
+                        let value = codes.get(i + 2).map(|&n| color_256(n));
+                        i += 2;
+                        value
+                    }
+                    Some(2) => {
+                        let value = codes
+                            .get(i + 2..i + 5)
+                            .map(|rgb| format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2]));
+                        i += 4;
+                        value
+                    }
+                    _ => None,
+                };
+
+                if code == 38 {
+                    if let Some(value) = value {
+                        *color = Some(value);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        i += 1;
+    }
+}
+
+/// Returns a color of a 256 color palette, where first 16 colors are XTerm ones.
+fn color_256(n: u8) -> String {
+    const BASIC: [&str; 16] = [
+        "#000000", "#cd0000", "#00cd00", "#cdcd00", "#0000ee", "#cd00cd", "#00cdcd", "#e5e5e5",
+        "#7f7f7f", "#ff0000", "#00ff00", "#ffff00", "#5c5cff", "#ff00ff", "#00ffff", "#ffffff",
+    ];
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    match n {
+        0..=15 => BASIC[n as usize].to_owned(),
+        16..=231 => {
+            let n = n - 16;
+            let (r, g, b) = (n / 36, n / 6 % 6, n % 6);
+            let (r, g, b) = (LEVELS[r as usize], LEVELS[g as usize], LEVELS[b as usize]);
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        }
+        _ => {
+            let level = 8 + (n - 232) * 10;
+            format!("#{:02x}{:02x}{:02x}", level, level, level)
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut buf = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '"' => buf.push_str("&quot;"),
+            '\'' => buf.push_str("&apos;"),
+            _ => buf.push(c),
+        }
+    }
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use tabled::{
+        builder::Builder,
+        object::{Cell, Columns},
+        Alignment, Margin, Modify, ModifyObject, Span, Style,
+    };
+
+    use super::*;
+
+    #[test]
+    fn basic() {
+        let table = Table::new([["1", "2"]]);
+        let table = SvgTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"90\" height=\"100\" viewBox=\"0 0 90 100\" font-family=\"monospace\" font-size=\"16\">\n",
+                "    <g stroke=\"black\">\n",
+                "        <line x1=\"5\" y1=\"10\" x2=\"85\" y2=\"10\" />\n",
+                "        <line x1=\"5\" y1=\"50\" x2=\"85\" y2=\"50\" />\n",
+                "        <line x1=\"5\" y1=\"90\" x2=\"85\" y2=\"90\" />\n",
+                "        <line x1=\"5\" y1=\"10\" x2=\"5\" y2=\"90\" />\n",
+                "        <line x1=\"45\" y1=\"10\" x2=\"45\" y2=\"90\" />\n",
+                "        <line x1=\"85\" y1=\"10\" x2=\"85\" y2=\"90\" />\n",
+                "    </g>\n",
+                "    <g fill=\"black\" xml:space=\"preserve\">\n",
+                "        <text x=\"20\" y=\"35\">0</text>\n",
+                "        <text x=\"60\" y=\"35\">1</text>\n",
+                "        <text x=\"20\" y=\"75\">1</text>\n",
+                "        <text x=\"60\" y=\"75\">2</text>\n",
+                "    </g>\n",
+                "</svg>",
+            )
+        );
+    }
+
+    #[test]
+    fn alignment() {
+        let mut table = Table::new([["a", "bbbb"], ["ccc", "d"]]);
+        table
+            .with(Style::modern())
+            .with(Columns::single(0).modify().with(Alignment::right()));
+
+        let table = SvgTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"140\" height=\"140\" viewBox=\"0 0 140 140\" font-family=\"monospace\" font-size=\"16\">\n",
+                "    <g stroke=\"black\">\n",
+                "        <line x1=\"5\" y1=\"10\" x2=\"135\" y2=\"10\" />\n",
+                "        <line x1=\"5\" y1=\"50\" x2=\"135\" y2=\"50\" />\n",
+                "        <line x1=\"5\" y1=\"90\" x2=\"135\" y2=\"90\" />\n",
+                "        <line x1=\"5\" y1=\"130\" x2=\"135\" y2=\"130\" />\n",
+                "        <line x1=\"5\" y1=\"10\" x2=\"5\" y2=\"130\" />\n",
+                "        <line x1=\"65\" y1=\"10\" x2=\"65\" y2=\"130\" />\n",
+                "        <line x1=\"135\" y1=\"10\" x2=\"135\" y2=\"130\" />\n",
+                "    </g>\n",
+                "    <g fill=\"black\" xml:space=\"preserve\">\n",
+                "        <text x=\"40\" y=\"35\">0</text>\n",
+                "        <text x=\"80\" y=\"35\">1</text>\n",
+                "        <text x=\"40\" y=\"75\">a</text>\n",
+                "        <text x=\"80\" y=\"75\">bbbb</text>\n",
+                "        <text x=\"20\" y=\"115\">ccc</text>\n",
+                "        <text x=\"80\" y=\"115\">d</text>\n",
+                "    </g>\n",
+                "</svg>",
+            )
+        );
+    }
+
+    #[test]
+    fn column_span() {
+        let mut table = Table::new([["a", "b"], ["c", "d"]]);
+        table.with(Style::modern()).with(
+            Modify::new(Cell(1, 0))
+                .with(Span::column(2))
+                .with(Alignment::center()),
+        );
+
+        let table = SvgTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"90\" height=\"140\" viewBox=\"0 0 90 140\" font-family=\"monospace\" font-size=\"16\">\n",
+                "    <g stroke=\"black\">\n",
+                "        <line x1=\"5\" y1=\"10\" x2=\"85\" y2=\"10\" />\n",
+                "        <line x1=\"5\" y1=\"50\" x2=\"85\" y2=\"50\" />\n",
+                "        <line x1=\"5\" y1=\"90\" x2=\"85\" y2=\"90\" />\n",
+                "        <line x1=\"5\" y1=\"130\" x2=\"85\" y2=\"130\" />\n",
+                "        <line x1=\"5\" y1=\"10\" x2=\"5\" y2=\"130\" />\n",
+                "        <line x1=\"45\" y1=\"10\" x2=\"45\" y2=\"50\" />\n",
+                "        <line x1=\"45\" y1=\"90\" x2=\"45\" y2=\"130\" />\n",
+                "        <line x1=\"85\" y1=\"10\" x2=\"85\" y2=\"130\" />\n",
+                "    </g>\n",
+                "    <g fill=\"black\" xml:space=\"preserve\">\n",
+                "        <text x=\"20\" y=\"35\">0</text>\n",
+                "        <text x=\"60\" y=\"35\">1</text>\n",
+                "        <text x=\"40\" y=\"75\">a</text>\n",
+                "        <text x=\"20\" y=\"115\">c</text>\n",
+                "        <text x=\"60\" y=\"115\">d</text>\n",
+                "    </g>\n",
+                "</svg>",
+            )
+        );
+    }
+
+    #[test]
+    fn row_span() {
+        let mut table = Table::new([["a", "b"], ["c", "d"]]);
+        table.with(Modify::new(Cell(1, 0)).with(Span::row(2)));
+
+        let table = SvgTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"90\" height=\"140\" viewBox=\"0 0 90 140\" font-family=\"monospace\" font-size=\"16\">\n",
+                "    <g stroke=\"black\">\n",
+                "        <line x1=\"5\" y1=\"10\" x2=\"85\" y2=\"10\" />\n",
+                "        <line x1=\"5\" y1=\"50\" x2=\"85\" y2=\"50\" />\n",
+                "        <line x1=\"45\" y1=\"90\" x2=\"85\" y2=\"90\" />\n",
+                "        <line x1=\"5\" y1=\"130\" x2=\"85\" y2=\"130\" />\n",
+                "        <line x1=\"5\" y1=\"10\" x2=\"5\" y2=\"130\" />\n",
+                "        <line x1=\"45\" y1=\"10\" x2=\"45\" y2=\"130\" />\n",
+                "        <line x1=\"85\" y1=\"10\" x2=\"85\" y2=\"130\" />\n",
+                "    </g>\n",
+                "    <g fill=\"black\" xml:space=\"preserve\">\n",
+                "        <text x=\"20\" y=\"35\">0</text>\n",
+                "        <text x=\"60\" y=\"35\">1</text>\n",
+                "        <text x=\"20\" y=\"75\">a</text>\n",
+                "        <text x=\"60\" y=\"75\">b</text>\n",
+                "        <text x=\"60\" y=\"115\">d</text>\n",
+                "    </g>\n",
+                "</svg>",
+            )
+        );
+    }
+
+    #[test]
+    fn ansi_text() {
+        let mut table =
+            Table::new([["\u{1b}[31mred\u{1b}[39m <x> \u{1b}[38;2;1;2;3mrgb\u{1b}[0m"]]);
+        table.with(Style::empty());
+
+        let table = SvgTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"130\" height=\"40\" viewBox=\"0 0 130 40\" font-family=\"monospace\" font-size=\"16\">\n",
+                "    <g fill=\"black\" xml:space=\"preserve\">\n",
+                "        <text x=\"10\" y=\"15\">0</text>\n",
+                "        <text x=\"10\" y=\"35\"><tspan fill=\"#cd0000\">red</tspan> &lt;x&gt; <tspan fill=\"#010203\">rgb</tspan></text>\n",
+                "    </g>\n",
+                "</svg>",
+            )
+        );
+    }
+
+    #[test]
+    fn settings_and_multiline() {
+        let mut table = Table::new([["a\nbb"]]);
+        table.with(Style::blank()).with(Margin::new(1, 1, 1, 1));
+
+        let mut table = SvgTable::from(table);
+        table.set_char_size(8, 16);
+        table.set_font_family("Fira Code");
+        table.set_color("#333");
+
+        let table = table.to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"48\" height=\"80\" viewBox=\"0 0 48 80\" font-family=\"Fira Code\" font-size=\"12\">\n",
+                "    <g fill=\"#333\" xml:space=\"preserve\">\n",
+                "        <text x=\"16\" y=\"28\">0</text>\n",
+                "        <text x=\"16\" y=\"44\">a</text>\n",
+                "        <text x=\"16\" y=\"60\">bb</text>\n",
+                "    </g>\n",
+                "</svg>",
+            )
+        );
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn border_color() {
+        use std::convert::TryFrom;
+
+        use tabled::color::Color;
+
+        let mut table = Table::new([["1", "2"]]);
+        table.with(Color::try_from("\u{1b}[31m \u{1b}[39m").unwrap());
+
+        let table = SvgTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"90\" height=\"100\" viewBox=\"0 0 90 100\" font-family=\"monospace\" font-size=\"16\">\n",
+                "    <g stroke=\"black\">\n",
+                "        <line x1=\"5\" y1=\"10\" x2=\"85\" y2=\"10\" stroke=\"#cd0000\" />\n",
+                "        <line x1=\"5\" y1=\"50\" x2=\"85\" y2=\"50\" stroke=\"#cd0000\" />\n",
+                "        <line x1=\"5\" y1=\"90\" x2=\"85\" y2=\"90\" stroke=\"#cd0000\" />\n",
+                "        <line x1=\"5\" y1=\"10\" x2=\"5\" y2=\"90\" stroke=\"#cd0000\" />\n",
+                "        <line x1=\"45\" y1=\"10\" x2=\"45\" y2=\"90\" stroke=\"#cd0000\" />\n",
+                "        <line x1=\"85\" y1=\"10\" x2=\"85\" y2=\"90\" stroke=\"#cd0000\" />\n",
+                "    </g>\n",
+                "    <g fill=\"black\" xml:space=\"preserve\">\n",
+                "        <text x=\"20\" y=\"35\">0</text>\n",
+                "        <text x=\"60\" y=\"35\">1</text>\n",
+                "        <text x=\"20\" y=\"75\">1</text>\n",
+                "        <text x=\"60\" y=\"75\">2</text>\n",
+                "    </g>\n",
+                "</svg>",
+            )
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let table = Builder::default().build();
+        let table = SvgTable::from(table).to_string();
+
+        assert_eq!(
+            table,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"0\" height=\"0\" viewBox=\"0 0 0 0\" font-family=\"monospace\" font-size=\"16\">\n</svg>"
+        );
+    }
+
+    #[test]
+    fn colors_256() {
+        assert_eq!(color_256(1), "#cd0000");
+        assert_eq!(color_256(196), "#ff0000");
+        assert_eq!(color_256(244), "#808080");
+    }
+}
@@ -1,4 +1,8 @@
-use syn::{Attribute, LitInt};
+use std::ops::Range;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Attribute, LitInt, LitStr};
 
 use crate::{casing_style::CasingStyle, error::Error, parse};
 
@@ -11,7 +15,21 @@ pub struct Attributes {
     pub rename_all: Option<CasingStyle>,
     pub display_with: Option<String>,
     pub display_with_use_self: bool,
+    pub format: Option<TokenStream>,
+    pub format_args: Option<Vec<TokenStream>>,
+    pub table: bool,
+    pub table_style: Option<TokenStream>,
+    pub expand: bool,
     pub order: Option<usize>,
+    pub align: Option<TokenStream>,
+    pub max_width: Option<usize>,
+    pub wrap: bool,
+    pub truncate: bool,
+    pub truncate_suffix: Option<String>,
+    pub color: Option<(String, String)>,
+    pub flatten_map: bool,
+    pub variant_column: Option<String>,
+    width_span: Option<Span>,
 }
 
 impl Attributes {
@@ -19,6 +37,16 @@ impl Attributes {
         let mut attributes = Self::default();
         attributes.fill_attributes(attrs)?;
 
+        if let Some(span) = attributes.width_span {
+            if attributes.max_width.is_none() {
+                return Err(Error::new(
+                    "`wrap` and `truncate` can be used only together with `max_width`",
+                    span,
+                    Some("set a width, like `max_width = 30`".to_owned()),
+                ));
+            }
+        }
+
         Ok(attributes)
     }
 
@@ -41,6 +69,14 @@ impl Attributes {
                 }
             }
             parse::TabledAttrKind::Inline(b, prefix) => {
+                if b.value && self.flatten_map {
+                    return Err(Error::new(
+                        "`inline` can't be used together with `flatten_map`",
+                        attr.ident.span(),
+                        None,
+                    ));
+                }
+
                 if b.value {
                     self.inline = true;
                 }
@@ -54,10 +90,145 @@ impl Attributes {
                 self.rename_all = Some(CasingStyle::from_lit(&lit)?);
             }
             parse::TabledAttrKind::DisplayWith(path, use_self) => {
+                if self.format.is_some() {
+                    return Err(Error::new(
+                        "`display_with` can't be used together with `format`",
+                        path.span(),
+                        None,
+                    ));
+                }
+
                 self.display_with = Some(path.value());
                 self.display_with_use_self = use_self;
             }
+            parse::TabledAttrKind::Format(lit, args) => {
+                if self.display_with.is_some() {
+                    return Err(Error::new(
+                        "`format` can't be used together with `display_with`",
+                        lit.span(),
+                        None,
+                    ));
+                }
+
+                let count_args = args.as_ref().map_or(1, Vec::len);
+                check_format_string(&lit, count_args)?;
+
+                self.format = Some(lit.into_token_stream());
+                self.format_args =
+                    args.map(|args| args.into_iter().map(ToTokens::into_token_stream).collect());
+            }
+            parse::TabledAttrKind::Table(style) => {
+                if self.flatten_map {
+                    return Err(Error::new(
+                        "`table` can't be used together with `flatten_map`",
+                        attr.ident.span(),
+                        None,
+                    ));
+                }
+
+                if self.expand {
+                    return Err(Error::new(
+                        "`table` can't be used together with `expand`",
+                        attr.ident.span(),
+                        None,
+                    ));
+                }
+
+                self.table = true;
+
+                if let Some(style) = style {
+                    let expr = style.parse::<syn::Expr>().map_err(|err| {
+                        Error::new(
+                            format!(
+                                "Failed to parse {:?} as an expression; {}",
+                                style.value(),
+                                err
+                            ),
+                            style.span(),
+                            Some("it's supposed to be a style, like `Style::modern()`".to_owned()),
+                        )
+                    })?;
+
+                    self.table_style = Some(expr.into_token_stream());
+                }
+            }
+            parse::TabledAttrKind::Expand => {
+                if self.flatten_map {
+                    return Err(Error::new(
+                        "`expand` can't be used together with `flatten_map`",
+                        attr.ident.span(),
+                        None,
+                    ));
+                }
+
+                if self.table {
+                    return Err(Error::new(
+                        "`expand` can't be used together with `table`",
+                        attr.ident.span(),
+                        None,
+                    ));
+                }
+
+                self.expand = true;
+            }
             parse::TabledAttrKind::Order(value) => self.order = Some(lit_int_to_usize(&value)?),
+            parse::TabledAttrKind::Align(lit) => {
+                let alignment = match lit.value().as_str() {
+                    "left" => quote!(::tabled::Alignment::left()),
+                    "right" => quote!(::tabled::Alignment::right()),
+                    "center" => quote!(::tabled::Alignment::center()),
+                    value => {
+                        return Err(Error::new(
+                            format!("unexpected alignment {:?}", value),
+                            lit.span(),
+                            Some("expected one of `left`, `right` or `center`".to_owned()),
+                        ))
+                    }
+                };
+
+                self.align = Some(alignment);
+            }
+            parse::TabledAttrKind::MaxWidth(value) => {
+                self.max_width = Some(lit_int_to_usize(&value)?)
+            }
+            parse::TabledAttrKind::Wrap => {
+                if self.truncate {
+                    return Err(Error::new(
+                        "`wrap` can't be used together with `truncate`",
+                        attr.ident.span(),
+                        None,
+                    ));
+                }
+
+                self.wrap = true;
+                self.width_span = Some(attr.ident.span());
+            }
+            parse::TabledAttrKind::Truncate(suffix) => {
+                if self.wrap {
+                    return Err(Error::new(
+                        "`truncate` can't be used together with `wrap`",
+                        attr.ident.span(),
+                        None,
+                    ));
+                }
+
+                self.truncate = true;
+                self.truncate_suffix = suffix.map(|suffix| suffix.value());
+                self.width_span = Some(attr.ident.span());
+            }
+            parse::TabledAttrKind::Color(lit) => self.color = Some(parse_color(&lit)?),
+            parse::TabledAttrKind::VariantColumn(lit) => self.variant_column = Some(lit.value()),
+            parse::TabledAttrKind::FlattenMap => {
+                if self.inline || self.table || self.expand {
+                    return Err(Error::new(
+                        "`flatten_map` can't be used together with `inline`, `table` or `expand`",
+                        attr.ident.span(),
+                        None,
+                    ));
+                }
+
+                self.flatten_map = true;
+            }
         }
 
         Ok(())
@@ -70,6 +241,7 @@ impl Attributes {
 
 pub struct ObjectAttributes {
     pub rename_all: Option<CasingStyle>,
+    pub variant_column: Option<String>,
 }
 
 impl ObjectAttributes {
@@ -77,10 +249,55 @@ impl ObjectAttributes {
         let attrs = Attributes::parse(attrs)?;
         Ok(Self {
             rename_all: attrs.rename_all,
+            variant_column: attrs.variant_column,
         })
     }
 }
 
+#[cfg(feature = "color")]
+fn parse_color(lit: &LitStr) -> Result<(String, String), Error> {
+    let name = lit.value();
+    let (name, offset) = match name.strip_prefix("bright_") {
+        Some(name) => (name, 90),
+        None => (name.as_str(), 30),
+    };
+
+    let code = match name {
+        "black" => 0,
+        "red" => 1,
+        "green" => 2,
+        "yellow" => 3,
+        "blue" => 4,
+        "magenta" => 5,
+        "cyan" => 6,
+        "white" => 7,
+        _ => {
+            return Err(Error::new(
+                format!("unexpected color {:?}", lit.value()),
+                lit.span(),
+                Some(
+                    "expected one of `black`, `red`, `green`, `yellow`, `blue`, `magenta`, `cyan` or `white`, optionally prefixed by `bright_`"
+                        .to_owned(),
+                ),
+            ))
+        }
+    };
+
+    let prefix = format!("\u{1b}[{}m", offset + code);
+    let suffix = String::from("\u{1b}[39m");
+
+    Ok((prefix, suffix))
+}
+
+#[cfg(not(feature = "color"))]
+fn parse_color(lit: &LitStr) -> Result<(String, String), Error> {
+    Err(Error::new(
+        "`color` attribute is available only with a `color` feature",
+        lit.span(),
+        Some("turn on a `color` feature of `tabled`".to_owned()),
+    ))
+}
+
 fn lit_int_to_usize(value: &LitInt) -> Result<usize, Error> {
     value.base10_parse::<usize>().map_err(|e| {
         Error::new(
@@ -90,3 +307,154 @@ fn lit_int_to_usize(value: &LitInt) -> Result<usize, Error> {
         )
     })
 }
+
+/// Verifies that a format string is valid and uses exactly the given number of positional arguments.
+///
+/// It's not a complete check as `format!` does, but it makes it possible to point out
+/// the most common mistakes in the attribute itself.
+fn check_format_string(lit: &LitStr, count_args: usize) -> Result<(), Error> {
+    let text = lit.value();
+
+    // a placeholder is described by its text and a position in a string
+    let error = |msg: String, placeholder: Option<Range<usize>>| {
+        let help = match count_args {
+            1 => "a field value is the only argument, like in `format = \"{:.2}\"`",
+            _ => "arguments are given after a format string, like in `format(\"{} {}\", self.a, self.b)`",
+        };
+
+        let (msg, span) = match placeholder {
+            Some(range) => {
+                let position = text[..range.start].chars().count();
+                let msg = format!(
+                    "{} in `{}` at position {}",
+                    msg,
+                    &text[range.clone()],
+                    position
+                );
+                (msg, placeholder_span(lit, range))
+            }
+            None => (msg, lit.span()),
+        };
+
+        Error::new(msg, span, Some(help.to_owned()))
+    };
+
+    let mut used = vec![false; count_args];
+    let mut next_arg = 0;
+
+    let mut use_arg = |index: usize, placeholder: &Range<usize>| {
+        if index >= count_args {
+            return Err(error(
+                format!(
+                    "invalid reference to positional argument {} ({})",
+                    index,
+                    match count_args {
+                        0 => "no arguments were given".to_owned(),
+                        1 => "there is 1 argument".to_owned(),
+                        n => format!("there are {} arguments", n),
+                    }
+                ),
+                Some(placeholder.clone()),
+            ));
+        }
+
+        used[index] = true;
+        Ok(())
+    };
+
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '{' if matches!(chars.peek(), Some((_, '{'))) => {
+                chars.next();
+            }
+            '}' if matches!(chars.peek(), Some((_, '}'))) => {
+                chars.next();
+            }
+            '}' => {
+                return Err(error(
+                    "invalid format string: unmatched `}` found".to_owned(),
+                    Some(start..start + 1),
+                ))
+            }
+            '{' => {
+                let mut end = text.len();
+                let mut is_closed = false;
+                for (i, c) in chars.by_ref() {
+                    if c == '}' {
+                        end = i + 1;
+                        is_closed = true;
+                        break;
+                    }
+                }
+
+                let placeholder = start..end;
+                let inner = &text[start + 1..if is_closed { end - 1 } else { end }];
+                if !is_closed || inner.contains('{') {
+                    return Err(error(
+                        "invalid format string: expected `}`, found end of string".to_owned(),
+                        Some(placeholder),
+                    ));
+                }
+
+                let (arg, spec) = match inner.split_once(':') {
+                    Some((arg, spec)) => (arg.trim(), spec),
+                    None => (inner.trim(), ""),
+                };
+
+                // named arguments are captured from a scope so we don't check them
+                if arg.is_empty() {
+                    use_arg(next_arg, &placeholder)?;
+                    next_arg += 1;
+                } else if let Ok(index) = arg.parse::<usize>() {
+                    use_arg(index, &placeholder)?;
+                }
+
+                // a precision or a width may reference an argument as well, like `{:.*}` or `{:1$}`
+                if spec.contains(".*") {
+                    use_arg(next_arg, &placeholder)?;
+                    next_arg += 1;
+                }
+
+                for part in spec.split('$').rev().skip(1) {
+                    let digits = part
+                        .chars()
+                        .rev()
+                        .take_while(char::is_ascii_digit)
+                        .collect::<String>();
+                    let index = digits.chars().rev().collect::<String>();
+                    if let Ok(index) = index.parse::<usize>() {
+                        use_arg(index, &placeholder)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(index) = used.iter().position(|used| !used) {
+        return Err(error(
+            format!("argument {} is never used in a format string", index),
+            None,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns a span of a part of a string literal.
+///
+/// It's possible only if a literal has no escapes and a compiler supports it,
+/// otherwise a span of the whole literal is returned.
+fn placeholder_span(lit: &LitStr, range: Range<usize>) -> Span {
+    let token = lit.token();
+    let is_plain = token.to_string() == format!("\"{}\"", lit.value());
+    if !is_plain {
+        return lit.span();
+    }
+
+    // the first byte of a token is a quote
+    token
+        .subspan(range.start + 1..range.end + 1)
+        .unwrap_or_else(|| lit.span())
+}
@@ -10,8 +10,8 @@ use proc_macro_error::proc_macro_error;
 use quote::{quote, ToTokens, TokenStreamExt};
 use std::{collections::HashMap, str};
 use syn::{
-    parse_macro_input, token, Data, DataEnum, DataStruct, DeriveInput, Field, Fields, Ident, Index,
-    Type, Variant,
+    parse_macro_input, spanned::Spanned, token, Data, DataEnum, DataStruct, DeriveInput, Field,
+    Fields, GenericArgument, Ident, Index, PathArguments, Type, Variant,
 };
 
 use attributes::{Attributes, ObjectAttributes};
@@ -30,13 +30,39 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
         .map_err(error::abort)
         .unwrap();
 
-    let length = get_tabled_length(ast).map_err(error::abort).unwrap();
+    let length = get_tabled_length(ast, &attrs)
+        .map_err(error::abort)
+        .unwrap();
     let info = collect_info(ast, &attrs).map_err(error::abort).unwrap();
-    let fields = info.values;
-    let headers = info.headers;
 
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    if let Some(fields) = info.dynamic {
+        return quote! {
+            impl #impl_generics ::tabled::TabledDynamic for #name #ty_generics #where_clause {
+                fn fields(&self) -> Vec<(::std::borrow::Cow<'_, str>, ::std::borrow::Cow<'_, str>)> {
+                    #fields
+                }
+            }
+        };
+    }
+
+    let fields = info.values;
+    let headers = info.headers;
+    let configure = info.configure.map(|configure| {
+        quote! {
+            fn configure<R>(table: &mut ::tabled::Table<R>)
+            where
+                R: ::tabled::papergrid::records::Records
+                    + ::tabled::papergrid::records::RecordsMut<String>,
+            {
+                #configure
+            }
+        }
+    });
+    let rows = info.rows;
+
     let expanded = quote! {
         impl #impl_generics Tabled for #name #ty_generics #where_clause {
             const LENGTH: usize = #length;
@@ -48,16 +74,20 @@ fn impl_tabled(ast: &DeriveInput) -> TokenStream {
             fn headers() -> Vec<::std::borrow::Cow<'static, str>> {
                 #headers
             }
+
+            #rows
+
+            #configure
         }
     };
 
     expanded
 }
 
-fn get_tabled_length(ast: &DeriveInput) -> Result<TokenStream, Error> {
+fn get_tabled_length(ast: &DeriveInput, attrs: &ObjectAttributes) -> Result<TokenStream, Error> {
     match &ast.data {
         Data::Struct(data) => get_fields_length(&data.fields),
-        Data::Enum(data) => get_enum_length(data),
+        Data::Enum(data) => get_enum_length(data, attrs),
         Data::Union(_) => Err(Error::message("Union type isn't supported")),
     }
 }
@@ -75,11 +105,15 @@ fn get_fields_length(fields: &Fields) -> Result<TokenStream, Error> {
         .map(|(field, attr)| {
             if attr.inline {
                 let field_type = &field.ty;
-                quote!({<#field_type as Tabled>::LENGTH})
+                Ok(quote!({<#field_type as Tabled>::LENGTH}))
+            } else if attr.expand {
+                let item_type = collection_item_type(&field.ty)?;
+                Ok(quote!({<#item_type as Tabled>::LENGTH}))
             } else {
-                quote!({ 1 })
+                Ok(quote!({ 1 }))
             }
-        });
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
 
     let size_components = std::iter::once(quote!(0)).chain(size_components);
 
@@ -89,7 +123,13 @@ fn get_fields_length(fields: &Fields) -> Result<TokenStream, Error> {
     Ok(stream)
 }
 
-fn get_enum_length(enum_ast: &DataEnum) -> Result<TokenStream, Error> {
+fn get_enum_length(enum_ast: &DataEnum, attrs: &ObjectAttributes) -> Result<TokenStream, Error> {
+    if attrs.variant_column.is_some() {
+        let variants = collect_variant_fields(enum_ast, attrs)?;
+        let length = 1 + variant_columns(&variants).len();
+        return Ok(quote!(#length));
+    }
+
     let variant_sizes = get_enum_variant_length(enum_ast);
 
     let mut stream = TokenStream::new();
@@ -137,6 +177,12 @@ fn collect_info(ast: &DeriveInput, attrs: &ObjectAttributes) -> Result<Impl, Err
 }
 
 fn collect_info_struct(ast: &DataStruct, attrs: &ObjectAttributes) -> Result<Impl, Error> {
+    if attrs.variant_column.is_some() {
+        return Err(Error::message(
+            "`variant_column` is supported only for enums",
+        ));
+    }
+
     info_from_fields(&ast.fields, attrs, field_var_name, "")
 }
 
@@ -163,6 +209,11 @@ fn info_from_fields(
 
     let mut headers = Vec::new();
     let mut values = Vec::new();
+    let mut lengths = Vec::new();
+    let mut layouts = Vec::new();
+    let mut dynamic = Vec::new();
+    let mut is_dynamic = false;
+    let mut expanded = None;
     let mut reorder = HashMap::new();
 
     for result in fields {
@@ -182,21 +233,152 @@ fn info_from_fields(
             reorder.insert(order, i);
         }
 
+        let field_name = field_name(i, field);
+
+        if attributes.flatten_map {
+            headers.push(quote!(Vec::<::std::borrow::Cow<'static, str>>::new()));
+            values.push(quote!(Vec::<::std::borrow::Cow<'_, str>>::new()));
+            lengths.push(quote!(0));
+            layouts.push(field_layout(&attributes));
+            dynamic.push(Some(quote! {
+                out.extend(::tabled::TabledDynamic::fields(&#field_name));
+            }));
+            is_dynamic = true;
+            continue;
+        }
+
+        if attributes.expand {
+            if expanded.is_some() {
+                return Err(Error::new(
+                    "Only a single field can be expanded",
+                    field.ty.span(),
+                    None,
+                ));
+            }
+
+            let item_type = collection_item_type(&field.ty)?;
+
+            headers.push(get_type_headers(item_type, "", header_prefix));
+            values.push(get_expanded_fields(&field_name, item_type));
+            lengths.push(quote!(<#item_type as Tabled>::LENGTH));
+            layouts.push(field_layout(&attributes));
+            dynamic.push(None);
+            expanded = Some((values.len() - 1, field_name, item_type.clone()));
+            continue;
+        }
+
         let header = field_headers(field, i, &attributes, header_prefix);
 
         headers.push(header);
 
-        let field_name = field_name(i, field);
         let value = get_field_fields(&field_name, &attributes);
 
         values.push(value);
+
+        let length = if attributes.inline {
+            let field_type = &field.ty;
+            quote!(<#field_type as Tabled>::LENGTH)
+        } else {
+            quote!(1)
+        };
+
+        lengths.push(length);
+        layouts.push(field_layout(&attributes));
+        dynamic.push(None);
     }
 
     if !reorder.is_empty() {
+        if let Some((index, _, _)) = &mut expanded {
+            let indexes = (0..values.len()).collect::<Vec<_>>();
+            let indexes = reorder_fields(&reorder, &indexes);
+            *index = indexes.iter().position(|i| i == index).unwrap_or(*index);
+        }
+
         values = reorder_fields(&reorder, &values);
         headers = reorder_fields(&reorder, &headers);
+        lengths = reorder_fields(&reorder, &lengths);
+        layouts = reorder_fields(&reorder, &layouts);
+        dynamic = reorder_fields(&reorder, &dynamic);
     }
 
+    let dynamic = if is_dynamic {
+        if layouts.iter().any(Option::is_some) {
+            return Err(Error::message(
+                "Layout attributes like `align` and `max_width` can't be used together with `flatten_map`",
+            ));
+        }
+
+        let fields =
+            dynamic
+                .into_iter()
+                .zip(headers.iter().zip(&values))
+                .map(|(map, (header, value))| match map {
+                    Some(map) => map,
+                    None => quote!(out.extend(#header.into_iter().zip(#value));),
+                });
+
+        Some(quote!({
+            let mut out = Vec::new();
+            #(#fields)*
+            out
+        }))
+    } else {
+        None
+    };
+
+    let layouts = layouts
+        .iter()
+        .enumerate()
+        .filter_map(|(index, layout)| {
+            let layout = layout.as_ref()?;
+            let offset = &lengths[..index];
+            let length = &lengths[index];
+            Some(quote! {
+                let start = 0 #(+ #offset)*;
+                let columns = ::tabled::object::Columns::new(start..start + #length);
+                let cells = ::tabled::object::Object::not(columns, ::tabled::object::Rows::first());
+                table.with(::tabled::Modify::new(cells)#layout);
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let configure = if layouts.is_empty() {
+        None
+    } else {
+        Some(quote!(#({ #layouts })*))
+    };
+
+    let rows = expanded.map(|(index, field_name, item_type)| {
+        let offset = &lengths[..index];
+        quote! {
+            fn rows(&self) -> Vec<Vec<::std::borrow::Cow<'_, str>>> {
+                let fields = self.fields();
+                let offset = 0 #(+ #offset)*;
+
+                let mut rows = Vec::new();
+                for item in (&#field_name).into_iter() {
+                    let mut row = fields.clone();
+                    for (i, field) in <#item_type as Tabled>::fields(item).into_iter().enumerate() {
+                        row[offset + i] = field;
+                    }
+
+                    rows.push(row);
+                }
+
+                if rows.is_empty() {
+                    rows.push(fields);
+                }
+
+                rows
+            }
+
+            fn expanded_columns() -> ::std::ops::Range<usize> {
+                let offset = 0 #(+ #offset)*;
+                offset..offset + <#item_type as Tabled>::LENGTH
+            }
+        }
+    });
+
     let headers = quote!({
         let mut out = Vec::new();
         #(out.extend(#headers);)*
@@ -209,7 +391,13 @@ fn info_from_fields(
         out
     });
 
-    Ok(Impl { headers, values })
+    Ok(Impl {
+        headers,
+        values,
+        rows,
+        configure,
+        dynamic,
+    })
 }
 
 fn reorder_fields<T: Clone>(order: &HashMap<usize, usize>, elements: &[T]) -> Vec<T> {
@@ -264,6 +452,10 @@ fn field_headers(
 }
 
 fn collect_info_enum(ast: &DataEnum, attrs: &ObjectAttributes) -> Result<Impl, Error> {
+    if let Some(column) = &attrs.variant_column {
+        return info_from_variant_column(ast, attrs, column);
+    }
+
     let mut headers_list = Vec::new();
     let mut variants = Vec::new();
     for variant in &ast.variants {
@@ -274,6 +466,30 @@ fn collect_info_enum(ast: &DataEnum, attrs: &ObjectAttributes) -> Result<Impl, E
         }
 
         let info = info_from_variant(variant, &attributes, attrs)?;
+        if info.rows.is_some() {
+            return Err(Error::new(
+                "`expand` isn't supported for enum variants",
+                variant.ident.span(),
+                None,
+            ));
+        }
+
+        if info.dynamic.is_some() {
+            return Err(Error::new(
+                "`flatten_map` isn't supported for enum variants",
+                variant.ident.span(),
+                None,
+            ));
+        }
+
+        if info.configure.is_some() {
+            return Err(Error::new(
+                "Layout attributes like `align` and `max_width` aren't supported for enum variants",
+                variant.ident.span(),
+                None,
+            ));
+        }
+
         variants.push((variant, info.values));
         headers_list.push(info.headers);
     }
@@ -290,7 +506,13 @@ fn collect_info_enum(ast: &DataEnum, attrs: &ObjectAttributes) -> Result<Impl, E
         .concat()
     };
 
-    Ok(Impl { headers, values })
+    Ok(Impl {
+        headers,
+        values,
+        rows: None,
+        configure: None,
+        dynamic: None,
+    })
 }
 
 fn info_from_variant(
@@ -314,12 +536,150 @@ fn info_from_variant(
     // we need exactly string because of it must be inlined as string
     let values = quote! { vec![::std::borrow::Cow::Borrowed(#value)] };
 
-    Ok(Impl { headers, values })
+    Ok(Impl {
+        headers,
+        values,
+        rows: None,
+        configure: None,
+        dynamic: None,
+    })
+}
+
+/// Builds a table with a single column for a variant name
+/// followed by a union of fields of all variants.
+fn info_from_variant_column(
+    ast: &DataEnum,
+    attrs: &ObjectAttributes,
+    column: &str,
+) -> Result<Impl, Error> {
+    let variants = collect_variant_fields(ast, attrs)?;
+    let columns = variant_columns(&variants);
+
+    let headers = quote! {
+        vec![
+            ::std::borrow::Cow::Borrowed(#column),
+            #(::std::borrow::Cow::Borrowed(#columns),)*
+        ]
+    };
+
+    let branches = variants.iter().map(|variant| {
+        let branch = match_variant(variant.variant);
+        let name = variant_name(variant.variant, &variant.attributes);
+        let fields = variant
+            .fields
+            .iter()
+            .map(|(index, field, attributes, header)| {
+                let column = 1 + columns.iter().position(|c| c == header).unwrap_or_default();
+                let value = get_field_fields(&variant_var_name(*index, field), attributes);
+                quote! {
+                    for field in #value {
+                        out_vec[#column] = field;
+                    }
+                }
+            });
+
+        quote! {
+            Self::#branch => {
+                out_vec[0] = ::std::borrow::Cow::Borrowed(#name);
+                #(#fields)*
+            },
+        }
+    });
+
+    let values = quote! {
+        let size = <Self as Tabled>::LENGTH;
+        let mut out_vec = vec![::std::borrow::Cow::Borrowed(""); size];
+
+        #[allow(unused_variables)]
+        match &self {
+            #(#branches)*
+            _ => return vec![], // variant is hidden so we return an empty vector
+        };
+
+        out_vec
+    };
+
+    Ok(Impl {
+        headers,
+        values,
+        rows: None,
+        configure: None,
+        dynamic: None,
+    })
+}
+
+struct VariantFields<'a> {
+    variant: &'a Variant,
+    attributes: Attributes,
+    fields: Vec<(usize, &'a Field, Attributes, String)>,
+}
+
+/// Collects visible variants with their visible fields and column names of the fields.
+fn collect_variant_fields<'a>(
+    ast: &'a DataEnum,
+    attrs: &ObjectAttributes,
+) -> Result<Vec<VariantFields<'a>>, Error> {
+    let mut variants = Vec::new();
+    for variant in &ast.variants {
+        let mut attributes = Attributes::parse(&variant.attrs)?;
+        merge_attributes(&mut attributes, attrs);
+        if attributes.is_ignored() {
+            continue;
+        }
+
+        let mut fields = Vec::new();
+        for (index, field) in variant.fields.iter().enumerate() {
+            let mut field_attributes = Attributes::parse(&field.attrs)?;
+            merge_attributes(&mut field_attributes, attrs);
+            if field_attributes.is_ignored() {
+                continue;
+            }
+
+            if field_attributes.inline
+                || field_attributes.expand
+                || field_attributes.flatten_map
+                || field_attributes.order.is_some()
+                || field_layout(&field_attributes).is_some()
+            {
+                return Err(Error::new(
+                    "Only `rename`, `skip`, `display_with`, `format` and `table` attributes can be used together with `variant_column`",
+                    field.span(),
+                    None,
+                ));
+            }
+
+            let header = field_header_name(field, &field_attributes, index);
+            fields.push((index, field, field_attributes, header));
+        }
+
+        variants.push(VariantFields {
+            variant,
+            attributes,
+            fields,
+        });
+    }
+
+    Ok(variants)
+}
+
+/// Returns a union of column names of all variants, in order they were met.
+fn variant_columns(variants: &[VariantFields<'_>]) -> Vec<String> {
+    let mut columns: Vec<String> = Vec::new();
+    for (_, _, _, header) in variants.iter().flat_map(|variant| &variant.fields) {
+        if !columns.contains(header) {
+            columns.push(header.clone());
+        }
+    }
+
+    columns
 }
 
 struct Impl {
     headers: TokenStream,
     values: TokenStream,
+    rows: Option<TokenStream>,
+    configure: Option<TokenStream>,
+    dynamic: Option<TokenStream>,
 }
 
 fn get_type_headers(field_type: &Type, inline_prefix: &str, prefix: &str) -> TokenStream {
@@ -342,6 +702,29 @@ fn get_field_fields(field: &TokenStream, attr: &Attributes) -> TokenStream {
         return quote! { #field.fields() };
     }
 
+    if attr.table {
+        let style = attr
+            .table_style
+            .as_ref()
+            .map(|style| quote!(table.with(#style);));
+        return quote!(vec![::std::borrow::Cow::Owned({
+            let mut table = ::tabled::Table::new((&#field).into_iter());
+            #style
+            table.to_string()
+        })]);
+    }
+
+    if let Some(format) = &attr.format {
+        let args = match &attr.format_args {
+            Some(args) => args.clone(),
+            None => vec![field.clone()],
+        };
+
+        return quote!(vec![::std::borrow::Cow::Owned(
+            format!(#format #(, #args)*)
+        )]);
+    }
+
     if let Some(func) = &attr.display_with {
         let func_call = match attr.display_with_use_self {
             true => use_function_with_self(func),
@@ -354,6 +737,82 @@ fn get_field_fields(field: &TokenStream, attr: &Attributes) -> TokenStream {
     quote!(vec![::std::borrow::Cow::Owned(format!("{}", #field))])
 }
 
+/// Returns a list of cell settings for a column of a field, like an alignment or a width.
+fn field_layout(attr: &Attributes) -> Option<TokenStream> {
+    let mut settings = Vec::new();
+
+    if let Some(alignment) = &attr.align {
+        settings.push(alignment.clone());
+    }
+
+    if let Some(width) = attr.max_width {
+        let setting = if attr.wrap {
+            quote!(::tabled::Width::wrap(#width))
+        } else {
+            match &attr.truncate_suffix {
+                Some(suffix) => quote!(::tabled::Width::truncate(#width).suffix(#suffix)),
+                None => quote!(::tabled::Width::truncate(#width)),
+            }
+        };
+
+        settings.push(setting);
+    }
+
+    if let Some((prefix, suffix)) = &attr.color {
+        settings.push(quote!(::tabled::format::Format::new(|text| {
+            format!("{}{}{}", #prefix, text, #suffix)
+        })));
+    }
+
+    if settings.is_empty() {
+        return None;
+    }
+
+    Some(quote!(#(.with(#settings))*))
+}
+
+/// Returns fields of a first element of a collection, or empty cells if there's none.
+fn get_expanded_fields(field: &TokenStream, item_type: &Type) -> TokenStream {
+    quote! {
+        match (&#field).into_iter().next() {
+            Some(item) => <#item_type as Tabled>::fields(item),
+            None => vec![::std::borrow::Cow::Borrowed(""); <#item_type as Tabled>::LENGTH],
+        }
+    }
+}
+
+/// Returns a type of elements of a collection like `Vec<T>`, `&[T]` or `[T; N]`.
+fn collection_item_type(ty: &Type) -> Result<&Type, Error> {
+    let item_type = match ty {
+        Type::Reference(reference) => return collection_item_type(&reference.elem),
+        Type::Slice(slice) => Some(&*slice.elem),
+        Type::Array(array) => Some(&*array.elem),
+        Type::Path(path) => {
+            path.path
+                .segments
+                .last()
+                .and_then(|segment| match &segment.arguments {
+                    PathArguments::AngleBracketed(args) => {
+                        args.args.iter().find_map(|arg| match arg {
+                            GenericArgument::Type(ty) => Some(ty),
+                            _ => None,
+                        })
+                    }
+                    _ => None,
+                })
+        }
+        _ => None,
+    };
+
+    item_type.ok_or_else(|| {
+        Error::new(
+            "Failed to find a type of elements to expand",
+            ty.span(),
+            Some("`expand` is supposed to be used with a collection like `Vec<T>`".to_owned()),
+        )
+    })
+}
+
 fn use_function_for(field: &TokenStream, function: &str) -> TokenStream {
     let path: syn::Result<syn::ExprPath> = syn::parse_str(function);
     match path {
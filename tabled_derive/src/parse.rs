@@ -1,7 +1,7 @@
 use proc_macro2::{Ident, Span};
 use syn::{
-    parenthesized, parse::Parse, punctuated::Punctuated, token, Attribute, LitBool, LitInt, LitStr,
-    Token,
+    parenthesized, parse::Parse, punctuated::Punctuated, token, Attribute, Expr, LitBool, LitInt,
+    LitStr, Token,
 };
 
 pub fn parse_attributes(
@@ -32,7 +32,17 @@ pub enum TabledAttrKind {
     Rename(LitStr),
     RenameAll(LitStr),
     DisplayWith(LitStr, bool),
+    Format(LitStr, Option<Vec<Expr>>),
+    Table(Option<LitStr>),
+    Expand,
     Order(LitInt),
+    Align(LitStr),
+    MaxWidth(LitInt),
+    Wrap,
+    Truncate(Option<LitStr>),
+    Color(LitStr),
+    FlattenMap,
+    VariantColumn(LitStr),
 }
 
 impl Parse for TabledAttr {
@@ -52,6 +62,12 @@ impl Parse for TabledAttr {
                     "rename" => return Ok(Self::new(name, Rename(lit))),
                     "rename_all" => return Ok(Self::new(name, RenameAll(lit))),
                     "display_with" => return Ok(Self::new(name, DisplayWith(lit, false))),
+                    "format" => return Ok(Self::new(name, Format(lit, None))),
+                    "table" => return Ok(Self::new(name, Table(Some(lit)))),
+                    "align" => return Ok(Self::new(name, Align(lit))),
+                    "truncate" => return Ok(Self::new(name, Truncate(Some(lit)))),
+                    "color" => return Ok(Self::new(name, Color(lit))),
+                    "variant_column" => return Ok(Self::new(name, VariantColumn(lit))),
                     _ => {}
                 }
             }
@@ -69,8 +85,10 @@ impl Parse for TabledAttr {
             if input.peek(LitInt) {
                 let lit = input.parse::<LitInt>()?;
 
-                if let "order" = name_str.as_str() {
-                    return Ok(Self::new(name, Order(lit)));
+                match name_str.as_str() {
+                    "order" => return Ok(Self::new(name, Order(lit))),
+                    "max_width" => return Ok(Self::new(name, MaxWidth(lit))),
+                    _ => {}
                 }
             }
 
@@ -103,6 +121,19 @@ impl Parse for TabledAttr {
 
                         return Ok(Self::new(name, DisplayWith(lit, use_self)));
                     }
+                    "format" => {
+                        let mut args = Vec::new();
+                        while nested.peek(Token![,]) {
+                            let _comma = nested.parse::<Token![,]>()?;
+                            if nested.is_empty() {
+                                break;
+                            }
+
+                            args.push(nested.parse::<Expr>()?);
+                        }
+
+                        return Ok(Self::new(name, Format(lit, Some(args))));
+                    }
                     "inline" => {
                         return Ok(Self::new(
                             name,
@@ -127,6 +158,11 @@ impl Parse for TabledAttr {
                     Inline(LitBool::new(true, Span::call_site()), None),
                 ))
             }
+            "table" => return Ok(Self::new(name, Table(None))),
+            "expand" => return Ok(Self::new(name, Expand)),
+            "wrap" => return Ok(Self::new(name, Wrap)),
+            "flatten_map" => return Ok(Self::new(name, FlattenMap)),
+            "truncate" => return Ok(Self::new(name, Truncate(None))),
             _ => {}
         }
 
@@ -738,6 +738,80 @@ test_table!(
     "+---+------+---------+-----------+"
 );
 
+test_table!(
+    build_columnar,
+    Builder::from_iter([["n", "name"], ["0", "Dmitriy"], ["1", "Vladislav"]]).build_columnar(),
+    "+---+-----------+"
+    "| n | name      |"
+    "+---+-----------+"
+    "| 0 | Dmitriy   |"
+    "+---+-----------+"
+    "| 1 | Vladislav |"
+    "+---+-----------+"
+);
+
+test_table!(
+    build_columnar_with_columns_and_different_row_sizes,
+    {
+        let mut b = Builder::default();
+        b.set_columns(["a", "b", "c"]);
+        b.add_record(["1"]);
+        b.add_record(["1", "2", "3", "4"]);
+        b.set_default_text("?");
+        b.build_columnar()
+    },
+    "+---+---+---+---+"
+    "| a | b | c | ? |"
+    "+---+---+---+---+"
+    "| 1 | ? | ? | ? |"
+    "+---+---+---+---+"
+    "| 1 | 2 | 3 | 4 |"
+    "+---+---+---+---+"
+);
+
+test_table!(
+    build_columnar_empty,
+    Builder::default().build_columnar(),
+    ""
+);
+
+#[test]
+fn build_columnar_matches_build() {
+    use tabled::{
+        object::{Columns, Rows},
+        Concat, Disable, Extract, Rotate, Style,
+    };
+
+    let data = [
+        ["id", "name", "value", "comment"],
+        ["0", "Hello", "1.23", "multi\nline"],
+        ["1", "World", "3.21", ""],
+        ["2", "!", "0", "end"],
+    ];
+
+    macro_rules! check {
+        ($($option:expr),*) => {
+            let mut table = Builder::from_iter(data).build();
+            let mut columnar = Builder::from_iter(data).build_columnar();
+            $(
+                table.with($option);
+                columnar.with($option);
+            )*
+            assert_eq!(columnar.to_string(), table.to_string());
+            assert_eq!(columnar.shape(), table.shape());
+        };
+    }
+
+    check!(Style::modern());
+    check!(Disable::column(Columns::new(1..3)));
+    check!(Disable::row(Rows::first()));
+    check!(Extract::segment(1..3, 1..));
+    check!(Rotate::Left);
+    check!(Rotate::Right, Rotate::Bottom);
+    check!(Concat::horizontal(Builder::from_iter(data).build()));
+    check!(Concat::vertical(Builder::from_iter(data).build()));
+}
+
 #[quickcheck_macros::quickcheck]
 #[ignore = "Quickcheck tests are a bit slow, so we don't run them all the time"]
 fn qc_table_is_consistent(data: Vec<Vec<isize>>) -> bool {
@@ -0,0 +1,205 @@
+#![cfg(feature = "csv")]
+
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    csv::{CsvOptions, Header, MultiLine, Quote, SpanText},
+    object::{Cell, Columns},
+    Modify, Span, Style, Table,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn to_csv<R>(table: &Table<R>, options: CsvOptions) -> String
+where
+    R: tabled::papergrid::records::Records,
+{
+    let mut buf = Vec::new();
+    table.to_csv(&mut buf, options).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+test_table!(
+    from_csv_detects_header,
+    Builder::from_csv("name,count\napple,10\norange,3\n".as_bytes(), CsvOptions::new())
+        .unwrap()
+        .index()
+        .build()
+        .with(Style::psql()),
+    "   | name   | count "
+    "---+--------+-------"
+    " 0 | apple  | 10    "
+    " 1 | orange | 3     "
+);
+
+test_table!(
+    from_csv_detects_no_header,
+    Builder::from_csv("1,10\n2,3\n".as_bytes(), CsvOptions::new())
+        .unwrap()
+        .index()
+        .build()
+        .with(Style::psql()),
+    "   | 0 | 1  "
+    "---+---+----"
+    " 0 | 1 | 10 "
+    " 1 | 2 | 3  "
+);
+
+test_table!(
+    from_csv_header_absent,
+    Builder::from_csv(
+        "name,count\napple,10\n".as_bytes(),
+        CsvOptions::new().header(Header::Absent)
+    )
+    .unwrap()
+    .index()
+    .build()
+    .with(Style::psql()),
+    "   | 0     | 1     "
+    "---+-------+-------"
+    " 0 | name  | count "
+    " 1 | apple | 10    "
+);
+
+test_table!(
+    from_csv_custom_quotes_and_uneven_records,
+    Builder::from_csv(
+        "id;name\n0;'tabled; grid'\n1\n".as_bytes(),
+        CsvOptions::new()
+            .delimiter(b';')
+            .quote(b'\'')
+            .header(Header::Present)
+    )
+    .unwrap()
+    .build()
+    .with(Style::psql()),
+    " id | name         "
+    "----+--------------"
+    " 0  | tabled; grid "
+    " 1  |              "
+);
+
+test_table!(
+    from_csv_multiline_and_ansi,
+    Builder::from_csv(
+        "name,note\n\"\u{1b}[31mapple\u{1b}[39m\",\"red\nsweet\"\n".as_bytes(),
+        CsvOptions::new().multiline(MultiLine::Join(String::from(" / ")))
+    )
+    .unwrap()
+    .build()
+    .with(Style::psql()),
+    " name  | note        "
+    "-------+-------------"
+    " apple | red / sweet "
+);
+
+#[test]
+fn from_csv_invalid_utf8() {
+    let data = b"name\n\xff\xfe\n";
+    assert!(Builder::from_csv(&data[..], CsvOptions::new()).is_err());
+}
+
+#[test]
+fn to_csv_quote_styles() {
+    let table = Builder::from_iter([["name", "count"], ["apple, green", "10"]]).build();
+
+    assert_eq!(
+        to_csv(&table, CsvOptions::new()),
+        "name,count\n\"apple, green\",10\n"
+    );
+    assert_eq!(
+        to_csv(&table, CsvOptions::new().quote_style(Quote::Always)),
+        "\"name\",\"count\"\n\"apple, green\",\"10\"\n"
+    );
+    assert_eq!(
+        to_csv(&table, CsvOptions::new().quote_style(Quote::NonNumeric)),
+        "\"name\",\"count\"\n\"apple, green\",10\n"
+    );
+    assert_eq!(
+        to_csv(&table, CsvOptions::new().quote_style(Quote::Never)),
+        "name,count\napple, green,10\n"
+    );
+    assert_eq!(
+        to_csv(&table, CsvOptions::new().delimiter(b';').quote(b'\'')),
+        "name;count\napple, green;10\n"
+    );
+}
+
+#[test]
+fn to_csv_multiline() {
+    let table = Builder::from_iter([["note"], ["red\nsweet"]]).build();
+
+    assert_eq!(to_csv(&table, CsvOptions::new()), "note\n\"red\nsweet\"\n");
+    assert_eq!(
+        to_csv(
+            &table,
+            CsvOptions::new().multiline(MultiLine::Join(String::from(" ")))
+        ),
+        "note\nred sweet\n"
+    );
+}
+
+#[test]
+fn to_csv_ansi() {
+    let table = Builder::from_iter([
+        ["\u{1b}[1m\u{1b}[31mred\u{1b}[0m"],
+        ["\u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{1b}\\"],
+    ])
+    .build();
+
+    assert_eq!(to_csv(&table, CsvOptions::new()), "red\nlink\n");
+    assert_eq!(
+        to_csv(&table, CsvOptions::new().strip_ansi(false)),
+        "\u{1b}[1m\u{1b}[31mred\u{1b}[0m\n\u{1b}]8;;https://example.com\u{1b}\\link\u{1b}]8;;\u{1b}\\\n"
+    );
+}
+
+#[test]
+fn to_csv_spans() {
+    let mut table = create_table::<3, 3>();
+    table
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .with(
+            Modify::new(Cell(2, 2))
+                .with(Span::column(2))
+                .with(Span::row(2)),
+        );
+
+    assert_eq!(
+        to_csv(&table, CsvOptions::new()),
+        "N,column 0,column 1,column 2\n\
+         0,,0-1,0-2\n\
+         1,1-0,1-1,\n\
+         2,2-0,,\n"
+    );
+    assert_eq!(
+        to_csv(&table, CsvOptions::new().spans(SpanText::Repeat)),
+        "N,column 0,column 1,column 2\n\
+         0,0,0-1,0-2\n\
+         1,1-0,1-1,1-1\n\
+         2,2-0,1-1,1-1\n"
+    );
+}
+
+#[test]
+fn to_csv_columnar_records() {
+    let mut table = Builder::from_iter([["name", "count"], ["apple", "10"]]).build_columnar();
+    table.with(Modify::new(Columns::first()).with(Span::column(2)));
+
+    assert_eq!(to_csv(&table, CsvOptions::tsv()), "name\t\napple\t\n");
+}
+
+#[test]
+fn csv_round_trip() {
+    let table = create_table::<3, 3>();
+    let text = to_csv(&table, CsvOptions::new());
+
+    let restored = Builder::from_csv(text.as_bytes(), CsvOptions::new())
+        .unwrap()
+        .build();
+
+    assert_eq!(to_csv(&restored, CsvOptions::new()), text);
+}
@@ -213,6 +213,28 @@ mod tupple {
         }
     );
 
+    test_tuple!(
+        format,
+        t: { u8 #[tabled(format = "{:.2}")] f32 },
+        init: { 0 1.5 },
+        expected: ["0", "1"], ["0", "1.50"],
+    );
+
+    #[test]
+    fn format_with_args() {
+        #[derive(Tabled)]
+        struct St(
+            u8,
+            #[tabled(format("{} / {} / {}", self.0, self.1, self.2))] sstr,
+            u16,
+        );
+
+        let st = St(0, "v2", 3);
+
+        assert_eq!(st.fields(), vec!["0", "0 / v2 / 3", "3"]);
+        assert_eq!(St::headers(), vec!["0", "1", "2"]);
+    }
+
     // #[test]
     // fn order_compile_fail_when_order_is_bigger_then_count_fields() {
     //     #[derive(Tabled)]
@@ -475,6 +497,54 @@ mod enum_ {
         headers: ["SOMEFIELD1", "someField2", "variant_name2", "k"],
         tests:
     );
+
+    test_enum!(
+        format,
+        t: {
+            #[tabled(inline)]
+            Build { #[tabled(format = "{:>8.2} ms")] time: f64 }
+            #[tabled(inline("server::"))]
+            Server { #[tabled(format("{}:{}", host, port))] host: sstr, #[tabled(format = "{:05}")] port: u16 }
+            Unknown
+        },
+        headers: ["time", "server::host", "server::port", "Unknown"],
+        tests:
+            Build { time: 2.5 } => ["    2.50 ms", "", "", ""],
+            Server { host: "localhost", port: 80 } => ["", "localhost:80", "00080", ""],
+            Unknown => ["", "", "", "+"],
+    );
+
+    test_enum!(
+        variant_column,
+        t: #[tabled(variant_column = "kind")] {
+            Circle { radius: u8 }
+            Rect { width: u8, height: u8 }
+            Square { width: u8 }
+            Point
+        },
+        headers: ["kind", "radius", "width", "height"],
+        tests:
+            Circle { radius: 1 } => ["Circle", "1", "", ""],
+            Rect { width: 2, height: 3 } => ["Rect", "", "2", "3"],
+            Square { width: 4 } => ["Square", "", "4", ""],
+            Point => ["Point", "", "", ""],
+    );
+
+    test_enum!(
+        variant_column_rename,
+        t: #[tabled(variant_column = "Kind", rename_all = "UPPERCASE")] {
+            #[tabled(rename = "Dot")] Point
+            Circle { #[tabled(rename = "r")] radius: u8, #[tabled(skip)] center: (u8, u8) }
+            #[tabled(rename_all = "lowercase")] Line(u8, #[tabled(format = "{}px")] u8)
+            #[tabled(skip)] Empty
+        },
+        headers: ["Kind", "r", "0", "1"],
+        tests:
+            Point => ["Dot", "", "", ""],
+            Circle { radius: 1, center: (0, 0) } => ["CIRCLE", "1", "", ""],
+            Line(2, 3) => ["line", "", "2", "3px"],
+            Empty => [],
+    );
 }
 
 mod unit {
@@ -673,6 +743,136 @@ mod structure {
         expected: ["Hello", "F2"], ["0", "v2"]
     );
 
+    test_struct!(
+        format,
+        t: { #[tabled(format = "{:>8.2} ms")] f1: f64, #[tabled(format = "{:03}")] f2: u8 }
+        init: { f1: 12.345, f2: 7 }
+        expected: ["f1", "f2"], ["   12.35 ms", "007"]
+    );
+
+    #[test]
+    fn format_with_args() {
+        #[derive(Tabled)]
+        struct St {
+            #[tabled(format("{} / {}", self.f1, self.f2), rename = "ratio")]
+            f1: u8,
+            f2: u8,
+            #[tabled(format("{{{:.*}}}", usize::from(self.f2), self.f3))]
+            f3: f32,
+        }
+
+        let st = St {
+            f1: 1,
+            f2: 2,
+            f3: 1.0,
+        };
+
+        assert_eq!(st.fields(), vec!["1 / 2", "2", "{1.00}"]);
+        assert_eq!(St::headers(), vec!["ratio", "f2", "f3"]);
+    }
+
+    #[test]
+    fn table() {
+        #[derive(Tabled)]
+        struct Item {
+            id: u8,
+        }
+
+        #[derive(Tabled)]
+        struct St {
+            f1: u8,
+            #[tabled(table)]
+            f2: Vec<Item>,
+            #[tabled(table = "tabled::Style::psql()")]
+            f3: Vec<Item>,
+        }
+
+        let st = St {
+            f1: 0,
+            f2: vec![Item { id: 1 }, Item { id: 2 }],
+            f3: vec![Item { id: 3 }],
+        };
+
+        assert_eq!(
+            st.fields(),
+            vec![
+                "0",
+                "+----+\n| id |\n+----+\n| 1  |\n+----+\n| 2  |\n+----+",
+                " id \n----\n 3  "
+            ]
+        );
+        assert_eq!(St::headers(), vec!["f1", "f2", "f3"]);
+        assert_eq!(St::LENGTH, 3);
+    }
+
+    #[test]
+    fn expand() {
+        #[derive(Tabled)]
+        struct Item {
+            id: u8,
+            name: sstr,
+        }
+
+        #[derive(Tabled)]
+        struct St {
+            f1: u8,
+            #[tabled(expand)]
+            f2: Vec<Item>,
+            f3: sstr,
+        }
+
+        let st = St {
+            f1: 0,
+            f2: vec![Item { id: 1, name: "a" }, Item { id: 2, name: "b" }],
+            f3: "v3",
+        };
+
+        assert_eq!(St::headers(), vec!["f1", "id", "name", "f3"]);
+        assert_eq!(St::LENGTH, 4);
+        assert_eq!(St::expanded_columns(), 1..3);
+        assert_eq!(st.fields(), vec!["0", "1", "a", "v3"]);
+        assert_eq!(
+            st.rows(),
+            vec![vec!["0", "1", "a", "v3"], vec!["0", "2", "b", "v3"]]
+        );
+
+        let st = St {
+            f1: 0,
+            f2: vec![],
+            f3: "v3",
+        };
+
+        assert_eq!(st.fields(), vec!["0", "", "", "v3"]);
+        assert_eq!(st.rows(), vec![vec!["0", "", "", "v3"]]);
+    }
+
+    #[test]
+    fn expand_with_order() {
+        #[derive(Tabled)]
+        struct Item {
+            id: u8,
+        }
+
+        #[derive(Tabled)]
+        struct St {
+            f1: u8,
+            #[tabled(expand)]
+            f2: [Item; 2],
+            #[tabled(order = 0)]
+            f3: sstr,
+        }
+
+        let st = St {
+            f1: 0,
+            f2: [Item { id: 1 }, Item { id: 2 }],
+            f3: "v3",
+        };
+
+        assert_eq!(St::headers(), vec!["f3", "f1", "id"]);
+        assert_eq!(St::expanded_columns(), 2..3);
+        assert_eq!(st.rows(), vec![vec!["v3", "0", "1"], vec!["v3", "0", "2"]]);
+    }
+
     // #[test]
     // fn order_compile_fail_when_order_is_bigger_then_count_fields() {
     //     #[derive(Tabled)]
@@ -0,0 +1,54 @@
+#![cfg(feature = "color")]
+
+use std::iter::FromIterator;
+
+use owo_colors::OwoColorize;
+use tabled::{builder::Builder, locator::ByColumnName, Style, Table, TableDiff};
+
+use crate::util::test_table;
+
+mod util;
+
+fn table(data: &[&[&'static str]]) -> Table {
+    Builder::from_iter(data.iter().map(|row| row.iter().copied())).build()
+}
+
+test_table!(
+    diff_colored_rows,
+    {
+        let old = table(&[&["id", "name"], &["0", "a"], &["1", "b"]]);
+        let new = table(&[&["id", "name"], &["0", "c"], &["2", "d"]]);
+        TableDiff::new(&old, &new)
+            .key(ByColumnName::new("id"))
+            .build()
+            .with(Style::psql())
+            .to_string()
+    },
+    " id | name  "
+    "----+-------"
+    " 0  | \u{1b}[33ma → c\u{1b}[39m "
+    " \u{1b}[31m1\u{1b}[39m  | \u{1b}[31mb\u{1b}[39m     "
+    " \u{1b}[32m2\u{1b}[39m  | \u{1b}[32md\u{1b}[39m     "
+);
+
+test_table!(
+    diff_colored_columns,
+    {
+        let old = table(&[&["name", "size"], &["a", "1"]]);
+        let new = table(&[&["name", "kind"], &["a", "file"]]);
+        TableDiff::new(&old, &new).build().with(Style::psql()).to_string()
+    },
+    " name | \u{1b}[31msize\u{1b}[39m | \u{1b}[32mkind\u{1b}[39m "
+    "------+------+------"
+    " a    | 1    | file "
+);
+
+#[test]
+fn diff_colors_match_owo_colors() {
+    let old = table(&[&["name"], &["a"]]);
+    let new = table(&[&["name"], &["b"], &["c"]]);
+    let table = TableDiff::new(&old, &new).build().to_string();
+
+    assert!(table.contains(&"a → b".yellow().to_string()));
+    assert!(table.contains(&"c".green().to_string()));
+}
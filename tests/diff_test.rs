@@ -0,0 +1,136 @@
+#![cfg(not(feature = "color"))]
+
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, locator::ByColumnName, Style, Table, TableDiff};
+
+use crate::util::test_table;
+
+mod util;
+
+fn table(data: &[&[&'static str]]) -> Table {
+    Builder::from_iter(data.iter().map(|row| row.iter().copied())).build()
+}
+
+fn diff(old: &[&[&'static str]], new: &[&[&'static str]]) -> Table {
+    let (old, new) = (table(old), table(new));
+    let mut table = TableDiff::new(&old, &new).build();
+    table.with(Style::psql());
+    table
+}
+
+fn diff_by_name(old: &[&[&'static str]], new: &[&[&'static str]]) -> Table {
+    let (old, new) = (table(old), table(new));
+    let mut table = TableDiff::new(&old, &new)
+        .key(ByColumnName::new("name"))
+        .build();
+    table.with(Style::psql());
+    table
+}
+
+test_table!(
+    diff_equal,
+    diff(&[&["name", "size"], &["a", "1"]], &[&["name", "size"], &["a", "1"]]),
+    "  | name | size "
+    "--+------+------"
+    "  | a    | 1    "
+);
+
+test_table!(
+    diff_changed_cell,
+    diff(
+        &[&["name", "size"], &["a", "1"], &["b", "2"]],
+        &[&["name", "size"], &["a", "1"], &["b", "3"]]
+    ),
+    "   | name | size  "
+    "---+------+-------"
+    "   | a    | 1     "
+    " ~ | b    | 2 → 3 "
+);
+
+test_table!(
+    diff_rows_by_position,
+    diff(
+        &[&["name", "size"], &["a", "1"], &["b", "2"], &["c", "3"]],
+        &[&["name", "size"], &["b", "2"]]
+    ),
+    "   | name  | size  "
+    "---+-------+-------"
+    " ~ | a → b | 1 → 2 "
+    " - | b     | 2     "
+    " - | c     | 3     "
+);
+
+test_table!(
+    diff_added_rows_by_position,
+    diff(&[&["name"], &["a"]], &[&["name"], &["a"], &["b"]]),
+    "   | name "
+    "---+------"
+    "   | a    "
+    " + | b    "
+);
+
+test_table!(
+    diff_rows_by_key,
+    diff_by_name(
+        &[&["name", "size"], &["a", "1"], &["b", "2"], &["c", "3"]],
+        &[&["name", "size"], &["d", "4"], &["c", "30"], &["a", "1"]]
+    ),
+    "   | name | size   "
+    "---+------+--------"
+    " + | d    | 4      "
+    " ~ | c    | 3 → 30 "
+    "   | a    | 1      "
+    " - | b    | 2      "
+);
+
+test_table!(
+    diff_rows_by_duplicate_keys,
+    diff_by_name(
+        &[&["name", "size"], &["a", "1"], &["a", "2"]],
+        &[&["name", "size"], &["a", "1"], &["a", "2"], &["a", "3"]]
+    ),
+    "   | name | size "
+    "---+------+------"
+    "   | a    | 1    "
+    "   | a    | 2    "
+    " + | a    | 3    "
+);
+
+test_table!(
+    diff_columns,
+    diff(
+        &[&["name", "size", "kind"], &["a", "1", "file"]],
+        &[&["kind", "name", "date"], &["dir", "a", "today"]]
+    ),
+    "   | kind       | name | - size | + date "
+    "---+------------+------+--------+--------"
+    " ~ | file → dir | a    | 1      | today  "
+);
+
+test_table!(
+    diff_missing_key_falls_back_to_position,
+    {
+        let old = table(&[&["id"], &["1"], &["2"]]);
+        let new = table(&[&["id"], &["2"]]);
+        TableDiff::new(&old, &new)
+            .key(ByColumnName::new("missing"))
+            .build()
+            .with(Style::psql())
+            .to_string()
+    },
+    "   | id    "
+    "---+-------"
+    " ~ | 1 → 2 "
+    " - | 2     "
+);
+
+test_table!(diff_empty_tables, diff(&[], &[]), "");
+
+test_table!(
+    diff_from_empty_table,
+    diff(&[], &[&["name"], &["a"]]),
+    "   | + name "
+    "---+--------"
+    " + | a      "
+);
@@ -0,0 +1,160 @@
+use std::collections::{BTreeMap, HashMap};
+
+use tabled::{Style, Table};
+
+use crate::util::test_table;
+
+mod util;
+
+test_table!(
+    dynamic_maps,
+    Table::from_dynamic([
+        BTreeMap::from([("cpu", "12%"), ("mem", "1.2G")]),
+        BTreeMap::from([("cpu", "7%"), ("disk", "40G")]),
+        BTreeMap::from([("net", "1Mb/s")]),
+    ]),
+    "+-----+------+------+-------+"
+    "| cpu | mem  | disk | net   |"
+    "+-----+------+------+-------+"
+    "| 12% | 1.2G |      |       |"
+    "+-----+------+------+-------+"
+    "| 7%  |      | 40G  |       |"
+    "+-----+------+------+-------+"
+    "|     |      |      | 1Mb/s |"
+    "+-----+------+------+-------+"
+);
+
+test_table!(
+    dynamic_maps_new_keys_are_put_before_known_ones,
+    Table::from_dynamic([
+        BTreeMap::from([("a", 1), ("d", 4)]),
+        BTreeMap::from([("b", 2), ("c", 3), ("d", 4), ("e", 5)]),
+    ]),
+    "+---+---+---+---+---+"
+    "| a | b | c | d | e |"
+    "+---+---+---+---+---+"
+    "| 1 |   |   | 4 |   |"
+    "+---+---+---+---+---+"
+    "|   | 2 | 3 | 4 | 5 |"
+    "+---+---+---+---+---+"
+);
+
+test_table!(
+    dynamic_hash_maps_are_sorted,
+    Table::from_dynamic([HashMap::from([("c", 3), ("a", 1), ("b", 2), ("e", 5), ("d", 4)])]),
+    "+---+---+---+---+---+"
+    "| a | b | c | d | e |"
+    "+---+---+---+---+---+"
+    "| 1 | 2 | 3 | 4 | 5 |"
+    "+---+---+---+---+---+"
+);
+
+test_table!(
+    dynamic_maps_by_reference,
+    Table::from_dynamic(&[BTreeMap::from([(1, 'a'), (2, 'b')])]).with(Style::psql()),
+    " 1 | 2 "
+    "---+---"
+    " a | b "
+);
+
+test_table!(
+    dynamic_empty,
+    Table::from_dynamic(Vec::<BTreeMap<String, String>>::new()),
+    ""
+);
+
+#[cfg(feature = "derive")]
+mod derived {
+    use super::*;
+
+    use tabled::Tabled;
+
+    test_table!(
+        flatten_map,
+        Table::from_dynamic({
+            #[derive(Tabled)]
+            struct Host {
+                name: &'static str,
+                #[tabled(flatten_map)]
+                metrics: BTreeMap<&'static str, f32>,
+                #[tabled(rename = "up")]
+                is_up: bool,
+            }
+
+            [
+                Host {
+                    name: "alpha",
+                    metrics: BTreeMap::from([("cpu", 0.5), ("mem", 0.25)]),
+                    is_up: true,
+                },
+                Host {
+                    name: "beta",
+                    metrics: BTreeMap::from([("cpu", 0.75), ("disk", 0.9)]),
+                    is_up: false,
+                },
+            ]
+        }),
+        "+-------+------+------+------+-------+"
+        "| name  | cpu  | mem  | disk | up    |"
+        "+-------+------+------+------+-------+"
+        "| alpha | 0.5  | 0.25 |      | true  |"
+        "+-------+------+------+------+-------+"
+        "| beta  | 0.75 |      | 0.9  | false |"
+        "+-------+------+------+------+-------+"
+    );
+
+    test_table!(
+        flatten_map_with_order,
+        Table::from_dynamic({
+            #[derive(Tabled)]
+            struct Host {
+                name: &'static str,
+                #[tabled(flatten_map)]
+                metrics: BTreeMap<&'static str, u8>,
+                #[tabled(order = 0)]
+                id: u8,
+            }
+
+            [Host {
+                name: "alpha",
+                metrics: BTreeMap::from([("cpu", 1)]),
+                id: 0,
+            }]
+        }),
+        "+----+-------+-----+"
+        "| id | name  | cpu |"
+        "+----+-------+-----+"
+        "| 0  | alpha | 1   |"
+        "+----+-------+-----+"
+    );
+
+    test_table!(
+        flatten_hash_map,
+        Table::from_dynamic({
+            #[derive(Tabled)]
+            struct Host {
+                name: &'static str,
+                #[tabled(flatten_map)]
+                metrics: HashMap<&'static str, u8>,
+            }
+
+            [
+                Host {
+                    name: "alpha",
+                    metrics: HashMap::from([("net", 1), ("gpu", 2), ("mem", 3), ("cpu", 4)]),
+                },
+                Host {
+                    name: "beta",
+                    metrics: HashMap::from([("disk", 5), ("cpu", 6)]),
+                },
+            ]
+        }),
+        "+-------+-----+-----+-----+-----+------+"
+        "| name  | cpu | gpu | mem | net | disk |"
+        "+-------+-----+-----+-----+-----+------+"
+        "| alpha | 4   | 2   | 3   | 1   |      |"
+        "+-------+-----+-----+-----+-----+------+"
+        "| beta  | 6   |     |     |     | 5    |"
+        "+-------+-----+-----+-----+-----+------+"
+    );
+}
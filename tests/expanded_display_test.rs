@@ -489,3 +489,36 @@ fn display_with_truncate_colored() {
         )
     );
 }
+
+#[cfg(feature = "derive")]
+#[test]
+fn display_expanded_structure() {
+    #[derive(Tabled)]
+    struct Item {
+        id: u8,
+    }
+
+    #[derive(Tabled)]
+    struct Order {
+        customer: &'static str,
+        #[tabled(expand)]
+        items: Vec<Item>,
+    }
+
+    let data = [Order {
+        customer: "alice",
+        items: vec![Item { id: 1 }, Item { id: 2 }],
+    }];
+
+    assert_expanded_display!(
+        &data,
+        static_table!(
+            "-[ RECORD 0 ]---"
+            "customer | alice"
+            "id       | 1"
+            "-[ RECORD 1 ]---"
+            "customer | alice"
+            "id       | 2"
+        )
+    );
+}
@@ -0,0 +1,141 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    locator::ByColumnName,
+    object::{Cell, Columns},
+    Filter, Modify, Panel, Span, Style, Table,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn table(data: &[[&'static str; 3]]) -> Table {
+    let mut table = Builder::from_iter(data.iter().copied()).build();
+    table.with(Style::psql());
+    table
+}
+
+const DATA: [[&str; 3]; 5] = [
+    ["service", "status", "uptime"],
+    ["api", "ok", "99.9"],
+    ["db", "down", "95.1"],
+    ["cache", "ok", "99.99"],
+    ["queue", "degraded", "98.0"],
+];
+
+test_table!(
+    filter_rows,
+    table(&DATA).with(Filter::rows(|row| row[1] == "ok")),
+    " service | status | uptime "
+    "---------+--------+--------"
+    " api     | ok     | 99.9   "
+    " cache   | ok     | 99.99  "
+);
+
+test_table!(
+    filter_column_by_name,
+    table(&DATA).with(Filter::column(ByColumnName::new("status"), |status| status != "ok")),
+    " service | status   | uptime "
+    "---------+----------+--------"
+    " db      | down     | 95.1   "
+    " queue   | degraded | 98.0   "
+);
+
+test_table!(
+    filter_column_by_index,
+    table(&DATA).with(Filter::column(Columns::single(2), |uptime| uptime.starts_with("99"))),
+    " service | status | uptime "
+    "---------+--------+--------"
+    " api     | ok     | 99.9   "
+    " cache   | ok     | 99.99  "
+);
+
+test_table!(
+    filter_several_columns_must_all_match,
+    table(&DATA).with(Filter::column(Columns::new(..2), |text| text.len() > 2)),
+    " service | status   | uptime "
+    "---------+----------+--------"
+    " queue   | degraded | 98.0   "
+);
+
+test_table!(
+    filter_all_rows,
+    table(&DATA).with(Filter::rows(|_| false)),
+    " service | status | uptime "
+    "---------+--------+--------"
+);
+
+test_table!(
+    filter_without_header,
+    create_table::<3, 1>()
+        .with(Style::psql())
+        .with(Filter::rows(|row| row[0] != "N" && row[0] != "1").header(0)),
+    " 0 | 0-0 "
+    "---+-----"
+    " 2 | 2-0 "
+);
+
+test_table!(
+    filter_by_missing_column_keeps_rows,
+    table(&DATA[..2]).with(Filter::column(ByColumnName::new("missing"), |_| false)),
+    " service | status | uptime "
+    "---------+--------+--------"
+    " api     | ok     | 99.9   "
+);
+
+test_table!(
+    filter_keeps_panels,
+    Table::new([1, 2, 3, 4])
+        .with(Panel::header("Numbers"))
+        .with(Style::psql())
+        .with(Filter::rows(|row| row[0] != "2" && row[0] != "3").header(2)),
+    " Numbers "
+    "---------"
+    " i32     "
+    " 1       "
+    " 4       "
+);
+
+test_table!(
+    filter_shifts_column_span,
+    table(&DATA)
+        .with(Modify::new(Cell(4, 0)).with(Span::column(2)))
+        .with(Filter::rows(|row| row[1] != "down")),
+    " service | status | uptime "
+    "---------+--------+--------"
+    " api     | ok     | 99.9   "
+    " cache   | ok     | 99.99  "
+    " queue            | 98.0   "
+);
+
+test_table!(
+    filter_shrinks_row_span,
+    table(&DATA)
+        .with(Modify::new(Cell(1, 2)).with(Span::row(3)))
+        .with(Filter::rows(|row| row[0] != "db")),
+    " service | status   | uptime "
+    "---------+----------+--------"
+    " api     | ok       | 99.9   "
+    " cache   | ok       |        "
+    " queue   | degraded | 98.0   "
+);
+
+test_table!(
+    filter_moves_row_span_content,
+    table(&DATA)
+        .with(Modify::new(Cell(1, 2)).with(Span::row(3)))
+        .with(Filter::rows(|row| row[0] != "api")),
+    " service | status   | uptime "
+    "---------+----------+--------"
+    " db      | down     | 99.9   "
+    " cache   | ok       |        "
+    " queue   | degraded | 98.0   "
+);
+
+test_table!(
+    filter_empty_table,
+    Builder::default().build().with(Filter::rows(|_| false)),
+    ""
+);
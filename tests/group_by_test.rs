@@ -0,0 +1,221 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    locator::ByColumnName,
+    object::{Cell, Columns},
+    style::Line,
+    Alignment, GroupBy, Modify, Style, Summary, Table,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn table(data: &[[&'static str; 3]]) -> Table {
+    let mut table = Builder::from_iter(data.iter().copied()).build();
+    table.with(Style::psql());
+    table
+}
+
+const DATA: [[&str; 3]; 6] = [
+    ["service", "region", "cost"],
+    ["api", "eu", "10"],
+    ["db", "us", "30"],
+    ["cache", "eu", "5"],
+    ["queue", "asia", "1.5"],
+    ["dns", "us", "2"],
+];
+
+test_table!(
+    group_by_column_name,
+    table(&DATA).with(GroupBy::column(ByColumnName::new("region"))),
+    " service | cost "
+    "---------+------"
+    " eu             "
+    " api     | 10   "
+    " cache   | 5    "
+    " us             "
+    " db      | 30   "
+    " dns     | 2    "
+    " asia           "
+    " queue   | 1.5  "
+);
+
+test_table!(
+    group_by_last_column,
+    table(&DATA[..4]).with(GroupBy::column(Columns::last())),
+    " service | region "
+    "---------+--------"
+    " 10               "
+    " api     | eu     "
+    " 30               "
+    " db      | us     "
+    " 5                "
+    " cache   | eu     "
+);
+
+test_table!(
+    group_by_subtotal,
+    table(&DATA).with(
+        GroupBy::column(Columns::single(1))
+            .subtotal(Summary::new().label(0, "total").sum(ByColumnName::new("cost")))
+    ),
+    " service | cost "
+    "---------+------"
+    " eu             "
+    " api     | 10   "
+    " cache   | 5    "
+    " total   | 15   "
+    " us             "
+    " db      | 30   "
+    " dns     | 2    "
+    " total   | 32   "
+    " asia           "
+    " queue   | 1.5  "
+    " total   | 1.5  "
+);
+
+test_table!(
+    group_by_several_subtotal_rows,
+    table(&DATA[..4]).with(
+        GroupBy::column(Columns::single(1)).subtotal(
+            Summary::new()
+                .count(Columns::first())
+                .row()
+                .max(Columns::last())
+        )
+    ),
+    " service | cost "
+    "---------+------"
+    " eu             "
+    " api     | 10   "
+    " cache   | 5    "
+    " 2       |      "
+    "         | 10   "
+    " us             "
+    " db      | 30   "
+    " 1       |      "
+    "         | 30   "
+);
+
+test_table!(
+    group_by_line,
+    table(&DATA).with(
+        GroupBy::column(Columns::single(1))
+            .line(Line::new(Some('='), Some('='), None, None))
+    ),
+    " service | cost "
+    "---------+------"
+    " eu             "
+    " api     | 10   "
+    " cache   | 5    "
+    "================"
+    " us             "
+    " db      | 30   "
+    " dns     | 2    "
+    "================"
+    " asia           "
+    " queue   | 1.5  "
+);
+
+test_table!(
+    group_by_subtotal_line,
+    table(&DATA[..3]).with(
+        GroupBy::column(Columns::single(1))
+            .subtotal(
+                Summary::new()
+                    .sum(Columns::last())
+                    .line(Line::new(Some('.'), Some('.'), None, None))
+            )
+            .line(Line::new(Some('='), Some('='), None, None))
+    ),
+    " service | cost "
+    "---------+------"
+    " eu             "
+    " api     | 10   "
+    "................"
+    "         | 10   "
+    "================"
+    " us             "
+    " db      | 30   "
+    "................"
+    "         | 30   "
+);
+
+test_table!(
+    group_by_without_header,
+    create_table::<3, 1>()
+        .with(Style::psql())
+        .with(GroupBy::column(Columns::single(1)).header(0)),
+    " column 0 "
+    "----------"
+    "    N     "
+    "   0-0    "
+    "    0     "
+    "   1-0    "
+    "    1     "
+    "   2-0    "
+    "    2     "
+);
+
+test_table!(
+    group_by_single_column,
+    Table::new([1, 2, 1]).with(GroupBy::column(Columns::first())),
+    "+-----+"
+    "| i32 |"
+    "+-----+"
+    "| 1   |"
+    "+-----+"
+    "| 1   |"
+    "+-----+"
+    "| 1   |"
+    "+-----+"
+    "| 2   |"
+    "+-----+"
+    "| 2   |"
+    "+-----+"
+);
+
+test_table!(
+    group_by_keeps_column_settings,
+    table(&DATA[..3])
+        .with(Modify::new(Columns::last()).with(Alignment::right()))
+        .with(GroupBy::column(Columns::single(1))),
+    " service | cost "
+    "---------+------"
+    " eu             "
+    " api     |   10 "
+    " us             "
+    " db      |   30 "
+);
+
+test_table!(
+    group_by_centered_panels,
+    table(&DATA[..3])
+        .with(GroupBy::column(Columns::single(1)))
+        .with(Modify::new(Cell(1, 0)).with(Alignment::center()))
+        .with(Modify::new(Cell(3, 0)).with(Alignment::center())),
+    " service | cost "
+    "---------+------"
+    "       eu       "
+    " api     | 10   "
+    "       us       "
+    " db      | 30   "
+);
+
+test_table!(
+    group_by_missing_column,
+    table(&DATA[..2]).with(GroupBy::column(ByColumnName::new("missing"))),
+    " service | region | cost "
+    "---------+--------+------"
+    " api     | eu     | 10   "
+);
+
+test_table!(
+    group_by_empty_table,
+    Builder::default()
+        .build()
+        .with(GroupBy::column(Columns::first())),
+    ""
+);
@@ -0,0 +1,126 @@
+use tabled::{
+    object::{Cell, Columns, Rows, Segment},
+    papergrid::records::Records,
+    records::TabledRecords,
+    Alignment, Margin, Modify, Padding, Span, Style, Table,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+const DATA: [(usize, &str, &str); 3] = [
+    (0, "Hello", "1.23"),
+    (1, "World", "3.21"),
+    (2, "Multiline\nstring", "0"),
+];
+
+test_table!(
+    lazy_table,
+    Table::lazy(&DATA),
+    "+-------+-----------+------+"
+    "| usize | &str      | &str |"
+    "+-------+-----------+------+"
+    "| 0     | Hello     | 1.23 |"
+    "+-------+-----------+------+"
+    "| 1     | World     | 3.21 |"
+    "+-------+-----------+------+"
+    "| 2     | Multiline | 0    |"
+    "|       | string    |      |"
+    "+-------+-----------+------+"
+);
+
+test_table!(
+    lazy_table_empty,
+    Table::lazy(&[] as &[(usize, &str)]),
+    "+-------+------+"
+    "| usize | &str |"
+    "+-------+------+"
+);
+
+test_table!(
+    lazy_table_settings,
+    Table::lazy(&DATA)
+        .with(Style::psql())
+        .with(Padding::new(0, 1, 0, 0))
+        .with(Margin::new(1, 0, 0, 0))
+        .with(Modify::new(Columns::single(0)).with(Alignment::right()))
+        .with(Modify::new(Rows::first()).with(Alignment::center())),
+    " usize |  &str    |&str "
+    " ------+----------+-----"
+    "     0 |Hello     |1.23 "
+    "     1 |World     |3.21 "
+    "     2 |Multiline |0    "
+    "       |string    |     "
+);
+
+test_table!(
+    lazy_table_span,
+    Table::lazy(&DATA)
+        .with(Style::modern())
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2))),
+    "┌───────┬───────────┬──────┐"
+    "│ usize │ &str      │ &str │"
+    "├───────┼───────────┼──────┤"
+    "│ 0                 │ 1.23 │"
+    "├───────┼───────────┼──────┤"
+    "│ 1     │ World     │ 3.21 │"
+    "├───────┼───────────┼──────┤"
+    "│ 2     │ Multiline │ 0    │"
+    "│       │ string    │      │"
+    "└───────┴───────────┴──────┘"
+);
+
+#[test]
+fn lazy_table_matches_table() {
+    let styles = [
+        Style::ascii().into(),
+        Style::psql().into(),
+        Style::modern().into(),
+    ];
+
+    for style in styles {
+        let style: tabled::style::RawStyle = style;
+
+        let mut table = Table::new(DATA);
+        table.with(style.clone());
+
+        let mut lazy = Table::lazy(&DATA);
+        lazy.with(style);
+
+        assert_eq!(lazy.to_string(), table.to_string());
+        assert_eq!(lazy.total_width(), table.total_width());
+        assert_eq!(lazy.total_height(), table.total_height());
+    }
+}
+
+#[test]
+fn lazy_table_padding_matches_table() {
+    let mut table = Table::new(DATA);
+    table
+        .with(Style::modern())
+        .with(Modify::new(Segment::all()).with(Padding::new(3, 3, 1, 0)));
+
+    let mut lazy = Table::lazy(&DATA);
+    lazy.with(Style::modern())
+        .with(Modify::new(Segment::all()).with(Padding::new(3, 3, 1, 0)));
+
+    assert_eq!(lazy.to_string(), table.to_string());
+    assert_eq!(lazy.total_width(), table.total_width());
+    assert_eq!(lazy.total_height(), table.total_height());
+}
+
+#[test]
+fn tabled_records() {
+    let records = TabledRecords::new(&DATA);
+
+    assert_eq!(records.count_rows(), 4);
+    assert_eq!(records.count_columns(), 3);
+    assert_eq!(records.get_text((0, 0)), "usize");
+    assert_eq!(records.get_text((3, 1)), "Multiline\nstring");
+    assert_eq!(records.get_line((3, 1), 1), "string");
+    assert_eq!(records.count_lines((3, 1)), 2);
+    assert_eq!(records.get_text((1, 1)), "Hello");
+    assert_eq!(records.get_text((2, 2)), "3.21");
+    assert_eq!(records.as_slice().len(), 3);
+}
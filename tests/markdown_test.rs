@@ -0,0 +1,142 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    object::{Cell, Columns, Rows, Segment},
+    Alignment, Modify, ModifyObject, Span, Style, Table,
+};
+
+use crate::util::create_table;
+
+mod util;
+
+#[test]
+fn to_markdown_default_alignment() {
+    let table = Table::new([("tabled", 1), ("papergrid", 20)]);
+
+    assert_eq!(
+        table.to_markdown(),
+        "| &str      | i32 |\n\
+         |-----------|-----|\n\
+         | tabled    | 1   |\n\
+         | papergrid | 20  |"
+    );
+}
+
+#[test]
+fn to_markdown_column_alignment() {
+    let mut table = Builder::from_iter([["left", "center", "right"], ["a", "b", "c"]]).build();
+    table
+        .with(Columns::single(1).modify().with(Alignment::center()))
+        .with(Columns::single(2).modify().with(Alignment::right()));
+
+    assert_eq!(
+        table.to_markdown(),
+        "| left | center | right |\n\
+         |------|:------:|------:|\n\
+         | a    |   b    |     c |"
+    );
+}
+
+#[test]
+fn to_markdown_explicit_left_alignment() {
+    let mut table = Builder::from_iter([["left", "default"], ["a", "b"]]).build();
+    table.with(Columns::single(0).modify().with(Alignment::left()));
+
+    assert_eq!(
+        table.to_markdown(),
+        "| left | default |\n\
+         |:-----|---------|\n\
+         | a    | b       |"
+    );
+}
+
+#[test]
+fn to_markdown_ignores_row_alignment() {
+    let mut table = Builder::from_iter([["name"], ["tabled"]]).build();
+    table.with(Rows::first().modify().with(Alignment::right()));
+
+    assert_eq!(
+        table.to_markdown(),
+        "| name   |\n\
+         |--------|\n\
+         | tabled |"
+    );
+}
+
+#[test]
+fn to_markdown_global_alignment() {
+    let mut table = Builder::from_iter([["name"], ["tabled"]]).build();
+    table.with(Modify::new(Segment::all()).with(Alignment::center()));
+
+    assert_eq!(
+        table.to_markdown(),
+        "|  name  |\n\
+         |:------:|\n\
+         | tabled |"
+    );
+}
+
+#[test]
+fn to_markdown_escapes_text() {
+    let table = Builder::from_iter([
+        ["pattern", "path"],
+        ["a|b", "C:\\tmp"],
+        ["first\nsecond", "\u{1b}[31mred\u{1b}[39m"],
+    ])
+    .build();
+
+    assert_eq!(
+        table.to_markdown(),
+        "| pattern         | path    |\n\
+         |-----------------|---------|\n\
+         | a\\|b            | C:\\\\tmp |\n\
+         | first<br>second | red     |"
+    );
+}
+
+#[test]
+fn to_markdown_spans() {
+    let mut table = create_table::<2, 2>();
+    table.with(Modify::new(Cell(1, 0)).with(Span::column(3)));
+
+    assert_eq!(
+        table.to_markdown(),
+        "| N | column 0 | column 1 |\n\
+         |:-:|:--------:|:--------:|\n\
+         | 0 |          |          |\n\
+         | 1 |   1-0    |   1-1    |"
+    );
+}
+
+#[test]
+fn to_markdown_wide_chars() {
+    let table = Builder::from_iter([["name"], ["表格"]]).build();
+
+    assert_eq!(
+        table.to_markdown(),
+        "| name |\n\
+         |------|\n\
+         | 表格 |"
+    );
+}
+
+#[test]
+fn to_markdown_header_only() {
+    let table = Builder::from_iter([["", "id"]]).build();
+
+    assert_eq!(table.to_markdown(), "|   | id |\n|---|----|");
+}
+
+#[test]
+fn to_markdown_empty() {
+    assert_eq!(Builder::default().build().to_markdown(), "");
+}
+
+#[test]
+fn to_markdown_is_not_affected_by_style() {
+    let mut table = Builder::from_iter([["id"], ["0"]]).build();
+    table.with(Style::modern());
+
+    assert_eq!(table.to_markdown(), "| id |\n|----|\n| 0  |");
+}
@@ -0,0 +1,263 @@
+use std::iter::FromIterator;
+
+use tabled::{builder::Builder, object::Cell, Modify, Pagination, Panel, Span, Style, Table};
+
+use crate::util::{create_table, static_table};
+
+mod util;
+
+fn pages<R>(pages: Vec<Table<R>>) -> Vec<String>
+where
+    R: tabled::papergrid::records::Records,
+{
+    pages.iter().map(|page| page.to_string()).collect()
+}
+
+#[test]
+fn paginate_default_style() {
+    let table = create_table::<3, 3>();
+
+    assert_eq!(
+        pages(table.paginate(7)),
+        [
+            static_table!(
+                "+---+----------+----------+----------+"
+                "| N | column 0 | column 1 | column 2 |"
+                "+---+----------+----------+----------+"
+                "| 0 |   0-0    |   0-1    |   0-2    |"
+                "+---+----------+----------+----------+"
+                "| 1 |   1-0    |   1-1    |   1-2    |"
+                "+---+----------+----------+----------+"
+            ),
+            static_table!(
+                "+---+----------+----------+----------+"
+                "| N | column 0 | column 1 | column 2 |"
+                "+---+----------+----------+----------+"
+                "| 2 |   2-0    |   2-1    |   2-2    |"
+                "+---+----------+----------+----------+"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_psql_style() {
+    let mut table = create_table::<5, 2>();
+    table.with(Style::psql());
+
+    assert_eq!(
+        pages(table.paginate(4)),
+        [
+            static_table!(
+                " N | column 0 | column 1 "
+                "---+----------+----------"
+                " 0 |   0-0    |   0-1    "
+                " 1 |   1-0    |   1-1    "
+            ),
+            static_table!(
+                " N | column 0 | column 1 "
+                "---+----------+----------"
+                " 2 |   2-0    |   2-1    "
+                " 3 |   3-0    |   3-1    "
+            ),
+            static_table!(
+                " N | column 0 | column 1 "
+                "---+----------+----------"
+                " 4 |   4-0    |   4-1    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_keeps_columns_widths() {
+    let mut table = Builder::from_iter([["id", "name"], ["0", "a"], ["1", "long name"]]).build();
+    table.with(Style::markdown());
+
+    assert_eq!(
+        pages(table.paginate(3)),
+        [
+            static_table!(
+                "| id | name      |"
+                "|----|-----------|"
+                "| 0  | a         |"
+            ),
+            static_table!(
+                "| id | name      |"
+                "|----|-----------|"
+                "| 1  | long name |"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_multiline_row_is_not_split() {
+    let table = Builder::from_iter([["id", "name"], ["0", "a\nb\nc"], ["1", "x"]]).build();
+
+    assert_eq!(
+        pages(table.paginate(8)),
+        [
+            static_table!(
+                "+----+------+"
+                "| id | name |"
+                "+----+------+"
+                "| 0  | a    |"
+                "|    | b    |"
+                "|    | c    |"
+                "+----+------+"
+            ),
+            static_table!(
+                "+----+------+"
+                "| id | name |"
+                "+----+------+"
+                "| 1  | x    |"
+                "+----+------+"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_row_span_is_not_split() {
+    let mut table = Builder::from_iter([
+        ["id", "name"],
+        ["0", "a"],
+        ["1", "x"],
+        ["2", "y"],
+        ["3", "z"],
+    ])
+    .build();
+    table.with(Modify::new(Cell(2, 0)).with(Span::row(2)));
+
+    assert_eq!(
+        pages(table.paginate(7)),
+        [
+            static_table!(
+                "+----+------+"
+                "| id | name |"
+                "+----+------+"
+                "| 0  | a    |"
+                "+----+------+"
+            ),
+            static_table!(
+                "+----+------+"
+                "| id | name |"
+                "+----+------+"
+                "| 1  | x    |"
+                "+    +------+"
+                "|    | y    |"
+                "+----+------+"
+            ),
+            static_table!(
+                "+----+------+"
+                "| id | name |"
+                "+----+------+"
+                "| 3  | z    |"
+                "+----+------+"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_repeats_panels() {
+    let mut table = Table::new([1, 2, 3]);
+    table
+        .with(Style::modern())
+        .with(Panel::header("Numbers"))
+        .with(Panel::footer("End"));
+
+    let output = Pagination::new(11).header(2).footer(1).paginate(&table);
+
+    assert_eq!(
+        pages(output),
+        [
+            static_table!(
+                "┌─────────┐"
+                "│ Numbers │"
+                "├─────────┤"
+                "│ i32     │"
+                "├─────────┤"
+                "│ 1       │"
+                "├─────────┤"
+                "│ 2       │"
+                "├─────────┤"
+                "│ End     │"
+                "└─────────┘"
+            ),
+            static_table!(
+                "┌─────────┐"
+                "│ Numbers │"
+                "├─────────┤"
+                "│ i32     │"
+                "├─────────┤"
+                "│ 3       │"
+                "├─────────┤"
+                "│ End     │"
+                "└─────────┘"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_without_header() {
+    let mut table = create_table::<3, 1>();
+    table.with(Style::blank());
+
+    let output = Pagination::new(2).header(0).paginate(&table);
+
+    assert_eq!(
+        pages(output),
+        [
+            static_table!(
+                " N   column 0 "
+                " 0     0-0    "
+            ),
+            static_table!(
+                " 1     1-0    "
+                " 2     2-0    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_small_height_puts_a_row_per_page() {
+    let mut table = create_table::<2, 1>();
+    table.with(Style::psql());
+
+    assert_eq!(
+        pages(table.paginate(0)),
+        [
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 0 |   0-0    "
+            ),
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 1 |   1-0    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn paginate_fitting_table_is_a_single_page() {
+    let table = create_table::<3, 3>();
+    let output = table.paginate(table.total_height());
+
+    assert_eq!(pages(output), [table.to_string()]);
+}
+
+#[test]
+fn paginate_empty_table() {
+    let table = Builder::default().build();
+    assert_eq!(pages(table.paginate(10)), [table.to_string()]);
+
+    let table = create_table::<0, 3>();
+    assert_eq!(pages(table.paginate(1)), [table.to_string()]);
+}
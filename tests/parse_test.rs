@@ -0,0 +1,181 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder, object::Cell, parse::Rendered, style::RawStyle, Modify, Span, Style, Table,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn create_rows() -> Vec<Vec<String>> {
+    let mut rows = vec![vec!["N", "column 0", "column 1", "column 2"]
+        .into_iter()
+        .map(String::from)
+        .collect::<Vec<_>>()];
+    for row in 0..3 {
+        let mut cells = vec![row.to_string()];
+        cells.extend((0..3).map(|col| format!("{}-{}", row, col)));
+        rows.push(cells);
+    }
+
+    rows
+}
+
+fn assert_round_trip<S>(style: S)
+where
+    S: Into<RawStyle> + Clone,
+{
+    let style = style.into();
+    let text = create_table::<3, 3>().with(&style).to_string();
+
+    let rendered = Rendered::parse(&text, style);
+    assert_eq!(rendered.get_rows(), create_rows(), "{}", text);
+    assert!(rendered.get_spans().is_empty());
+}
+
+#[test]
+fn parse_style_presets() {
+    assert_round_trip(Style::ascii());
+    assert_round_trip(Style::psql());
+    assert_round_trip(Style::markdown());
+    assert_round_trip(Style::modern());
+    assert_round_trip(Style::rounded());
+    assert_round_trip(Style::extended());
+    assert_round_trip(Style::dots());
+    assert_round_trip(Style::re_structured_text());
+    assert_round_trip(Style::ascii_rounded());
+    assert_round_trip(Style::blank());
+}
+
+#[test]
+fn parse_multiline_cells() {
+    let data = [["a\nb", "c"], ["d", "e\n\nf"]];
+    for style in [
+        RawStyle::from(Style::ascii()),
+        RawStyle::from(Style::modern()),
+    ] {
+        let text = Builder::from_iter(data).build().with(&style).to_string();
+        let rendered = Rendered::parse(&text, style);
+        assert_eq!(rendered.get_rows(), data, "{}", text);
+    }
+}
+
+#[test]
+fn parse_multiline_cells_without_lines() {
+    let text = Builder::from_iter([["a\nb", "c"]])
+        .build()
+        .with(Style::psql())
+        .to_string();
+
+    let rendered = Rendered::parse(&text, Style::psql());
+    assert_eq!(rendered.get_rows(), [["a", "c"], ["b", ""]]);
+}
+
+#[test]
+fn parse_column_span() {
+    let text = create_table::<3, 3>()
+        .with(Modify::new(Cell(1, 1)).with(Span::column(2)))
+        .with(Modify::new(Cell(3, 0)).with(Span::column(4)))
+        .with(Style::modern())
+        .to_string();
+
+    let rendered = Rendered::parse(&text, Style::modern());
+    assert_eq!(rendered.get_spans(), [((1, 1), 2), ((3, 0), 4)]);
+    assert_eq!(
+        rendered.get_rows(),
+        [
+            ["N", "column 0", "column 1", "column 2"],
+            ["0", "0-0", "", "0-2"],
+            ["1", "1-0", "1-1", "1-2"],
+            ["2", "", "", ""],
+        ]
+    );
+}
+
+#[test]
+fn parse_wide_characters() {
+    let data = [["名前", "値"], ["テーブル", "1"]];
+    let text = Builder::from_iter(data)
+        .build()
+        .with(Style::modern())
+        .to_string();
+
+    let rendered = Rendered::parse(&text, Style::modern());
+    assert_eq!(rendered.get_rows(), data);
+}
+
+#[test]
+fn parse_empty() {
+    assert_eq!(Rendered::parse("", Style::ascii()), Rendered::default());
+}
+
+test_table!(
+    parse_build_keeps_spans,
+    Rendered::parse(
+        &create_table::<2, 2>()
+            .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+            .with(Style::ascii())
+            .to_string(),
+        Style::ascii(),
+    )
+    .build()
+    .with(Style::psql()),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0            | 0-1      "
+    " 1 | 1-0      | 1-1      "
+);
+
+test_table!(
+    from_rendered_markdown_document,
+    Builder::from_rendered(
+        "| Name | Language |\n\
+         |------|----------|\n\
+         | tabled | Rust |\n\
+         | psql | C |",
+        Style::markdown(),
+    )
+    .build()
+    .with(Style::psql()),
+    " Name   | Language "
+    "--------+----------"
+    " tabled | Rust     "
+    " psql   | C        "
+);
+
+test_table!(
+    from_rendered_psql_output,
+    Builder::from_rendered(
+        concat!(
+            " id | name      \n",
+            "----+-----------\n",
+            "  1 | tabled    \n",
+            "  2 | papergrid \n",
+        ),
+        Style::psql(),
+    )
+    .build()
+    .with(Style::ascii()),
+    "+----+-----------+"
+    "| id | name      |"
+    "+----+-----------+"
+    "| 1  | tabled    |"
+    "+----+-----------+"
+    "| 2  | papergrid |"
+    "+----+-----------+"
+);
+
+#[test]
+fn from_rendered_round_trip() {
+    let table = Table::new([(1, "tabled", 'a'), (2, "papergrid", 'b')])
+        .with(Style::extended())
+        .to_string();
+
+    let parsed = Builder::from_rendered(&table, Style::extended())
+        .build()
+        .with(Style::extended())
+        .to_string();
+
+    assert_eq!(parsed, table);
+}
@@ -0,0 +1,144 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    locator::ByColumnName,
+    object::Columns,
+    pivot::{Agg, Pivot},
+    Style, Table,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+const DATA: [[&str; 3]; 7] = [
+    ["region", "month", "sales"],
+    ["eu", "jan", "10"],
+    ["eu", "feb", "2.5"],
+    ["us", "jan", "5"],
+    ["eu", "jan", "1"],
+    ["asia", "mar", "x"],
+    ["us", "feb", "1,000"],
+];
+
+fn pivot(agg: Agg) -> Table {
+    let table = Builder::from_iter(DATA).build();
+    let mut table = Pivot::new(Columns::single(0), Columns::single(1), Columns::single(2))
+        .aggregate(agg)
+        .pivot(&table);
+    table.with(Style::psql());
+    table
+}
+
+test_table!(
+    pivot_first,
+    pivot(Agg::First),
+    "        | jan | feb   | mar "
+    "--------+-----+-------+-----"
+    " region |     |       |     "
+    " eu     | 10  | 2.5   |     "
+    " us     | 5   | 1,000 |     "
+    " asia   |     |       | x   "
+);
+
+test_table!(
+    pivot_last,
+    pivot(Agg::Last),
+    "        | jan | feb   | mar "
+    "--------+-----+-------+-----"
+    " region |     |       |     "
+    " eu     | 1   | 2.5   |     "
+    " us     | 5   | 1,000 |     "
+    " asia   |     |       | x   "
+);
+
+test_table!(
+    pivot_sum,
+    pivot(Agg::Sum),
+    "        | jan | feb   | mar "
+    "--------+-----+-------+-----"
+    " region |     |       |     "
+    " eu     | 11  | 2.5   |     "
+    " us     | 5   | 1,000 |     "
+    " asia   |     |       |     "
+);
+
+test_table!(
+    pivot_avg,
+    pivot(Agg::Avg),
+    "        | jan | feb   | mar "
+    "--------+-----+-------+-----"
+    " region |     |       |     "
+    " eu     | 6   | 2.5   |     "
+    " us     | 5   | 1,000 |     "
+    " asia   |     |       |     "
+);
+
+test_table!(
+    pivot_min_max_count,
+    {
+        let mut table = pivot(Agg::Min);
+        table.with(tabled::Concat::horizontal(pivot(Agg::Max)));
+        table.with(tabled::Concat::horizontal(pivot(Agg::Count)));
+        table
+    },
+    "        | jan | feb   | mar |        | jan | feb   | mar |        | jan | feb | mar "
+    "--------+-----+-------+-----+--------+-----+-------+-----+--------+-----+-----+-----"
+    " region |     |       |     | region |     |       |     | region |     |     |     "
+    " eu     | 1   | 2.5   |     | eu     | 10  | 2.5   |     | eu     | 2   | 1   |     "
+    " us     | 5   | 1,000 |     | us     | 5   | 1,000 |     | us     | 1   | 1   |     "
+    " asia   |     |       |     | asia   |     |       |     | asia   |     |     | 1   "
+);
+
+test_table!(
+    pivot_default_cell,
+    Pivot::new(
+        ByColumnName::new("region"),
+        ByColumnName::new("month"),
+        ByColumnName::new("sales")
+    )
+    .default_cell("-")
+    .pivot(&Builder::from_iter(DATA).build())
+    .with(Style::psql()),
+    "        | jan | feb   | mar "
+    "--------+-----+-------+-----"
+    " region |     |       |     "
+    " eu     | 10  | 2.5   | -   "
+    " us     | 5   | 1,000 | -   "
+    " asia   | -   | -     | x   "
+);
+
+test_table!(
+    pivot_by_value_as_column_key,
+    Pivot::new(Columns::single(1), Columns::single(0), Columns::single(2))
+        .aggregate(Agg::Count)
+        .default_cell("0")
+        .pivot(&Builder::from_iter(DATA).build())
+        .with(Style::psql()),
+    "       | eu | us | asia "
+    "-------+----+----+------"
+    " month |    |    |      "
+    " jan   | 2  | 1  | 0    "
+    " feb   | 1  | 1  | 0    "
+    " mar   | 0  | 0  | 1    "
+);
+
+test_table!(
+    pivot_without_data,
+    Pivot::new(Columns::single(0), Columns::single(1), Columns::single(2))
+        .pivot(&Builder::from_iter(DATA[..1].iter().copied()).build())
+        .with(Style::psql()),
+    ""
+);
+
+test_table!(
+    pivot_missing_column,
+    Pivot::new(
+        Columns::single(0),
+        Columns::single(1),
+        ByColumnName::new("missing")
+    )
+    .pivot(&Builder::from_iter(DATA).build()),
+    ""
+);
@@ -0,0 +1,158 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    locator::ByColumnName,
+    object::{Cell, Columns},
+    Alignment, Concat, Modify, Padding, Panel, Reorder, Span, Style, Table,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn table() -> Table {
+    let mut table = Builder::from_iter([
+        ["id", "size", "name"],
+        ["0", "1,024", "tabled"],
+        ["1", "512", "papergrid"],
+    ])
+    .build();
+    table.with(Style::psql());
+    table
+}
+
+test_table!(
+    reorder_columns_by_name,
+    table().with(Reorder::columns(["name", "id", "size"].map(ByColumnName::new))),
+    " name      | id | size  "
+    "-----------+----+-------"
+    " tabled    | 0  | 1,024 "
+    " papergrid | 1  | 512   "
+);
+
+test_table!(
+    reorder_columns_keeps_unlisted_order,
+    create_table::<1, 3>()
+        .with(Style::psql())
+        .with(Reorder::columns([Columns::single(3)])),
+    " column 2 | N | column 0 | column 1 "
+    "----------+---+----------+----------"
+    "   0-2    | 0 |   0-0    |   0-1    "
+);
+
+test_table!(
+    reorder_columns_by_range,
+    create_table::<1, 3>()
+        .with(Style::psql())
+        .with(Reorder::columns([Columns::new(2..4)])),
+    " column 1 | column 2 | N | column 0 "
+    "----------+----------+---+----------"
+    "   0-1    |   0-2    | 0 |   0-0    "
+);
+
+test_table!(
+    reorder_columns_ignores_duplicates_and_missing,
+    table().with(Reorder::columns(
+        ["name", "missing", "name"].map(ByColumnName::new)
+    )),
+    " name      | id | size  "
+    "-----------+----+-------"
+    " tabled    | 0  | 1,024 "
+    " papergrid | 1  | 512   "
+);
+
+test_table!(
+    reorder_move_column,
+    table().with(Reorder::move_column(ByColumnName::new("id"), 2)),
+    " size  | name      | id "
+    "-------+-----------+----"
+    " 1,024 | tabled    | 0  "
+    " 512   | papergrid | 1  "
+);
+
+test_table!(
+    reorder_move_column_to_start,
+    table().with(Reorder::move_column(Columns::last(), 0)),
+    " name      | id | size  "
+    "-----------+----+-------"
+    " tabled    | 0  | 1,024 "
+    " papergrid | 1  | 512   "
+);
+
+test_table!(
+    reorder_move_column_out_of_bounds,
+    table().with(Reorder::move_column(Columns::first(), 100)),
+    " size  | name      | id "
+    "-------+-----------+----"
+    " 1,024 | tabled    | 0  "
+    " 512   | papergrid | 1  "
+);
+
+test_table!(
+    reorder_carries_column_settings,
+    table()
+        .with(Modify::new(Columns::single(1)).with(Alignment::right()))
+        .with(Modify::new(Columns::single(0)).with(Padding::new(3, 0, 0, 0)))
+        .with(Modify::new(Cell(1, 2)).with(Alignment::center()))
+        .with(Reorder::columns([Columns::single(2), Columns::single(1)])),
+    " name      |  size |   id"
+    "-----------+-------+-----"
+    "  tabled   | 1,024 |   0 "
+    " papergrid |   512 |   1 "
+);
+
+test_table!(
+    reorder_keeps_settings_applied_after,
+    table()
+        .with(Reorder::columns([Columns::single(2)]))
+        .with(Modify::new(Columns::first()).with(Alignment::right())),
+    "      name | id | size  "
+    "-----------+----+-------"
+    "    tabled | 0  | 1,024 "
+    " papergrid | 1  | 512   "
+);
+
+test_table!(
+    reorder_keeps_panel,
+    Table::new([(0, "a", 'b')])
+        .with(Panel::header("Header"))
+        .with(Modify::new(Cell(0, 0)).with(Alignment::center()))
+        .with(Style::psql())
+        .with(Reorder::columns([Columns::single(2), Columns::single(0)])),
+    "      Header       "
+    "------+-----+------"
+    " char | i32 | &str "
+    " b    | 0   | a    "
+);
+
+test_table!(
+    reorder_cuts_broken_span,
+    create_table::<2, 3>()
+        .with(Style::psql())
+        .with(Modify::new(Cell(1, 0)).with(Span::column(2)))
+        .with(Reorder::columns([Columns::single(0), Columns::single(3)])),
+    " N | column 2 | column 0 | column 1 "
+    "---+----------+----------+----------"
+    " 0 |   0-2    |   0-0    |   0-1    "
+    " 1 |   1-2    |   1-0    |   1-1    "
+);
+
+test_table!(
+    reorder_concatenated_table,
+    Table::new([(0, "a")])
+        .with(Concat::horizontal(Table::new(['b'])))
+        .with(Reorder::columns([Columns::single(2)]))
+        .with(Style::psql()),
+    " char | i32 | &str "
+    "------+-----+------"
+    " b    | 0   | a    "
+);
+
+test_table!(
+    reorder_empty_table,
+    Builder::default()
+        .build()
+        .with(Reorder::columns([Columns::single(2)])),
+    ""
+);
@@ -0,0 +1,178 @@
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+
+use serde::{ser::Error as _, Serialize, Serializer as _};
+use tabled::{
+    ser::{self, Nested, Serializer},
+    Style,
+};
+
+use crate::util::test_table;
+
+mod util;
+
+#[derive(Serialize)]
+struct Release {
+    version: &'static str,
+    date: Date,
+    notes: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct Date {
+    year: u16,
+    month: u8,
+}
+
+#[derive(Serialize)]
+enum Status {
+    Stable,
+    Beta(u8),
+    Yanked { reason: &'static str },
+}
+
+fn releases() -> Vec<Release> {
+    vec![
+        Release {
+            version: "0.8.0",
+            date: Date {
+                year: 2022,
+                month: 7,
+            },
+            notes: None,
+        },
+        Release {
+            version: "0.9.0",
+            date: Date {
+                year: 2022,
+                month: 9,
+            },
+            notes: Some("spans"),
+        },
+    ]
+}
+
+test_table!(
+    ser_seq_of_structs,
+    ser::to_table(&releases()).unwrap(),
+    "+---------+-----------+------------+-------+"
+    "| version | date.year | date.month | notes |"
+    "+---------+-----------+------------+-------+"
+    "| 0.8.0   | 2022      | 7          |       |"
+    "+---------+-----------+------------+-------+"
+    "| 0.9.0   | 2022      | 9          | spans |"
+    "+---------+-----------+------------+-------+"
+);
+
+test_table!(
+    ser_seq_of_structs_nested_tables,
+    releases()
+        .serialize(Serializer::new().nested(Nested::Table))
+        .unwrap()
+        .with(Style::psql()),
+    " version | date             | notes "
+    "---------+------------------+-------"
+    " 0.8.0   | +-------+------+ |       "
+    "         | | year  | 2022 | |       "
+    "         | +-------+------+ |       "
+    "         | | month | 7    | |       "
+    "         | +-------+------+ |       "
+    " 0.9.0   | +-------+------+ | spans "
+    "         | | year  | 2022 | |       "
+    "         | +-------+------+ |       "
+    "         | | month | 9    | |       "
+    "         | +-------+------+ |       "
+);
+
+test_table!(
+    ser_single_struct,
+    ser::to_table(&releases()[1]).unwrap().with(Style::psql()),
+    " key        | value "
+    "------------+-------"
+    " version    | 0.9.0 "
+    " date.year  | 2022  "
+    " date.month | 9     "
+    " notes      | spans "
+);
+
+test_table!(
+    ser_maps_with_different_keys,
+    {
+        let mut first = BTreeMap::new();
+        first.insert("a", 1);
+        first.insert("b", 2);
+
+        let mut second = BTreeMap::new();
+        second.insert("b", 3);
+        second.insert("c", 4);
+
+        ser::to_table(&[first, second]).unwrap().with(Style::psql())
+    },
+    " a | b | c "
+    "---+---+---"
+    " 1 | 2 |   "
+    "   | 3 | 4 "
+);
+
+test_table!(
+    ser_enum_variants,
+    ser::to_table(&[
+        ("stable", Status::Stable),
+        ("beta", Status::Beta(2)),
+        ("yanked", Status::Yanked { reason: "bug" }),
+    ])
+    .unwrap()
+    .with(Style::psql()),
+    " stable | Stable                  "
+    "--------+-------------------------"
+    " beta   | +------+---+            "
+    "        | | Beta | 2 |            "
+    "        | +------+---+            "
+    " yanked | +---------------+-----+ "
+    "        | | Yanked.reason | bug | "
+    "        | +---------------+-----+ "
+);
+
+test_table!(
+    ser_nested_sequence,
+    ser::to_table(&[("tabled", vec!["papergrid", "tabled_derive"])])
+        .unwrap()
+        .with(Style::psql()),
+    " tabled | +---------------+ "
+    "        | | papergrid     | "
+    "        | +---------------+ "
+    "        | | tabled_derive | "
+    "        | +---------------+ "
+);
+
+test_table!(
+    ser_scalar,
+    ser::to_table(&1.5).unwrap(),
+    "+-----+"
+    "| 1.5 |"
+    "+-----+"
+);
+
+test_table!(ser_empty_sequence, ser::to_table::<[u8]>(&[]).unwrap(), "");
+
+#[test]
+fn ser_error() {
+    struct Failing;
+
+    impl Serialize for Failing {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            let _ = serializer;
+            Err(S::Error::custom("not supported"))
+        }
+    }
+
+    let err = ser::to_table(&[Failing]).unwrap_err();
+    assert_eq!(err.to_string(), "not supported");
+
+    let err = Serializer::new().serialize_some(&Failing).unwrap_err();
+    assert_eq!(err.to_string(), "not supported");
+}
@@ -0,0 +1,253 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    locator::ByColumnName,
+    object::{Cell, Columns},
+    papergrid::records::Records,
+    sort::Comparator,
+    Concat, Modify, Panel, Sort, Span, Style, Table,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn table(data: &[[&'static str; 3]]) -> Table {
+    let mut table = Builder::from_iter(data.iter().copied()).build();
+    table.with(Style::psql());
+    table
+}
+
+const DATA: [[&str; 3]; 6] = [
+    ["name", "version", "size"],
+    ["tabled", "0.10.0", "1,024"],
+    ["papergrid", "0.6.0", "512"],
+    ["tabled_derive", "0.5.0", "64.5"],
+    ["json_to_table", "0.2.0", "unknown"],
+    ["ansi-str", "0.5.0", "2_048"],
+];
+
+test_table!(
+    sort_lexical_by_default,
+    table(&DATA).with(Sort::column(Columns::first())),
+    " name          | version | size    "
+    "---------------+---------+---------"
+    " ansi-str      | 0.5.0   | 2_048   "
+    " json_to_table | 0.2.0   | unknown "
+    " papergrid     | 0.6.0   | 512     "
+    " tabled        | 0.10.0  | 1,024   "
+    " tabled_derive | 0.5.0   | 64.5    "
+);
+
+test_table!(
+    sort_descending,
+    table(&DATA).with(Sort::column(Columns::first()).descending()),
+    " name          | version | size    "
+    "---------------+---------+---------"
+    " tabled_derive | 0.5.0   | 64.5    "
+    " tabled        | 0.10.0  | 1,024   "
+    " papergrid     | 0.6.0   | 512     "
+    " json_to_table | 0.2.0   | unknown "
+    " ansi-str      | 0.5.0   | 2_048   "
+);
+
+test_table!(
+    sort_numeric,
+    table(&DATA).with(Sort::column(ByColumnName::new("size")).numeric()),
+    " name          | version | size    "
+    "---------------+---------+---------"
+    " tabled_derive | 0.5.0   | 64.5    "
+    " papergrid     | 0.6.0   | 512     "
+    " tabled        | 0.10.0  | 1,024   "
+    " ansi-str      | 0.5.0   | 2_048   "
+    " json_to_table | 0.2.0   | unknown "
+);
+
+test_table!(
+    sort_numeric_descending,
+    table(&DATA).with(Sort::column(ByColumnName::new("size")).numeric().descending()),
+    " name          | version | size    "
+    "---------------+---------+---------"
+    " json_to_table | 0.2.0   | unknown "
+    " ansi-str      | 0.5.0   | 2_048   "
+    " tabled        | 0.10.0  | 1,024   "
+    " papergrid     | 0.6.0   | 512     "
+    " tabled_derive | 0.5.0   | 64.5    "
+);
+
+#[test]
+fn sort_numeric_with_not_finite_values() {
+    let mut data = vec![vec![String::from("n")]];
+    for i in 0..200 {
+        let value = match i % 4 {
+            0 => String::from("NaN"),
+            1 => String::from("inf"),
+            _ => i.to_string(),
+        };
+
+        data.push(vec![value]);
+    }
+
+    let mut table = Builder::from_iter(data).build();
+    table.with(Sort::column(Columns::first()).numeric());
+
+    let numbers = (0..200).filter(|i| i % 4 > 1).map(|i| i.to_string());
+    let words = (0..100).map(|i| String::from(if i < 50 { "NaN" } else { "inf" }));
+    let expected = numbers.chain(words).collect::<Vec<_>>();
+
+    let records = table.get_records();
+    let got = (1..records.count_rows())
+        .map(|row| records.get_text((row, 0)).to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(got, expected);
+}
+
+test_table!(
+    sort_natural,
+    table(&DATA).with(Sort::column(ByColumnName::new("version")).natural()),
+    " name          | version | size    "
+    "---------------+---------+---------"
+    " json_to_table | 0.2.0   | unknown "
+    " tabled_derive | 0.5.0   | 64.5    "
+    " ansi-str      | 0.5.0   | 2_048   "
+    " papergrid     | 0.6.0   | 512     "
+    " tabled        | 0.10.0  | 1,024   "
+);
+
+test_table!(
+    sort_lexical_version,
+    table(&DATA).with(Sort::column(Columns::single(1)).comparator(Comparator::Lexical)),
+    " name          | version | size    "
+    "---------------+---------+---------"
+    " tabled        | 0.10.0  | 1,024   "
+    " json_to_table | 0.2.0   | unknown "
+    " tabled_derive | 0.5.0   | 64.5    "
+    " ansi-str      | 0.5.0   | 2_048   "
+    " papergrid     | 0.6.0   | 512     "
+);
+
+test_table!(
+    sort_multiple_keys,
+    table(&DATA).with(
+        Sort::column(ByColumnName::new("version"))
+            .natural()
+            .descending()
+            .then(Sort::column(ByColumnName::new("name")))
+    ),
+    " name          | version | size    "
+    "---------------+---------+---------"
+    " tabled        | 0.10.0  | 1,024   "
+    " papergrid     | 0.6.0   | 512     "
+    " ansi-str      | 0.5.0   | 2_048   "
+    " tabled_derive | 0.5.0   | 64.5    "
+    " json_to_table | 0.2.0   | unknown "
+);
+
+test_table!(
+    sort_multiple_columns_of_a_locator,
+    table(&[["1", "b", "x"], ["0", "b", "y"], ["1", "a", "z"]])
+        .with(Sort::column(Columns::new(..2)).header(0)),
+    " 0 | b | y "
+    "---+---+---"
+    " 1 | a | z "
+    " 1 | b | x "
+);
+
+test_table!(
+    sort_without_header,
+    create_table::<3, 1>()
+        .with(Style::psql())
+        .with(Sort::column(Columns::first()).descending().header(0)),
+    " N | column 0 "
+    "---+----------"
+    " 2 |   2-0    "
+    " 1 |   1-0    "
+    " 0 |   0-0    "
+);
+
+test_table!(
+    sort_keeps_panels_in_place,
+    Table::new([3, 1, 2])
+        .with(Panel::header("Numbers"))
+        .with(Panel::footer("End"))
+        .with(Style::psql())
+        .with(Sort::column(Columns::first()).header(2)),
+    " Numbers "
+    "---------"
+    " i32     "
+    " 1       "
+    " 2       "
+    " 3       "
+    " End     "
+);
+
+test_table!(
+    sort_sections_between_spans_separately,
+    table(&[
+        ["name", "id", "value"],
+        ["c", "1", "x"],
+        ["a", "3", "x"],
+        ["section", "", ""],
+        ["b", "0", "x"],
+        ["d", "2", "x"],
+    ])
+    .with(Modify::new(Cell(3, 0)).with(Span::column(3)))
+    .with(Sort::column(Columns::single(1)).descending()),
+    " name | id | value "
+    "------+----+-------"
+    " a    | 3  | x     "
+    " c    | 1  | x     "
+    " section           "
+    " d    | 2  | x     "
+    " b    | 0  | x     "
+);
+
+test_table!(
+    sort_keeps_row_spans_in_place,
+    table(&[
+        ["name", "id", "value"],
+        ["c", "3", "x"],
+        ["span", "2", "y"],
+        ["", "1", "z"],
+        ["b", "0", "x"],
+    ])
+    .with(Modify::new(Cell(2, 0)).with(Span::row(2)))
+    .with(Sort::column(Columns::single(1))),
+    " name | id | value "
+    "------+----+-------"
+    " c    | 3  | x     "
+    " span | 2  | y     "
+    "      | 1  | z     "
+    " b    | 0  | x     "
+);
+
+test_table!(
+    sort_concatenated_table,
+    table(&[["name", "id", "value"], ["b", "1", "x"]])
+        .with(Concat::vertical(table(&[["a", "0", "y"], ["c", "2", "z"]])))
+        .with(Sort::column(Columns::first()).descending()),
+    " name | id | value "
+    "------+----+-------"
+    " c    | 2  | z     "
+    " b    | 1  | x     "
+    " a    | 0  | y     "
+);
+
+test_table!(
+    sort_by_missing_column_does_nothing,
+    table(&DATA[..3]).with(Sort::column(ByColumnName::new("missing"))),
+    " name      | version | size  "
+    "-----------+---------+-------"
+    " tabled    | 0.10.0  | 1,024 "
+    " papergrid | 0.6.0   | 512   "
+);
+
+test_table!(
+    sort_empty_table,
+    Builder::default()
+        .build()
+        .with(Sort::column(Columns::first())),
+    ""
+);
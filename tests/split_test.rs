@@ -0,0 +1,220 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder,
+    locator::ByColumnName,
+    object::{Cell, Columns},
+    Alignment, Modify, Panel, Split, Style, Table,
+};
+
+use crate::util::{create_table, static_table};
+
+mod util;
+
+fn blocks<R>(blocks: Vec<Table<R>>) -> Vec<String>
+where
+    R: tabled::papergrid::records::Records,
+{
+    blocks.iter().map(|block| block.to_string()).collect()
+}
+
+#[test]
+fn split_default_style() {
+    let table = create_table::<2, 3>();
+
+    assert_eq!(
+        blocks(Split::columns(25).split(&table)),
+        [
+            static_table!(
+                "+---+----------+"
+                "| N | column 0 |"
+                "+---+----------+"
+                "| 0 |   0-0    |"
+                "+---+----------+"
+                "| 1 |   1-0    |"
+                "+---+----------+"
+            ),
+            static_table!(
+                "+----------+----------+"
+                "| column 1 | column 2 |"
+                "+----------+----------+"
+                "|   0-1    |   0-2    |"
+                "+----------+----------+"
+                "|   1-1    |   1-2    |"
+                "+----------+----------+"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_keep_first_column() {
+    let mut table = create_table::<2, 3>();
+    table.with(Style::psql());
+
+    let output = Split::columns(20).keep(Columns::first()).split(&table);
+
+    assert_eq!(
+        blocks(output),
+        [
+            static_table!(
+                " N | column 0 "
+                "---+----------"
+                " 0 |   0-0    "
+                " 1 |   1-0    "
+            ),
+            static_table!(
+                " N | column 1 "
+                "---+----------"
+                " 0 |   0-1    "
+                " 1 |   1-1    "
+            ),
+            static_table!(
+                " N | column 2 "
+                "---+----------"
+                " 0 |   0-2    "
+                " 1 |   1-2    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_keep_column_by_name() {
+    let mut table = Builder::from_iter([
+        ["name", "id", "language", "license"],
+        ["tabled", "0", "rust", "MIT"],
+        ["pandas", "1", "python", "BSD"],
+    ])
+    .build();
+    table.with(Style::markdown());
+
+    let output = Split::columns(27)
+        .keep(ByColumnName::new("id"))
+        .split(&table);
+
+    assert_eq!(
+        blocks(output),
+        [
+            static_table!(
+                "| name   | id | language |"
+                "|--------|----|----------|"
+                "| tabled | 0  | rust     |"
+                "| pandas | 1  | python   |"
+            ),
+            static_table!(
+                "| id | license |"
+                "|----|---------|"
+                "| 0  | MIT     |"
+                "| 1  | BSD     |"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_keeps_settings() {
+    let mut table = create_table::<2, 3>();
+    table
+        .with(Style::modern())
+        .with(Modify::new(Columns::new(2..)).with(Alignment::right()))
+        .with(Modify::new(Cell(1, 3)).with(Alignment::left()));
+
+    let output = Split::columns(27).keep(Columns::first()).split(&table);
+
+    assert_eq!(
+        blocks(output),
+        [
+            static_table!(
+                "┌───┬──────────┬──────────┐"
+                "│ N │ column 0 │ column 1 │"
+                "├───┼──────────┼──────────┤"
+                "│ 0 │   0-0    │      0-1 │"
+                "├───┼──────────┼──────────┤"
+                "│ 1 │   1-0    │      1-1 │"
+                "└───┴──────────┴──────────┘"
+            ),
+            static_table!(
+                "┌───┬──────────┐"
+                "│ N │ column 2 │"
+                "├───┼──────────┤"
+                "│ 0 │ 0-2      │"
+                "├───┼──────────┤"
+                "│ 1 │      1-2 │"
+                "└───┴──────────┘"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_moves_spanned_panel() {
+    let mut table = create_table::<1, 3>();
+    table.with(Style::psql()).with(Panel::header("Numbers"));
+
+    let output = Split::columns(25).keep(Columns::first()).split(&table);
+
+    assert_eq!(
+        blocks(output),
+        [
+            static_table!(
+                "         Numbers         "
+                "---+----------+----------"
+                " N | column 0 | column 1 "
+                " 0 |   0-0    |   0-1    "
+            ),
+            static_table!(
+                "   Numbers    "
+                "---+----------"
+                " N | column 2 "
+                " 0 |   0-2    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_narrow_width_puts_a_column_per_block() {
+    let mut table = create_table::<1, 2>();
+    table.with(Style::blank());
+
+    assert_eq!(
+        blocks(Split::columns(0).split(&table)),
+        [
+            static_table!(
+                " N "
+                " 0 "
+            ),
+            static_table!(
+                " column 0 "
+                "   0-0    "
+            ),
+            static_table!(
+                " column 1 "
+                "   0-1    "
+            ),
+        ]
+    );
+}
+
+#[test]
+fn split_fitting_table_is_a_single_block() {
+    let table = create_table::<3, 3>();
+    let output = Split::columns(table.total_width()).split(&table);
+
+    assert_eq!(blocks(output), [table.to_string()]);
+}
+
+#[test]
+fn split_keep_all_columns() {
+    let table = create_table::<3, 3>();
+    let output = Split::columns(1).keep(Columns::new(..)).split(&table);
+
+    assert_eq!(blocks(output), [table.to_string()]);
+}
+
+#[test]
+fn split_empty_table() {
+    let table = Builder::default().build();
+    assert_eq!(blocks(Split::columns(1).split(&table)), [table.to_string()]);
+}
@@ -0,0 +1,182 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder, locator::ByColumnName, object::Columns, style::Line, Style, Summary, Table,
+};
+
+use crate::util::{create_table, test_table};
+
+mod util;
+
+fn table(data: &[[&'static str; 3]]) -> Table {
+    let mut table = Builder::from_iter(data.iter().copied()).build();
+    table.with(Style::psql());
+    table
+}
+
+const DATA: [[&str; 3]; 4] = [
+    ["item", "price", "count"],
+    ["apple", "1.5", "10"],
+    ["orange", "2.25", "3"],
+    ["melon", "1,024", "n/a"],
+];
+
+test_table!(
+    summary_sum,
+    table(&DATA).with(Summary::new().sum(Columns::new(1..)).label(0, "Total")),
+    " item   | price    | count "
+    "--------+----------+-------"
+    " apple  | 1.5      | 10    "
+    " orange | 2.25     | 3     "
+    " melon  | 1,024    | n/a   "
+    " Total  | 1,027.75 | 13    "
+);
+
+test_table!(
+    summary_avg_keeps_precision,
+    table(&DATA[..3]).with(Summary::new().avg(Columns::single(1)).avg(Columns::single(2))),
+    " item   | price | count "
+    "--------+-------+-------"
+    " apple  | 1.5   | 10    "
+    " orange | 2.25  | 3     "
+    "        | 1.88  | 7     "
+);
+
+test_table!(
+    summary_min_max,
+    table(&DATA)
+        .with(
+            Summary::new()
+                .label(0, "min")
+                .min(Columns::new(1..))
+                .row()
+                .label(0, "max")
+                .max(Columns::new(1..))
+        ),
+    " item   | price    | count "
+    "--------+----------+-------"
+    " apple  | 1.5      | 10    "
+    " orange | 2.25     | 3     "
+    " melon  | 1,024    | n/a   "
+    " min    | 1.50     | 3     "
+    " max    | 1,024.00 | 10    "
+);
+
+test_table!(
+    summary_count,
+    table(&[
+        ["name", "email", "phone"],
+        ["a", "a@mail", ""],
+        ["b", "", ""],
+        ["c", "c@mail", "123"],
+    ])
+    .with(Summary::new().count(Columns::new(..))),
+    " name | email  | phone "
+    "------+--------+-------"
+    " a    | a@mail |       "
+    " b    |        |       "
+    " c    | c@mail | 123   "
+    " 3    | 2      | 1     "
+);
+
+test_table!(
+    summary_by_column_name,
+    table(&DATA).with(Summary::new().sum(ByColumnName::new("count")).label(1, "sum")),
+    " item   | price | count "
+    "--------+-------+-------"
+    " apple  | 1.5   | 10    "
+    " orange | 2.25  | 3     "
+    " melon  | 1,024 | n/a   "
+    "        | sum   | 13    "
+);
+
+test_table!(
+    summary_negative_grouped_numbers,
+    table(&[
+        ["a", "b", "c"],
+        ["-1,000", "1_000", "-0.5"],
+        ["-999,000.5", "2_000", "0.25"],
+    ])
+    .with(Summary::new().sum(Columns::new(..))),
+    " a            | b     | c     "
+    "--------------+-------+-------"
+    " -1,000       | 1_000 | -0.5  "
+    " -999,000.5   | 2_000 | 0.25  "
+    " -1,000,000.5 | 3000  | -0.25 "
+);
+
+test_table!(
+    summary_without_numbers,
+    table(&DATA).with(Summary::new().sum(Columns::first()).label(1, "-")),
+    " item   | price | count "
+    "--------+-------+-------"
+    " apple  | 1.5   | 10    "
+    " orange | 2.25  | 3     "
+    " melon  | 1,024 | n/a   "
+    "        | -     |       "
+);
+
+test_table!(
+    summary_line,
+    table(&DATA[..2]).with(
+        Summary::new()
+            .sum(Columns::single(2))
+            .line(Line::new(Some('='), Some('+'), None, None))
+    ),
+    " item  | price | count "
+    "-------+-------+-------"
+    " apple | 1.5   | 10    "
+    "=======+=======+======="
+    "       |       | 10    "
+);
+
+test_table!(
+    summary_line_with_full_style,
+    Table::new([1, 2])
+        .with(Style::modern().off_horizontal())
+        .with(Summary::new().sum(Columns::first()).line(Style::modern().get_horizontal())),
+    "┌─────┐"
+    "│ i32 │"
+    "│ 1   │"
+    "│ 2   │"
+    "├─────┤"
+    "│ 3   │"
+    "└─────┘"
+);
+
+test_table!(
+    summary_without_header,
+    create_table::<2, 2>()
+        .with(Style::psql())
+        .with(Summary::new().count(Columns::new(..)).header(0)),
+    " N | column 0 | column 1 "
+    "---+----------+----------"
+    " 0 |   0-0    |   0-1    "
+    " 1 |   1-0    |   1-1    "
+    " 3 |    3     |    3     "
+);
+
+test_table!(
+    summary_out_of_bounds_columns,
+    table(&DATA[..2]).with(Summary::new().sum(Columns::single(10)).label(10, "x")),
+    " item  | price | count "
+    "-------+-------+-------"
+    " apple | 1.5   | 10    "
+    "       |       |       "
+);
+
+test_table!(
+    summary_empty,
+    table(&DATA[..2]).with(Summary::new()),
+    " item  | price | count "
+    "-------+-------+-------"
+    " apple | 1.5   | 10    "
+);
+
+test_table!(
+    summary_empty_table,
+    Builder::default()
+        .build()
+        .with(Summary::new().sum(Columns::first())),
+    ""
+);
@@ -7,6 +7,15 @@ mod util;
 mod default_types {
     use super::*;
 
+    use std::{
+        collections::BTreeMap,
+        net::IpAddr,
+        path::PathBuf,
+        time::{Duration, UNIX_EPOCH},
+    };
+
+    use tabled::{HumanDuration, KeyValue};
+
     test_table!(
         table_str_vec,
         Table::new(vec!["hello", "world"]),
@@ -228,6 +237,85 @@ mod default_types {
         "| 6 | 7 | 8 |"
         "+---+---+---+"
     );
+
+    test_table!(
+        table_option_vec,
+        Table::new(vec![Some((1, "a")), None, Some((3, "c"))]),
+        "+-----+------+"
+        "| i32 | &str |"
+        "+-----+------+"
+        "| 1   | a    |"
+        "+-----+------+"
+        "|     |      |"
+        "+-----+------+"
+        "| 3   | c    |"
+        "+-----+------+"
+    );
+
+    test_table!(
+        table_result_vec,
+        Table::new(vec![Ok((1, "a")), Err("not found"), Ok((3, "c"))]),
+        "+-----+------+-----------+"
+        "| i32 | &str | error     |"
+        "+-----+------+-----------+"
+        "| 1   | a    |           |"
+        "+-----+------+-----------+"
+        "|     |      | not found |"
+        "+-----+------+-----------+"
+        "| 3   | c    |           |"
+        "+-----+------+-----------+"
+    );
+
+    test_table!(
+        table_map,
+        Table::new(BTreeMap::from([("alpha", 1), ("beta", 2)])),
+        "+-------+-----+"
+        "| &str  | i32 |"
+        "+-------+-----+"
+        "| alpha | 1   |"
+        "+-------+-----+"
+        "| beta  | 2   |"
+        "+-------+-----+"
+    );
+
+    test_table!(
+        table_map_key_value,
+        Table::new(BTreeMap::from([("alpha", 1.5), ("beta", 2.0)]).iter().map(KeyValue::from)),
+        "+-------+-------+"
+        "| key   | value |"
+        "+-------+-------+"
+        "| alpha | 1.5   |"
+        "+-------+-------+"
+        "| beta  | 2     |"
+        "+-------+-------+"
+    );
+
+    test_table!(
+        table_std_types,
+        Table::new(vec![
+            (
+                IpAddr::from([127, 0, 0, 1]),
+                PathBuf::from("/usr/bin"),
+                Duration::from_millis(1500),
+                HumanDuration(Duration::from_secs(3750)),
+                UNIX_EPOCH + Duration::from_secs(1_665_000_000),
+            ),
+            (
+                IpAddr::from([0, 0, 0, 0, 0, 0, 0, 1]),
+                PathBuf::from("Cargo.toml"),
+                Duration::from_micros(15),
+                HumanDuration(Duration::from_nanos(15)),
+                UNIX_EPOCH - Duration::from_millis(1500),
+            ),
+        ]),
+        "+-----------+------------+----------+-----------+------------------------+"
+        "| IpAddr    | PathBuf    | Duration | Duration  | SystemTime             |"
+        "+-----------+------------+----------+-----------+------------------------+"
+        "| 127.0.0.1 | /usr/bin   | 1.5s     | 1h 2m 30s | 2022-10-05T20:00:00Z   |"
+        "+-----------+------------+----------+-----------+------------------------+"
+        "| ::1       | Cargo.toml | 15µs     | 15ns      | 1969-12-31T23:59:58.5Z |"
+        "+-----------+------------+----------+-----------+------------------------+"
+    );
 }
 
 test_table!(
@@ -715,6 +803,318 @@ mod derived {
         "| Keep it simple                     | Unknown         | 🍳                            | 100    |"
         "+------------------------------------+-----------------+-------------------------------+--------+"
     );
+
+    test_table!(
+        table_expanded_structures,
+        Table::new({
+            #[derive(Tabled)]
+            struct Commit {
+                hash: &'static str,
+                message: &'static str,
+            }
+
+            #[derive(Tabled)]
+            struct Branch {
+                name: &'static str,
+                #[tabled(expand)]
+                commits: Vec<Commit>,
+                author: &'static str,
+            }
+
+            [
+                Branch {
+                    name: "main",
+                    commits: vec![
+                        Commit { hash: "3f2a1c", message: "Initial commit" },
+                        Commit { hash: "9b8e7d", message: "Add README" },
+                    ],
+                    author: "Alice",
+                },
+                Branch {
+                    name: "dev",
+                    commits: vec![],
+                    author: "Bob",
+                },
+            ]
+        }),
+        "+------+--------+----------------+--------+"
+        "| name | hash   | message        | author |"
+        "+------+--------+----------------+--------+"
+        "| main | 3f2a1c | Initial commit | Alice  |"
+        "+      +--------+----------------+        +"
+        "|      | 9b8e7d | Add README     |        |"
+        "+------+--------+----------------+--------+"
+        "| dev  |        |                | Bob    |"
+        "+------+--------+----------------+--------+"
+    );
+
+    #[derive(Tabled)]
+    struct OrderItem {
+        item: &'static str,
+        qty: u8,
+    }
+
+    #[derive(Tabled)]
+    struct Order {
+        customer: &'static str,
+        #[tabled(expand)]
+        items: Vec<OrderItem>,
+    }
+
+    fn orders() -> Vec<Order> {
+        vec![
+            Order {
+                customer: "alice",
+                items: vec![
+                    OrderItem {
+                        item: "pen",
+                        qty: 1,
+                    },
+                    OrderItem {
+                        item: "ink",
+                        qty: 1,
+                    },
+                ],
+            },
+            Order {
+                customer: "alice",
+                items: vec![OrderItem {
+                    item: "pad",
+                    qty: 2,
+                }],
+            },
+            Order {
+                customer: "bob",
+                items: vec![
+                    OrderItem {
+                        item: "pen",
+                        qty: 3,
+                    },
+                    OrderItem {
+                        item: "pad",
+                        qty: 3,
+                    },
+                ],
+            },
+        ]
+    }
+
+    test_table!(
+        table_expanded_structures_merge_only_parent_cells,
+        Table::new(orders()),
+        "+----------+------+-----+"
+        "| customer | item | qty |"
+        "+----------+------+-----+"
+        "| alice    | pen  | 1   |"
+        "+          +------+-----+"
+        "|          | ink  | 1   |"
+        "+----------+------+-----+"
+        "| alice    | pad  | 2   |"
+        "+----------+------+-----+"
+        "| bob      | pen  | 3   |"
+        "+          +------+-----+"
+        "|          | pad  | 3   |"
+        "+----------+------+-----+"
+    );
+
+    test_table!(
+        table_expanded_structures_from_iter,
+        orders().iter().collect::<Table<_>>().to_string(),
+        "+----------+------+-----+"
+        "| customer | item | qty |"
+        "+----------+------+-----+"
+        "| alice    | pen  | 1   |"
+        "+          +------+-----+"
+        "|          | ink  | 1   |"
+        "+----------+------+-----+"
+        "| alice    | pad  | 2   |"
+        "+----------+------+-----+"
+        "| bob      | pen  | 3   |"
+        "+          +------+-----+"
+        "|          | pad  | 3   |"
+        "+----------+------+-----+"
+    );
+
+    test_table!(
+        table_expanded_structures_lazy,
+        Table::lazy(&orders()).to_string(),
+        "+----------+------+-----+"
+        "| customer | item | qty |"
+        "+----------+------+-----+"
+        "| alice    | pen  | 1   |"
+        "+          +------+-----+"
+        "|          | ink  | 1   |"
+        "+----------+------+-----+"
+        "| alice    | pad  | 2   |"
+        "+----------+------+-----+"
+        "| bob      | pen  | 3   |"
+        "+          +------+-----+"
+        "|          | pad  | 3   |"
+        "+----------+------+-----+"
+    );
+
+    test_table!(
+        table_expanded_structures_in_option,
+        Table::new(vec![orders().into_iter().next(), None]),
+        "+----------+------+-----+"
+        "| customer | item | qty |"
+        "+----------+------+-----+"
+        "| alice    | pen  | 1   |"
+        "+          +------+-----+"
+        "|          | ink  | 1   |"
+        "+----------+------+-----+"
+        "|          |      |     |"
+        "+----------+------+-----+"
+    );
+
+    test_table!(
+        table_expanded_structures_in_result,
+        Table::new(vec![orders().into_iter().next().ok_or(""), Err("lost")]),
+        "+----------+------+-----+-------+"
+        "| customer | item | qty | error |"
+        "+----------+------+-----+-------+"
+        "| alice    | pen  | 1   |       |"
+        "+          +------+-----+       +"
+        "|          | ink  | 1   |       |"
+        "+----------+------+-----+-------+"
+        "|          |      |     | lost  |"
+        "+----------+------+-----+-------+"
+    );
+
+    test_table!(
+        table_structures_with_layout_attributes,
+        Table::new({
+            #[derive(Tabled)]
+            struct Book {
+                #[tabled(max_width = 8, truncate = "...")]
+                title: &'static str,
+                #[tabled(max_width = 6, wrap)]
+                author: &'static str,
+                #[tabled(align = "right")]
+                pages: usize,
+            }
+
+            [
+                Book {
+                    title: "The Rust Programming Language",
+                    author: "Steve Klabnik",
+                    pages: 560,
+                },
+                Book {
+                    title: "Dune",
+                    author: "Frank Herbert",
+                    pages: 48,
+                },
+            ]
+        }),
+        "+----------+--------+-------+"
+        "| title    | author | pages |"
+        "+----------+--------+-------+"
+        "| The R... | Steve  |   560 |"
+        "|          | Klabni |       |"
+        "|          | k      |       |"
+        "+----------+--------+-------+"
+        "| Dune     | Frank  |    48 |"
+        "|          | Herber |       |"
+        "|          | t      |       |"
+        "+----------+--------+-------+"
+    );
+
+    test_table!(
+        table_structures_with_layout_attributes_and_order,
+        {
+            #[derive(Tabled)]
+            struct Book {
+                #[tabled(inline, align = "center")]
+                id: (usize, char),
+                #[tabled(max_width = 3)]
+                title: &'static str,
+                #[tabled(align = "right", order = 0)]
+                pages: usize,
+            }
+
+            let books = [
+                Book { id: (1, 'a'), title: "Dune", pages: 48 },
+                Book { id: (22, 'b'), title: "It", pages: 1138 },
+            ];
+
+            books.iter().collect::<Table<_>>().to_string()
+        },
+        "+-------+-------+------+-------+"
+        "| pages | usize | char | title |"
+        "+-------+-------+------+-------+"
+        "|    48 |   1   |  a   | Dun   |"
+        "+-------+-------+------+-------+"
+        "|  1138 |  22   |  b   | It    |"
+        "+-------+-------+------+-------+"
+    );
+
+    #[cfg(feature = "color")]
+    test_table!(
+        table_structures_with_color_attribute,
+        Table::new({
+            #[derive(Tabled)]
+            struct Status {
+                #[tabled(color = "red")]
+                name: &'static str,
+                #[tabled(color = "bright_blue")]
+                n: u8,
+            }
+
+            [Status { name: "a", n: 1 }]
+        }),
+        "+------+---+"
+        "| name | n |"
+        "+------+---+"
+        "| \u{1b}[31ma\u{1b}[39m    | \u{1b}[94m1\u{1b}[39m |"
+        "+------+---+"
+    );
+
+    test_table!(
+        table_structures_with_layout_attributes_in_option,
+        Table::new({
+            #[derive(Tabled)]
+            struct Item {
+                #[tabled(align = "right")]
+                n: usize,
+                #[tabled(max_width = 2)]
+                s: &'static str,
+            }
+
+            [Some(Item { n: 100, s: "text" }), None, Some(Item { n: 7, s: "a" })]
+        }),
+        "+-----+----+"
+        "| n   | s  |"
+        "+-----+----+"
+        "| 100 | te |"
+        "+-----+----+"
+        "|     |    |"
+        "+-----+----+"
+        "|   7 | a  |"
+        "+-----+----+"
+    );
+
+    test_table!(
+        table_structures_with_layout_attributes_keep_header,
+        Table::new({
+            #[derive(Tabled)]
+            struct Item {
+                #[tabled(align = "right")]
+                n: usize,
+                #[tabled(max_width = 2)]
+                s: &'static str,
+            }
+
+            [Item { n: 100, s: "text" }, Item { n: 7, s: "a" }]
+        }),
+        "+-----+----+"
+        "| n   | s  |"
+        "+-----+----+"
+        "| 100 | te |"
+        "+-----+----+"
+        "|   7 | a  |"
+        "+-----+----+"
+    );
 }
 
 #[cfg(feature = "color")]
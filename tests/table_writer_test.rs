@@ -0,0 +1,176 @@
+use std::iter::FromIterator;
+
+use tabled::{
+    builder::Builder, object::Columns, Alignment, Margin, Modify, Padding, Style, TableWriter,
+};
+
+use crate::util::{static_table, test_table};
+
+mod util;
+
+const DATA: [[&str; 3]; 4] = [
+    ["id", "name", "value"],
+    ["0", "Hello", "1.23"],
+    ["1", "World", "3.21"],
+    ["2", "Multiline\nstring", "0"],
+];
+
+fn write_table<F>(rows: &[[&str; 3]], configure: F) -> String
+where
+    F: FnOnce(&mut TableWriter<Vec<u8>>),
+{
+    let mut writer = TableWriter::new(Vec::new(), 3);
+    configure(&mut writer);
+
+    for row in rows {
+        writer.push(row).unwrap();
+    }
+
+    let output = writer.finish().unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+test_table!(
+    writer_default_style,
+    write_table(&DATA, |_| {}),
+    "+----+-----------+-------+"
+    "| id | name      | value |"
+    "+----+-----------+-------+"
+    "| 0  | Hello     | 1.23  |"
+    "+----+-----------+-------+"
+    "| 1  | World     | 3.21  |"
+    "+----+-----------+-------+"
+    "| 2  | Multiline | 0     |"
+    "|    | string    |       |"
+    "+----+-----------+-------+"
+);
+
+test_table!(
+    writer_psql_style,
+    write_table(&DATA, |w| {
+        w.with(Style::psql());
+    }),
+    " id | name      | value "
+    "----+-----------+-------"
+    " 0  | Hello     | 1.23  "
+    " 1  | World     | 3.21  "
+    " 2  | Multiline | 0     "
+    "    | string    |       "
+);
+
+test_table!(
+    writer_modern_style,
+    write_table(&DATA[..2], |w| {
+        w.with(Style::modern());
+    }),
+    "┌────┬───────┬───────┐"
+    "│ id │ name  │ value │"
+    "├────┼───────┼───────┤"
+    "│ 0  │ Hello │ 1.23  │"
+    "└────┴───────┴───────┘"
+);
+
+test_table!(
+    writer_fixed_widths_truncate_content,
+    write_table(&DATA, |w| {
+        w.with(Style::markdown()).widths([1, 3, 5]);
+    }),
+    "| i | nam | value |"
+    "|---|-----|-------|"
+    "| 0 | Hel | 1.23  |"
+    "| 1 | Wor | 3.21  |"
+    "| 2 | Mul | 0     |"
+    "|   | str |       |"
+);
+
+test_table!(
+    writer_padding_and_alignment,
+    write_table(&DATA[..3], |w| {
+        w.with(Style::rounded())
+            .with(Padding::new(2, 0, 0, 0))
+            .with(Modify::new(Columns::single(2)).with(Alignment::right()));
+    }),
+    "╭────┬───────┬───────╮"
+    "│  id│  name │  value│"
+    "├────┼───────┼───────┤"
+    "│  0 │  Hello│   1.23│"
+    "│  1 │  World│   3.21│"
+    "╰────┴───────┴───────╯"
+);
+
+test_table!(
+    writer_margin,
+    write_table(&DATA[..2], |w| {
+        w.with(Style::ascii()).with(Margin::new(1, 1, 1, 1));
+    }),
+    "                        "
+    " +----+-------+-------+ "
+    " | id | name  | value | "
+    " +----+-------+-------+ "
+    " | 0  | Hello | 1.23  | "
+    " +----+-------+-------+ "
+    "                        "
+);
+
+test_table!(
+    writer_sample_is_limited,
+    write_table(&DATA, |w| {
+        w.with(Style::psql()).sample(2);
+    }),
+    " id | name  | value "
+    "----+-------+-------"
+    " 0  | Hello | 1.23  "
+    " 1  | World | 3.21  "
+    " 2  | Multi | 0     "
+    "    | strin |       "
+);
+
+#[test]
+fn writer_is_empty_without_rows() {
+    let output = TableWriter::new(Vec::new(), 3).finish().unwrap();
+    assert!(output.is_empty());
+}
+
+#[test]
+fn writer_row_is_extended_and_cut() {
+    let mut writer = TableWriter::new(Vec::new(), 2);
+    writer.with(Style::psql()).widths([3, 3]);
+    writer.push(["a"]).unwrap();
+    writer.push(["b", "c", "d"]).unwrap();
+
+    let output = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+    assert_eq!(
+        output,
+        static_table!(
+            " a   |     "
+            "-----+-----"
+            " b   | c   "
+        )
+    );
+}
+
+#[test]
+fn writer_matches_table() {
+    let styles = [
+        Style::ascii().into(),
+        Style::psql().into(),
+        Style::modern().into(),
+        Style::extended().into(),
+        Style::re_structured_text().into(),
+        Style::blank().into(),
+    ];
+
+    for style in styles {
+        let style: tabled::style::RawStyle = style;
+
+        let mut table = Builder::from_iter(DATA).build();
+        table.with(style.clone());
+
+        let output = write_table(&DATA, |w| {
+            w.with(style);
+        });
+
+        assert_eq!(output, table.to_string());
+    }
+}
@@ -0,0 +1,82 @@
+use std::io;
+
+use tabled::{object::Rows, Modify, Span, Style};
+
+use crate::util::create_table;
+
+mod util;
+
+#[test]
+fn write_to_matches_display() {
+    let mut table = create_table::<3, 3>();
+    table.with(Style::modern());
+
+    let mut buf = Vec::new();
+    table.write_to(&mut buf).unwrap();
+
+    assert_eq!(String::from_utf8(buf).unwrap(), table.to_string());
+}
+
+#[test]
+fn fmt_to_matches_display() {
+    let mut table = create_table::<3, 3>();
+    table.with(Style::psql());
+
+    let mut buf = String::new();
+    table.fmt_to(&mut buf).unwrap();
+
+    assert_eq!(buf, table.to_string());
+}
+
+#[test]
+fn fmt_to_spanned_table_matches_display() {
+    let mut table = create_table::<3, 3>();
+    table
+        .with(Style::extended())
+        .with(Modify::new(Rows::single(1)).with(Span::column(2)));
+
+    let mut buf = String::new();
+    table.fmt_to(&mut buf).unwrap();
+
+    assert_eq!(buf, table.to_string());
+}
+
+#[test]
+fn fmt_to_appends_to_buffer() {
+    let table = create_table::<1, 1>();
+
+    let mut buf = String::from("table:\n");
+    table.fmt_to(&mut buf).unwrap();
+
+    assert_eq!(buf, format!("table:\n{}", table));
+}
+
+#[test]
+fn write_to_empty_table() {
+    let table = create_table::<0, 0>();
+
+    let mut buf = Vec::new();
+    table.write_to(&mut buf).unwrap();
+
+    assert_eq!(String::from_utf8(buf).unwrap(), table.to_string());
+}
+
+#[test]
+fn write_to_returns_io_error() {
+    struct FailingWriter;
+
+    impl io::Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "pipe is closed"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let table = create_table::<3, 3>();
+    let err = table.write_to(FailingWriter).unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+}